@@ -0,0 +1,10 @@
+pub use stubderive::Codegen;
+pub struct Generation;
+impl Generation {
+    pub fn for_tag(_: &str) -> Self { Generation }
+    pub fn include_tag(self, _: &str) -> Self { self }
+    pub fn as_arg_of(self, _: &mut std::process::Command) -> Self { self }
+    pub fn with_output_path(self, _: &str) -> Self { self }
+    pub fn write(self) -> Self { self }
+    pub fn print(self) {}
+}
@@ -5,7 +5,7 @@ use std::{
     process::Command,
 };
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use serde::de::DeserializeOwned;
 
 /// Simple program to greet a person
@@ -20,19 +20,80 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Generate from example `cargo run -- test-typography`
-    TestTypography {
-        #[clap(long)]
-        show_settings: bool,
-        #[clap(long)]
-        print_all_tokens: bool,
-        #[clap(long)]
-        print_figma_input: bool,
+    TestTypography(Box<TestTypographyArgs>),
+    /// Trace which rules set each property `cargo run -- explain "text role:ui size:sm weight:700"`
+    Explain {
+        /// Token query, e.g. `text role:ui size:*` explains every size
+        query: String,
+    },
+    /// Import W3C design tokens (DTCG) JSON `cargo run -- import-dtcg ./tokens.json`
+    ImportDtcg {
+        /// e.g. exported from Tokens Studio
+        file: PathBuf,
     },
     /// Generate code from example `cargo run -- dev-codegen`
     DevCodegen,
 }
 
-pub(crate) fn run() {
+#[derive(Args)]
+struct TestTypographyArgs {
+    #[clap(long)]
+    show_settings: bool,
+    #[clap(long)]
+    print_all_tokens: bool,
+    #[clap(long)]
+    print_figma_input: bool,
+    /// Text styles exported by the Figma plugin, so `--print-figma-input` prints
+    /// a plan to review of what changes, instead of updating every text style
+    #[clap(long)]
+    figma_text_styles: Option<PathBuf>,
+    /// Figma plugin command with variable collections for colors and text style numbers
+    #[clap(long)]
+    print_figma_variables: bool,
+    #[clap(long)]
+    print_css: bool,
+    /// Tailwind `theme.extend` JSON with a `fontSize` for each text style, using the Tailwind units
+    #[clap(long)]
+    print_tailwind: bool,
+    /// W3C design tokens (DTCG) JSON with a typography composite for each text style
+    #[clap(long)]
+    print_dtcg: bool,
+    /// Style Dictionary JSON with a typography composite for each text style
+    #[clap(long)]
+    print_style_dictionary: bool,
+    /// Write `DesignTokens.swift` and `Colors.xcassets` to this directory
+    #[clap(long)]
+    write_swift: Option<PathBuf>,
+    /// Write `DesignTokens.kt` for Jetpack Compose to this directory
+    #[clap(long)]
+    write_compose: Option<PathBuf>,
+    /// Write `design-tokens.slint` with `Typography` and `Palette` globals to this directory
+    #[clap(long)]
+    write_slint: Option<PathBuf>,
+    /// Write `design_tokens.dart` for Flutter to this directory
+    #[clap(long)]
+    write_flutter: Option<PathBuf>,
+    /// Write `DesignTokens.cs` and `DesignTokens.uss` for Unity to this directory
+    #[clap(long)]
+    write_unity: Option<PathBuf>,
+    /// Write `design_tokens.rs` with text style and color constants to this directory
+    #[clap(long)]
+    write_rust: Option<PathBuf>,
+    /// Write `design-tokens.ts` with text style and color constants and a `Token` union to this directory
+    #[clap(long)]
+    write_typescript: Option<PathBuf>,
+    /// Write `_design-tokens.scss` and `design-tokens.less` to this directory, using the CSS units
+    #[clap(long)]
+    write_scss_and_less: Option<PathBuf>,
+    /// Write Android `res/values` and `res/values-night` resources to this directory
+    #[clap(long)]
+    write_android: Option<PathBuf>,
+    /// Which text styles to materialize for exports like `--print-dtcg` and `--write-swift`
+    #[clap(long, default_value = crate::typography::styles::DEFAULT_TEXT_STYLES_QUERY)]
+    text_styles: String,
+}
+
+pub fn run() {
     let cli = Cli::parse();
     let manifest_dir = PathBuf::from(
         std::env::var("CARGO_MANIFEST_DIR").expect("getting cargo manifest directory"),
@@ -50,11 +111,102 @@ pub(crate) fn run() {
             };
             dev.generate_helpers_for_sdks();
         }
-        Commands::TestTypography {
-            show_settings,
-            print_all_tokens,
-            print_figma_input,
-        } => {
+        Commands::Explain { query } => {
+            let parsed = match crate::tokens::query::Query::parse(&query) {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    eprintln!("{}", err.display_with_source(&query));
+                    std::process::exit(1);
+                }
+            };
+
+            let input_settings = run_deno_or_exit::<crate::input::SystemInput>(
+                "./examples/get-settings-json-to-stdout.ts",
+                std::iter::empty(),
+            );
+            let all_tokens: crate::typography::output::TypographyExport =
+                crate::typography::output::generate_typography_all_tokens(
+                    &input_settings.typography,
+                )
+                .expect("generating all tokens")
+                .into();
+
+            let aliases = crate::tokens::aliases::TokenAliases::from_input(
+                input_settings.aliases.as_deref().unwrap_or_default(),
+            )
+            .expect("resolving token aliases");
+            let lookup = all_tokens.as_lookup().with_aliases(&aliases);
+            let token_sets = lookup.expand_query(&parsed);
+            if token_sets.is_empty() {
+                eprintln!("No known tokens match {query:?}");
+                std::process::exit(1);
+            }
+            for token_set in token_sets {
+                println!("{token_set:?}\n{}", lookup.explain(&token_set));
+            }
+        }
+        Commands::ImportDtcg { file } => {
+            let json = serde_json::from_slice::<serde_json::Value>(
+                &std::fs::read(&file).expect("reading DTCG file"),
+            )
+            .expect("parsing DTCG file as JSON");
+            let imported = crate::dtcg::import::import_dtcg(&json).expect("importing DTCG tokens");
+
+            for unsupported in &imported.unsupported {
+                eprintln!("Unsupported {unsupported}");
+            }
+            eprintln!(
+                "Imported {} tokens ({} aliases), {} unsupported",
+                imported.tokens.len(),
+                imported.aliases.len(),
+                imported.unsupported.len()
+            );
+            crate::tokens::aliases::TokenAliases::from_input(&imported.aliases)
+                .expect("resolving imported aliases");
+
+            match imported.system_input_json() {
+                Ok(system_input) => println!(
+                    "####BEGIN:SYSTEM INPUT####\n{}\n####END:SYSTEM INPUT####",
+                    serde_json::to_string_pretty(&system_input)
+                        .expect("json stringifying system input")
+                ),
+                Err(err) => eprintln!("warning: no system input: {err:#}"),
+            }
+
+            let typography: crate::typography::output::TypographyExport =
+                imported.typography.into();
+            println!(
+                "####BEGIN:TYPOGRAPHY TOKENS####\n{}\n####END:TYPOGRAPHY TOKENS####",
+                serde_json::to_string(&typography).expect("json stringifying typography tokens")
+            );
+
+            println!(
+                "####BEGIN:TOKENS####\n{}\n####END:TOKENS####",
+                serde_json::to_string_pretty(&imported.tokens).expect("json stringifying tokens")
+            );
+        }
+        Commands::TestTypography(args) => {
+            let TestTypographyArgs {
+                show_settings,
+                print_all_tokens,
+                print_figma_input,
+                figma_text_styles,
+                print_figma_variables,
+                print_css,
+                print_tailwind,
+                print_dtcg,
+                print_style_dictionary,
+                write_swift,
+                write_compose,
+                write_slint,
+                write_flutter,
+                write_unity,
+                write_rust,
+                write_typescript,
+                write_scss_and_less,
+                write_android,
+                text_styles,
+            } = *args;
             let input_settings = run_deno_or_exit::<crate::input::SystemInput>(
                 "./examples/get-settings-json-to-stdout.ts",
                 std::iter::empty(),
@@ -73,6 +225,11 @@ pub(crate) fn run() {
                 )
                 .expect("generating all tokens")
                 .into();
+            let aliases = crate::tokens::aliases::TokenAliases::from_input(
+                input_settings.aliases.as_deref().unwrap_or_default(),
+            )
+            .expect("resolving token aliases");
+
             if print_all_tokens {
                 let mut formatter = serde_json::ser::PrettyFormatter::new();
 
@@ -93,12 +250,30 @@ pub(crate) fn run() {
                     >(input_settings.typography.Extensions.clone())
                     .expect("reading Figma extension input");
 
-                let figma_plugin_command =
-                    crate::typography::figma::figma_export::update_typography_for_figma(
+                let figma_plugin_command = match &figma_text_styles {
+                    Some(path) => {
+                        let existing = serde_json::from_str::<
+                            Vec<crate::typography::figma::figma_export::ExistingTextStyle>,
+                        >(
+                            &std::fs::read_to_string(path)
+                                .expect("reading Figma text styles file"),
+                        )
+                        .expect("parsing Figma text styles file");
+                        crate::typography::figma::figma_export::plan_typography_for_figma(
+                            &all_tokens,
+                            &figma_extension_input,
+                            &aliases,
+                            &existing,
+                        )
+                        .expect("getting a plan command for Figma plugin")
+                    }
+                    None => crate::typography::figma::figma_export::update_typography_for_figma(
                         &all_tokens,
                         &figma_extension_input,
+                        &aliases,
                     )
-                    .expect("getting an update command for Figma plugin");
+                    .expect("getting an update command for Figma plugin"),
+                };
 
                 println!(
                     "####BEGIN:FIGMA PLUGIN COMMAND####\n{}\n####END:FIGMA PLUGIN COMMAND####",
@@ -112,6 +287,252 @@ pub(crate) fn run() {
                 );
             }
 
+            if print_css {
+                let css_extension_input =
+                    serde_json::from_value::<
+                        crate::typography::css::css_config::TypographyExtensionInput,
+                    >(input_settings.typography.Extensions.clone())
+                    .expect("reading CSS extension input")
+                    .CSS
+                    .unwrap_or_default();
+
+                let css = crate::typography::css::css_export::generate_typography_for_css(
+                    &all_tokens,
+                    &input_settings.typography.Families,
+                    &css_extension_input,
+                )
+                .expect("generating CSS");
+                for warning in &css.warnings {
+                    eprintln!("warning: {warning}");
+                }
+
+                println!("####BEGIN:CSS####\n{}####END:CSS####", css.to_css_string());
+            }
+
+            let colors = input_settings
+                .color_palette
+                .as_ref()
+                .map(crate::color::output::generate_colors)
+                .transpose()
+                .expect("generating colors");
+            let text_styles_query = crate::tokens::query::Query::parse(&text_styles)
+                .unwrap_or_else(|err| panic!("{}", err.display_with_source(&text_styles)));
+            let materialized = crate::typography::styles::materialize_text_styles(
+                &all_tokens,
+                &aliases,
+                &text_styles_query,
+            )
+            .expect("materializing text styles");
+
+            if print_figma_variables {
+                let figma_extension_input =
+                    serde_json::from_value::<
+                        crate::typography::figma::figma_config::TypographyExtensionInput,
+                    >(input_settings.typography.Extensions.clone())
+                    .expect("reading Figma extension input");
+
+                let figma_plugin_command =
+                    crate::typography::figma::figma_export::update_variables_for_figma(
+                        &all_tokens,
+                        colors.as_ref(),
+                        &figma_extension_input,
+                        &aliases,
+                    )
+                    .expect("getting a variables command for Figma plugin");
+
+                println!(
+                    "####BEGIN:FIGMA PLUGIN COMMAND####\n{}\n####END:FIGMA PLUGIN COMMAND####",
+                    serde_json::to_string(&figma_plugin_command)
+                        .expect("json stringifying figma plugin command")
+                        .replace(
+                            r#"},{"key""#,
+                            r#"},
+    {"key""#
+                        )
+                );
+            }
+
+            if print_tailwind {
+                let tailwind_input = serde_json::from_value::<
+                    crate::typography::tailwind::tailwind_config::TypographyExtensionInput,
+                >(input_settings.typography.Extensions.clone())
+                .expect("reading Tailwind extension input")
+                .Tailwind
+                .unwrap_or_default();
+                let tailwind = crate::typography::tailwind::generate_typography_for_tailwind(
+                    &materialized,
+                    &input_settings.typography.Families,
+                    &tailwind_input,
+                )
+                .expect("generating Tailwind theme");
+
+                println!(
+                    "####BEGIN:TAILWIND####\n{}\n####END:TAILWIND####",
+                    serde_json::to_string_pretty(&tailwind)
+                        .expect("json stringifying Tailwind theme")
+                );
+            }
+
+            if print_dtcg {
+                let dtcg = crate::dtcg::export::export_dtcg(
+                    &all_tokens,
+                    colors.as_ref(),
+                    &aliases,
+                    &text_styles_query,
+                )
+                .expect("generating DTCG JSON");
+
+                println!(
+                    "####BEGIN:DTCG####\n{}\n####END:DTCG####",
+                    serde_json::to_string_pretty(&dtcg).expect("json stringifying DTCG")
+                );
+            }
+
+            if print_style_dictionary {
+                let style_dictionary_input =
+                    serde_json::from_value::<
+                        crate::style_dictionary::style_dictionary_config::TypographyExtensionInput,
+                    >(input_settings.typography.Extensions.clone())
+                    .expect("reading Style Dictionary extension input")
+                    .StyleDictionary
+                    .unwrap_or_default();
+
+                let style_dictionary =
+                    crate::style_dictionary::style_dictionary_export::generate_style_dictionary(
+                        &all_tokens,
+                        colors.as_ref(),
+                        &aliases,
+                        &text_styles_query,
+                        &style_dictionary_input,
+                    )
+                    .expect("generating Style Dictionary JSON");
+
+                println!(
+                    "####BEGIN:STYLE DICTIONARY####\n{}\n####END:STYLE DICTIONARY####",
+                    serde_json::to_string_pretty(&style_dictionary)
+                        .expect("json stringifying Style Dictionary")
+                );
+            }
+
+            if let Some(output_dir) = write_swift {
+                let files = crate::platforms::swift::generate_swift(
+                    &materialized,
+                    &input_settings.typography.Families,
+                    colors.as_ref(),
+                )
+                .expect("generating Swift");
+                crate::platforms::write_files(&output_dir, &files).expect("writing Swift files");
+                eprintln!("Wrote {} Swift files to {output_dir:?}", files.len());
+            }
+
+            if let Some(output_dir) = write_compose {
+                let compose_input = serde_json::from_value::<
+                    crate::platforms::compose::compose_config::TypographyExtensionInput,
+                >(input_settings.typography.Extensions.clone())
+                .expect("reading Compose extension input")
+                .Compose
+                .unwrap_or_default();
+                let files = crate::platforms::compose::generate_compose(
+                    &materialized,
+                    colors.as_ref(),
+                    &compose_input,
+                )
+                .expect("generating Compose");
+                crate::platforms::write_files(&output_dir, &files).expect("writing Compose files");
+                eprintln!("Wrote {} Compose files to {output_dir:?}", files.len());
+            }
+
+            if let Some(output_dir) = write_slint {
+                let files = crate::platforms::slint::generate_slint(&materialized, colors.as_ref())
+                    .expect("generating Slint");
+                crate::platforms::write_files(&output_dir, &files).expect("writing Slint files");
+                eprintln!("Wrote {} Slint files to {output_dir:?}", files.len());
+            }
+
+            if let Some(output_dir) = write_flutter {
+                let flutter_input = serde_json::from_value::<
+                    crate::platforms::flutter::flutter_config::TypographyExtensionInput,
+                >(input_settings.typography.Extensions.clone())
+                .expect("reading Flutter extension input")
+                .Flutter
+                .unwrap_or_default();
+                let files = crate::platforms::flutter::generate_flutter(
+                    &materialized,
+                    colors.as_ref(),
+                    &flutter_input,
+                )
+                .expect("generating Flutter");
+                crate::platforms::write_files(&output_dir, &files).expect("writing Flutter files");
+                eprintln!("Wrote {} Flutter files to {output_dir:?}", files.len());
+            }
+
+            if let Some(output_dir) = write_unity {
+                let files = crate::platforms::unity::generate_unity(&materialized, colors.as_ref())
+                    .expect("generating Unity");
+                crate::platforms::write_files(&output_dir, &files).expect("writing Unity files");
+                eprintln!("Wrote {} Unity files to {output_dir:?}", files.len());
+            }
+
+            if let Some(output_dir) = write_rust {
+                let rust_input =
+                    serde_json::from_value::<
+                        crate::platforms::rust::rust_config::TypographyExtensionInput,
+                    >(input_settings.typography.Extensions.clone())
+                    .expect("reading Rust extension input")
+                    .Rust
+                    .unwrap_or_default();
+                let files = crate::platforms::rust::generate_rust(
+                    &materialized,
+                    colors.as_ref(),
+                    &rust_input,
+                )
+                .expect("generating Rust");
+                crate::platforms::write_files(&output_dir, &files).expect("writing Rust files");
+                eprintln!("Wrote {} Rust files to {output_dir:?}", files.len());
+            }
+
+            if let Some(output_dir) = write_typescript {
+                let files = crate::platforms::typescript::generate_typescript(
+                    &all_tokens,
+                    &materialized,
+                    colors.as_ref(),
+                    &aliases,
+                )
+                .expect("generating TypeScript");
+                crate::platforms::write_files(&output_dir, &files)
+                    .expect("writing TypeScript files");
+                eprintln!("Wrote {} TypeScript files to {output_dir:?}", files.len());
+            }
+
+            if let Some(output_dir) = write_scss_and_less {
+                let css_extension_input =
+                    serde_json::from_value::<
+                        crate::typography::css::css_config::TypographyExtensionInput,
+                    >(input_settings.typography.Extensions.clone())
+                    .expect("reading CSS extension input")
+                    .CSS
+                    .unwrap_or_default();
+                let files = crate::platforms::css_preprocessors::generate_scss_and_less(
+                    &materialized,
+                    &input_settings.typography.Families,
+                    colors.as_ref(),
+                    &css_extension_input,
+                )
+                .expect("generating SCSS and Less");
+                crate::platforms::write_files(&output_dir, &files)
+                    .expect("writing SCSS and Less files");
+                eprintln!("Wrote {} SCSS and Less files to {output_dir:?}", files.len());
+            }
+
+            if let Some(output_dir) = write_android {
+                let files =
+                    crate::platforms::android::generate_android(&materialized, colors.as_ref())
+                        .expect("generating Android resources");
+                crate::platforms::write_files(&output_dir, &files)
+                    .expect("writing Android resources");
+                eprintln!("Wrote {} Android resources to {output_dir:?}", files.len());
+            }
+
             // let output = run_deno_or_exit::<serde_json::Value>(
             //     "./examples/tailwind/generate-tailwind-json-from-arg.ts",
             //     std::iter::once(all_tokens_str.as_str()),
@@ -129,7 +550,14 @@ struct DesignTokensDev {
 impl DesignTokensDev {
     pub fn generate_helpers_for_sdks(&self) {
         derive_codegen::Generation::for_tag("typography-input")
+            .include_tag("input")
+            .include_tag("typography-units-input")
             .include_tag("css-typography-scalar")
+            .include_tag("css-typography-input")
+            .include_tag("compose-input")
+            .include_tag("flutter-input")
+            .include_tag("rust-input")
+            .include_tag("style-dictionary-input")
             .include_tag("tailwind-typography-input")
             .include_tag("figma-typography-scalar")
             .include_tag("figma-typography-input")
@@ -5,3 +5,4 @@
 use crate::prelude::*;
 
 pub mod input;
+pub mod output;
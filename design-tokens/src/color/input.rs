@@ -2,32 +2,57 @@ use crate::prelude::*;
 
 /// TODO: adjust API/Configuration to be more accomodating
 /// of other color generation strategies than just Material You.
-#[derive(Codegen, Debug, Deserialize)]
+#[derive(Codegen, Debug, Deserialize, Serialize)]
 #[codegen(tags = "input,color")]
 #[allow(non_snake_case)]
 pub struct ColorPalette {
-    Primary: InputColor,
-    Extensions: Vec<ColorExtension>,
+    pub Primary: InputColor,
+    pub Extensions: Vec<ColorExtension>,
 }
 
-#[derive(Codegen, Debug, Deserialize)]
+#[derive(Codegen, Debug, Deserialize, Serialize)]
 #[codegen(tags = "input,color")]
 #[allow(non_snake_case)]
 pub struct ColorExtension {
     /// e.g. `"blue"`
-    Token: String,
-    Source: SourceColor,
+    pub Token: String,
+    pub Source: SourceColor,
 }
 
-#[derive(Codegen, Debug, Deserialize)]
+#[derive(Codegen, Debug, Deserialize, Serialize)]
 #[codegen(tags = "input,color")]
 pub enum SourceColor {
+    /// Harmonized with the primary color, shifting its hue up to 15° toward the primary
+    /// like Material's custom colors, so it fits in with the rest of the palette.
     SimilarTo(InputColor),
+    /// Used as is, and also available as a color like `color:blue`.
     Exactly(InputColor),
 }
 
-#[derive(Codegen, Debug, Deserialize)]
+#[derive(Codegen, Debug, Deserialize, Serialize)]
 #[codegen(tags = "input,color")]
 pub enum InputColor {
     Hex(String),
 }
+
+impl InputColor {
+    /// e.g. `"#0a84ff"` is `[255, 10, 132, 255]`, also accepting `"#08f"` and no `#`.
+    pub fn to_argb(&self) -> Result<[u8; 4]> {
+        match self {
+            InputColor::Hex(hex) => {
+                let digits = hex.trim().trim_start_matches('#');
+                let digits = match digits.len() {
+                    3 => digits.chars().flat_map(|c| [c, c]).collect::<String>(),
+                    6 => digits.to_string(),
+                    _ => anyhow::bail!("expected a hex color like \"#0a84ff\", but found {hex:?}"),
+                };
+                let channel = |idx: usize| {
+                    u8::from_str_radix(&digits[idx..idx + 2], 16).with_context(|| {
+                        format!("expected a hex color like \"#0a84ff\", but found {hex:?}")
+                    })
+                };
+                Ok([255, channel(0)?, channel(2)?, channel(4)?])
+            }
+        }
+    }
+}
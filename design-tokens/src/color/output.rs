@@ -0,0 +1,182 @@
+//! Generates color tokens from [ColorPalette] using Material You's tonal palettes and schemes.
+//!
+//! * Tones like `color:primary tone:40` for `primary`, `secondary`, `tertiary`, `neutral`,
+//!   `neutral-variant`, `error`, and each extension.
+//! * Scheme roles like `color:on-primary scheme:dark`.
+//! * Extensions which are `Exactly` a color also have their exact color, like `color:blue`,
+//!   while extensions `SimilarTo` a color are harmonized with the primary color first.
+use material_color_utilities_rs::{
+    blend::harmonize,
+    palettes::{core::CorePalette, tonal::TonalPalette},
+    scheme::Scheme,
+    util::color::format_argb_as_rgb,
+};
+
+use crate::{
+    prelude::*,
+    tokens::{aliases::TokenAliases, Token, TokenSet},
+};
+
+use super::input::{ColorPalette, SourceColor};
+
+/// Tones of each palette, matching Material Theme Builder.
+pub const TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+#[derive(Debug, Serialize, Codegen)]
+#[codegen(tags = "color-export")]
+pub struct ColorExport {
+    pub colors: Vec<ColorToken>,
+}
+
+#[derive(Debug, Serialize, Codegen)]
+#[codegen(tags = "color-export")]
+pub struct ColorToken {
+    pub tokens: TokenSet,
+    /// e.g. `"#0a84ff"`
+    pub hex: String,
+}
+
+/// A scheme role like `color:on-primary` with its `scheme:light` and `scheme:dark` colors.
+pub struct SchemeRoleColor<'a> {
+    /// e.g. `"on-primary"`
+    pub role: &'a str,
+    pub light_hex: &'a str,
+    pub dark_hex: &'a str,
+}
+
+impl ColorExport {
+    /// Each role in the order generated, pairing its light and dark colors.
+    pub fn scheme_roles(&self) -> Vec<SchemeRoleColor<'_>> {
+        let scheme_hex = |role: &str, scheme: &str| {
+            self.colors.iter().find_map(|color| {
+                (color.tokens.get("color").map(|found| found.as_ref()) == Some(role)
+                    && color.tokens.get("scheme").map(|found| found.as_ref()) == Some(scheme))
+                .then_some(color.hex.as_str())
+            })
+        };
+        self.colors
+            .iter()
+            .filter(|color| color.tokens.get("scheme").map(|found| found.as_ref()) == Some("light"))
+            .filter_map(|color| {
+                let role = color.tokens.get("color")?.as_ref();
+                Some(SchemeRoleColor {
+                    role,
+                    light_hex: &color.hex,
+                    dark_hex: scheme_hex(role, "dark")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Finds the color with exactly these tokens, after resolving aliases
+    /// like `color:on-primary` for `color:primary tone:100`.
+    pub fn find(&self, tokens: &TokenSet, aliases: &TokenAliases) -> Option<&ColorToken> {
+        let resolved = aliases.resolve(tokens);
+        self.colors.iter().find(|color| color.tokens == resolved)
+    }
+}
+
+pub fn generate_colors(palette: &ColorPalette) -> Result<ColorExport> {
+    let mut colors = Vec::new();
+
+    let primary_argb = palette.Primary.to_argb().context("reading primary color")?;
+    let mut core = CorePalette::new(primary_argb, false);
+
+    for (name, tonal) in [
+        ("primary", &mut core.a1),
+        ("secondary", &mut core.a2),
+        ("tertiary", &mut core.a3),
+        ("neutral", &mut core.n1),
+        ("neutral-variant", &mut core.n2),
+        ("error", &mut core.error),
+    ] {
+        push_tones(&mut colors, name, tonal);
+    }
+
+    for (scheme_name, scheme) in [
+        ("light", Scheme::light_from_core_palette(&mut core)),
+        ("dark", Scheme::dark_from_core_palette(&mut core)),
+    ] {
+        for (role, argb) in scheme_roles(&scheme) {
+            colors.push(ColorToken {
+                tokens: TokenSet::from([
+                    Token::of_value("color", role),
+                    Token::of_value("scheme", scheme_name),
+                ]),
+                hex: format_argb_as_rgb(argb),
+            });
+        }
+    }
+
+    for extension in palette.Extensions.iter() {
+        let source = match &extension.Source {
+            SourceColor::SimilarTo(color) | SourceColor::Exactly(color) => color,
+        };
+        let source_argb = source
+            .to_argb()
+            .with_context(|| format!("reading color extension ({:?})", extension.Token))?;
+        let argb = match extension.Source {
+            SourceColor::SimilarTo(_) => harmonize(source_argb, primary_argb),
+            SourceColor::Exactly(_) => source_argb,
+        };
+        if let SourceColor::Exactly(_) = extension.Source {
+            colors.push(ColorToken {
+                tokens: TokenSet::from([Token::of_value("color", extension.Token.clone())]),
+                hex: format_argb_as_rgb(argb),
+            });
+        }
+        push_tones(
+            &mut colors,
+            &extension.Token,
+            &mut TonalPalette::from_int(argb),
+        );
+    }
+
+    Ok(ColorExport { colors })
+}
+
+fn push_tones(colors: &mut Vec<ColorToken>, name: &str, tonal: &mut TonalPalette) {
+    for tone in TONES {
+        colors.push(ColorToken {
+            tokens: TokenSet::from([
+                Token::of_value("color", name.to_string()),
+                Token::of_value_display("tone", tone),
+            ]),
+            hex: format_argb_as_rgb(tonal.tone(tone)),
+        });
+    }
+}
+
+fn scheme_roles(scheme: &Scheme) -> [(&'static str, [u8; 4]); 29] {
+    [
+        ("primary", scheme.primary),
+        ("on-primary", scheme.on_primary),
+        ("primary-container", scheme.primary_container),
+        ("on-primary-container", scheme.on_primary_container),
+        ("secondary", scheme.secondary),
+        ("on-secondary", scheme.on_secondary),
+        ("secondary-container", scheme.secondary_container),
+        ("on-secondary-container", scheme.on_secondary_container),
+        ("tertiary", scheme.tertiary),
+        ("on-tertiary", scheme.on_tertiary),
+        ("tertiary-container", scheme.tertiary_container),
+        ("on-tertiary-container", scheme.on_tertiary_container),
+        ("error", scheme.error),
+        ("on-error", scheme.on_error),
+        ("error-container", scheme.error_container),
+        ("on-error-container", scheme.on_error_container),
+        ("background", scheme.background),
+        ("on-background", scheme.on_background),
+        ("surface", scheme.surface),
+        ("on-surface", scheme.on_surface),
+        ("surface-variant", scheme.surface_variant),
+        ("on-surface-variant", scheme.on_surface_variant),
+        ("outline", scheme.outline),
+        ("outline-variant", scheme.outline_variant),
+        ("shadow", scheme.shadow),
+        ("scrim", scheme.scrim),
+        ("inverse-surface", scheme.inverse_surface),
+        ("inverse-on-surface", scheme.inverse_on_surface),
+        ("inverse-primary", scheme.inverse_primary),
+    ]
+}
@@ -0,0 +1,8 @@
+//! W3C Design Tokens Community Group format, see https://tr.designtokens.org/format/
+//!
+//! Tools like Tokens Studio, Style Dictionary and Penpot read and write this format,
+//! where tokens are objects with a `$value` (and usually a `$type`) nested in groups.
+use crate::prelude::*;
+
+pub mod export;
+pub mod import;
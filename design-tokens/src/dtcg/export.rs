@@ -0,0 +1,122 @@
+//! Writes DTCG JSON with groups derived from tokens, e.g.
+//! `text role:ui size:sm italic:true` is the `typography` composite at `text.italic.role/ui.size/sm`,
+//! and `color:primary tone:40` is the `color` at `color.primary.tone/40`.
+use serde_json::{json, Map, Value};
+
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    tokens::{
+        aliases::TokenAliases,
+        nested::{color_group_path, group_path, NestedFormat},
+        query::Query,
+        Token, TokenSet,
+    },
+    typography::{
+        output::{TypographyExport, TypographyProperty},
+        styles::materialize_text_styles,
+        units::{format_number, Length},
+    },
+};
+
+/// When a token and a group share a path (e.g. `color.blue` and `color.blue.40`),
+/// the token moves to the group's `$root`, as described by the DTCG format.
+const DTCG: NestedFormat = NestedFormat {
+    name: "DTCG",
+    value_key: "$value",
+    root_key: "$root",
+};
+
+pub fn export_dtcg(
+    all_tokens: &TypographyExport,
+    colors: Option<&ColorExport>,
+    aliases: &TokenAliases,
+    text_styles_query: &Query,
+) -> Result<Value> {
+    let mut root = Map::new();
+
+    // Dimensions of each rule, e.g. `font-size.role/ui.size/sm`
+    for (reqs, props) in all_tokens.iter_rules() {
+        let values_path = || {
+            group_path(reqs, &[])
+                .into_iter()
+                .skip(reqs.kind().iter().count())
+        };
+        for prop in props {
+            let (group, token) = match prop {
+                TypographyProperty::FontFamily { family_name } => (
+                    "font-family",
+                    json!({ "$type": "fontFamily", "$value": family_name }),
+                ),
+                TypographyProperty::FontSize { px } => ("font-size", dimension(*px)),
+                TypographyProperty::LineHeight { px } => ("line-height", dimension(*px)),
+                TypographyProperty::LetterSpacing { px, .. } => ("letter-spacing", dimension(*px)),
+                TypographyProperty::FontStyle(_) | TypographyProperty::BaselineShift { .. } => {
+                    continue
+                }
+            };
+            let path = std::iter::once(group.to_string())
+                .chain(values_path())
+                .collect::<Vec<_>>();
+            DTCG.insert_token(&mut root, &path, token)?;
+        }
+    }
+
+    for style in materialize_text_styles(all_tokens, aliases, text_styles_query)? {
+        let mut value = Map::new();
+        value.insert("fontFamily".into(), json!(style.family_name));
+        value.insert(
+            "fontSize".into(),
+            dimension(style.font_size_px)["$value"].take(),
+        );
+        value.insert("fontWeight".into(), json!(style.font_weight));
+        if let Some(px) = style.letter_spacing_px {
+            value.insert("letterSpacing".into(), dimension(px)["$value"].take());
+        }
+        if let Some(px) = style.line_height_px {
+            // DTCG line heights are multiplied by the font size
+            value.insert("lineHeight".into(), number(px / style.font_size_px));
+        }
+        DTCG.insert_token(
+            &mut root,
+            &group_path(&style.tokens, &[]),
+            json!({ "$type": "typography", "$value": value }),
+        )?;
+    }
+
+    if let Some(colors) = colors {
+        for color in &colors.colors {
+            DTCG.insert_token(
+                &mut root,
+                &color_group_path(&color.tokens, &[]),
+                json!({ "$type": "color", "$value": color.hex }),
+            )?;
+        }
+
+        // e.g. `color:on-brand` referencing `color:primary tone:100` is `"{color.primary.tone/100}"`
+        for (alias, _) in aliases.iter() {
+            let alias_set = TokenSet::from([alias.clone()]);
+            if !matches!(alias, Token::Value(key, _) if key == "color") {
+                continue;
+            }
+            if let Some(target) = colors.find(&alias_set, aliases) {
+                let reference = DTCG.reference_to(&root, &color_group_path(&target.tokens, &[]));
+                DTCG.insert_token(
+                    &mut root,
+                    &color_group_path(&alias_set, &[]),
+                    json!({ "$type": "color", "$value": format!("{{{reference}}}") }),
+                )?;
+            }
+        }
+    }
+
+    Ok(Value::Object(root))
+}
+
+fn dimension(px: f64) -> Value {
+    json!({ "$type": "dimension", "$value": Length::Px(px).to_string() })
+}
+
+fn number(value: f64) -> Value {
+    json!(format_number(value).parse::<f64>().unwrap_or(value))
+}
@@ -0,0 +1,1076 @@
+//! Reads DTCG JSON (e.g. exported from Tokens Studio) into pieces of [crate::input::SystemInput]
+//! and token sets. Tokens are identified by their group path, so `color.blue.500` becomes
+//! `color:blue-500` and `typography.heading.lg` becomes `typography:heading-lg`.
+//! Paths written by [crate::dtcg::export] keep their keys, so the composite at
+//! `text.italic.role/ui.size/sm` is a rule requiring `text italic:true role:ui size:sm`.
+use std::collections::{HashMap, HashSet};
+
+use serde_json::Value;
+
+use crate::{
+    color::input::{ColorExtension, ColorPalette, InputColor, SourceColor},
+    prelude::*,
+    tokens::{aliases::TokenAliasInput, Token, TokenSet},
+    typography::{
+        input::open_type_weight_name,
+        output::{TypographyProperty, TypographyTokensCollector},
+        scalars::FontStyleRule,
+        units::TypographyUnits,
+    },
+};
+
+pub struct DTCGImport {
+    /// The first color with `primary` in its path (or else the first color) is the primary
+    /// color, and the other colors which are not aliases become extensions.
+    /// Palette colors are opaque, so their alpha is dropped (and reported as unsupported).
+    pub color_palette: Option<ColorPalette>,
+    /// Tokens whose `$value` is a reference like `"{color.blue.500}"`
+    pub aliases: Vec<TokenAliasInput>,
+    /// Typography composites as rules requiring `text` and the composite's token,
+    /// or the tokens in its path when it has `key/value` groups
+    pub typography: TypographyTokensCollector,
+    /// Every imported token, with references resolved.
+    /// Dimensions, font families and font weights are only imported through the
+    /// typography composites which reference them, since the system input has no place for them.
+    pub tokens: Vec<DTCGToken>,
+    pub unsupported: Vec<DTCGUnsupported>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DTCGToken {
+    /// e.g. `["color", "blue", "500"]`
+    pub path: Vec<String>,
+    /// e.g. `color:blue-500`
+    pub token: Token,
+    pub value: DTCGValue,
+    /// Set when the `$value` is a reference to another token
+    pub alias_of: Option<Token>,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum DTCGValue {
+    /// e.g. `"#0a84ff"` or `"#0a84ff80"` with alpha
+    Color {
+        hex: String,
+    },
+    Dimension {
+        px: f64,
+    },
+    FontFamily {
+        names: Vec<String>,
+    },
+    FontWeight {
+        weight: usize,
+    },
+    Typography {
+        font_family: Vec<String>,
+        font_size_px: f64,
+        font_weight: usize,
+        /// The `fontWeight` when given by name, e.g. Tokens Studio exports Figma's `"Semi Bold"`
+        font_style_name: Option<String>,
+        letter_spacing_px: f64,
+        line_height_px: f64,
+    },
+}
+
+#[derive(Debug)]
+pub struct DTCGUnsupported {
+    /// e.g. `"border.focus"`
+    pub path: String,
+    pub type_name: Option<String>,
+    pub reason: String,
+}
+
+impl std::fmt::Display for DTCGUnsupported {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.type_name {
+            Some(type_name) => write!(f, "{} ({type_name}): {}", self.path, self.reason),
+            None => write!(f, "{}: {}", self.path, self.reason),
+        }
+    }
+}
+
+struct RawToken {
+    path: Vec<String>,
+    /// Including the `$type` inherited from groups
+    type_name: Option<String>,
+    value: Value,
+    description: Option<String>,
+}
+
+impl DTCGImport {
+    /// A complete [crate::input::SystemInput] with the imported colors and aliases.
+    /// DTCG has no font metrics or size scale to describe families and text roles with,
+    /// so imports with typography composites are rejected here, and only
+    /// available as [DTCGImport::typography] rules.
+    pub fn system_input_json(&self) -> Result<Value> {
+        let composites = self
+            .tokens
+            .iter()
+            .filter(|token| matches!(token.value, DTCGValue::Typography { .. }))
+            .map(|token| token.path.join("."))
+            .collect::<Vec<_>>();
+        if !composites.is_empty() {
+            anyhow::bail!(
+                "typography composites ({}) can't be described by the typography input, \
+                which needs font metrics and a size scale, so use the imported typography rules",
+                composites.join(", ")
+            );
+        }
+
+        let system_input = serde_json::json!({
+            "color_palette": self.color_palette,
+            "aliases": self.aliases,
+            "typography": {
+                "Families": [],
+                "FontSizeScale": {
+                    "FontSizes": [],
+                    "Equation": {
+                        "Multiplier": {
+                            "base_px": TypographyUnits::default().root_font_size_px(),
+                            "multiplier": 1.0,
+                        },
+                    },
+                },
+                "TextRoles": [],
+                "Extensions": {},
+            },
+        });
+        serde_json::from_value::<crate::input::SystemInput>(system_input.clone())
+            .context("reading back the imported system input")?;
+        Ok(system_input)
+    }
+}
+
+pub fn import_dtcg(json: &Value) -> Result<DTCGImport> {
+    let mut unsupported_children = Vec::new();
+    let mut raw_tokens = Vec::new();
+    collect_raw_tokens(
+        json,
+        &mut Vec::new(),
+        None,
+        &mut raw_tokens,
+        &mut unsupported_children,
+    )?;
+    let resolver = Resolver {
+        by_path: raw_tokens
+            .iter()
+            .enumerate()
+            .map(|(idx, raw)| (raw.path.join("."), idx))
+            .collect(),
+        raw_tokens: &raw_tokens,
+    };
+
+    let mut import = DTCGImport {
+        color_palette: None,
+        aliases: Vec::new(),
+        typography: TypographyTokensCollector::default(),
+        tokens: Vec::new(),
+        unsupported: unsupported_children,
+    };
+
+    let resolved = (0..raw_tokens.len())
+        .map(|idx| resolver.resolve_token(idx, &mut Vec::new()))
+        .collect::<Vec<_>>();
+    let mut referenced_by_typography = HashSet::new();
+    for (idx, raw) in raw_tokens.iter().enumerate() {
+        if matches!(&resolved[idx], Ok((Some(type_name), _)) if type_name == "typography") {
+            resolver.collect_references(&raw.value, &mut referenced_by_typography);
+        }
+    }
+
+    for (idx, (raw, resolved)) in raw_tokens.iter().zip(resolved).enumerate() {
+        let mut unsupported = |reason: String| {
+            import.unsupported.push(DTCGUnsupported {
+                path: raw.path.join("."),
+                type_name: raw.type_name.clone(),
+                reason,
+            })
+        };
+
+        let (type_name, value) = match resolved {
+            Ok((Some(type_name), value)) => (type_name, value),
+            Ok((None, _)) => {
+                unsupported("no `$type` on the token or its groups".to_string());
+                continue;
+            }
+            Err(err) => {
+                unsupported(format!("{err:#}"));
+                continue;
+            }
+        };
+
+        match parse_value(&type_name, &value) {
+            Ok(
+                DTCGValue::Dimension { .. }
+                | DTCGValue::FontFamily { .. }
+                | DTCGValue::FontWeight { .. },
+            ) if !referenced_by_typography.contains(&idx) => unsupported(
+                "only imported when referenced by a typography composite, \
+                since the system input has no standalone dimensions or fonts"
+                    .to_string(),
+            ),
+            Ok(value) => import.tokens.push(DTCGToken {
+                token: identity_token(&raw.path, &type_name),
+                path: raw.path.clone(),
+                value,
+                // resolving already found the target, and `$root` references use its path
+                alias_of: reference_target(&raw.value)
+                    .and_then(|target| resolver.find(target).ok())
+                    .map(|target_idx| identity_token(&raw_tokens[target_idx].path, &type_name)),
+                description: raw.description.clone(),
+            }),
+            Err(err) => unsupported(format!("{err:#}")),
+        }
+    }
+
+    for token in &import.tokens {
+        if let Some(target) = &token.alias_of {
+            import.aliases.push(TokenAliasInput {
+                token: token.token.to_string(),
+                references: target.to_string(),
+            });
+        }
+
+        if let (
+            None,
+            DTCGValue::Typography {
+                font_family,
+                font_size_px,
+                font_weight,
+                font_style_name,
+                letter_spacing_px,
+                line_height_px,
+            },
+        ) = (&token.alias_of, &token.value)
+        {
+            let mut filter =
+                path_tokens(&token.path).unwrap_or_else(|| TokenSet::from([token.token.clone()]));
+            filter.insert(Token::of_kind("text"));
+            import.typography.push_all(
+                filter.iter(),
+                [
+                    TypographyProperty::FontFamily {
+                        family_name: font_family.first().cloned().unwrap_or_default().into(),
+                    },
+                    TypographyProperty::FontSize { px: *font_size_px },
+                    TypographyProperty::LineHeight {
+                        px: *line_height_px,
+                    },
+                    TypographyProperty::LetterSpacing {
+                        px: *letter_spacing_px,
+                        em: *letter_spacing_px / *font_size_px,
+                    },
+                    TypographyProperty::FontStyle(font_style_rule(
+                        *font_weight,
+                        font_style_name.as_deref(),
+                    )),
+                ],
+            )?;
+        }
+    }
+
+    import.color_palette = color_palette(&import.tokens, &mut import.unsupported);
+
+    Ok(import)
+}
+
+fn collect_raw_tokens(
+    group: &Value,
+    path: &mut Vec<String>,
+    inherited_type: Option<&str>,
+    found: &mut Vec<RawToken>,
+    unsupported: &mut Vec<DTCGUnsupported>,
+) -> Result<()> {
+    let object = group
+        .as_object()
+        .ok_or_else(|| anyhow::anyhow!("expected a token or group at {:?}", path.join(".")))?;
+    let type_name = object
+        .get("$type")
+        .and_then(Value::as_str)
+        .or(inherited_type);
+
+    if let Some(value) = object.get("$value") {
+        found.push(RawToken {
+            path: path.clone(),
+            type_name: type_name.map(str::to_string),
+            value: value.clone(),
+            description: object
+                .get("$description")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+        });
+        return Ok(());
+    }
+
+    // a token sharing its path with this group, e.g. `color.blue.$root` is `color.blue`
+    if let Some(root) = object.get(ROOT_KEY) {
+        match root.get("$value") {
+            Some(_) => collect_raw_tokens(root, path, type_name, found, unsupported)?,
+            None => unsupported.push(DTCGUnsupported {
+                path: format!("{}.{ROOT_KEY}", path.join(".")),
+                type_name: type_name.map(str::to_string),
+                reason: format!("expected a token, but found {root}"),
+            }),
+        }
+    }
+
+    // e.g. `$type`, `$description`, or Tokens Studio's `$themes` and `$metadata`
+    for (key, child) in object.iter().filter(|(key, _)| !key.starts_with('$')) {
+        path.push(key.clone());
+        if child.is_object() {
+            collect_raw_tokens(child, path, type_name, found, unsupported)?;
+        } else {
+            unsupported.push(DTCGUnsupported {
+                path: path.join("."),
+                type_name: type_name.map(str::to_string),
+                reason: format!("expected a token or group, but found {child}"),
+            });
+        }
+        path.pop();
+    }
+
+    Ok(())
+}
+
+/// DTCG's key for a token which shares its path with a group
+const ROOT_KEY: &str = "$root";
+
+/// e.g. `"{color.blue.500}"` is `Some("color.blue.500")`
+fn reference_target(value: &Value) -> Option<&str> {
+    value.as_str()?.trim().strip_prefix('{')?.strip_suffix('}')
+}
+
+struct Resolver<'a> {
+    raw_tokens: &'a [RawToken],
+    by_path: HashMap<String, usize>,
+}
+
+impl<'a> Resolver<'a> {
+    /// The token's `$type` (or the type of what it references) and its `$value`
+    /// with every reference replaced.
+    fn resolve_token(&self, idx: usize, chain: &mut Vec<usize>) -> Result<(Option<String>, Value)> {
+        if chain.contains(&idx) {
+            anyhow::bail!(
+                "references form a cycle: {}",
+                chain
+                    .iter()
+                    .chain([&idx])
+                    .map(|idx| self.raw_tokens[*idx].path.join("."))
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+            );
+        }
+
+        chain.push(idx);
+        let raw = &self.raw_tokens[idx];
+        let resolved = match reference_target(&raw.value) {
+            Some(target) => {
+                let (type_name, value) = self.resolve_token(self.find(target)?, chain)?;
+                (raw.type_name.clone().or(type_name), value)
+            }
+            None => (
+                raw.type_name.clone(),
+                self.resolve_nested(&raw.value, chain)?,
+            ),
+        };
+        chain.pop();
+
+        Ok(resolved)
+    }
+
+    /// Composites like typography may reference other tokens in their fields.
+    fn resolve_nested(&self, value: &Value, chain: &mut Vec<usize>) -> Result<Value> {
+        Ok(match value {
+            Value::String(_) => match reference_target(value) {
+                Some(target) => self.resolve_token(self.find(target)?, chain)?.1,
+                None => value.clone(),
+            },
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| self.resolve_nested(item, chain))
+                    .collect::<Result<_>>()?,
+            ),
+            Value::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(key, field)| Ok((key.clone(), self.resolve_nested(field, chain)?)))
+                    .collect::<Result<_>>()?,
+            ),
+            other => other.clone(),
+        })
+    }
+
+    /// Every token referenced by the value, and by those tokens in turn
+    fn collect_references(&self, value: &Value, found: &mut HashSet<usize>) {
+        match value {
+            Value::String(_) => {
+                if let Some(idx) = reference_target(value).and_then(|target| self.find(target).ok())
+                {
+                    if found.insert(idx) {
+                        self.collect_references(&self.raw_tokens[idx].value, found);
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.collect_references(item, found);
+                }
+            }
+            Value::Object(fields) => {
+                for field in fields.values() {
+                    self.collect_references(field, found);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// e.g. `color.blue.$root` finds the token at `color.blue`
+    fn find(&self, target: &str) -> Result<usize> {
+        let path = target
+            .strip_suffix(ROOT_KEY)
+            .and_then(|path| path.strip_suffix('.'))
+            .unwrap_or(target);
+        self.by_path
+            .get(path)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("references unknown token {{{target}}}"))
+    }
+}
+
+fn parse_value(type_name: &str, value: &Value) -> Result<DTCGValue> {
+    Ok(match type_name {
+        "color" => DTCGValue::Color {
+            hex: parse_color(value)?,
+        },
+        "dimension" => DTCGValue::Dimension {
+            px: parse_dimension_px(value)?,
+        },
+        "fontFamily" => DTCGValue::FontFamily {
+            names: parse_font_family(value)?,
+        },
+        "fontWeight" => DTCGValue::FontWeight {
+            weight: parse_font_weight(value)?,
+        },
+        "typography" => {
+            let field = |name: &str| {
+                value
+                    .get(name)
+                    .ok_or_else(|| anyhow::anyhow!("typography is missing {name:?}"))
+            };
+            let font_size_px = parse_dimension_px(field("fontSize")?).context("fontSize")?;
+            DTCGValue::Typography {
+                font_family: parse_font_family(field("fontFamily")?).context("fontFamily")?,
+                font_size_px,
+                font_weight: match value.get("fontWeight") {
+                    Some(weight) => parse_font_weight(weight).context("fontWeight")?,
+                    None => 400,
+                },
+                font_style_name: value
+                    .get("fontWeight")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|name| name.parse::<f64>().is_err())
+                    .map(str::to_string),
+                letter_spacing_px: match value.get("letterSpacing") {
+                    Some(spacing) => parse_dimension_px(spacing).context("letterSpacing")?,
+                    None => 0.0,
+                },
+                line_height_px: parse_line_height_px(field("lineHeight")?, font_size_px)
+                    .context("lineHeight")?,
+            }
+        }
+        "shadow" | "cubicBezier" | "duration" => {
+            anyhow::bail!("`$type` {type_name:?} has no equivalent in the system input")
+        }
+        other => anyhow::bail!("`$type` {other:?} is not supported"),
+    })
+}
+
+/// Accepts `"#rgb"`, `"#rrggbb"` and `"#rrggbbaa"`, or the newer object
+/// with `"hex"` or sRGB `"components"`.
+fn parse_color(value: &Value) -> Result<String> {
+    if let Some(hex) = value.get("hex") {
+        return parse_color(hex);
+    }
+    if let Some(components) = value.get("components") {
+        let color_space = value.get("colorSpace").and_then(Value::as_str);
+        if color_space != Some("srgb") {
+            anyhow::bail!("color space {color_space:?} is not supported, only \"srgb\"");
+        }
+        let channels = components
+            .as_array()
+            .map(|channels| {
+                channels
+                    .iter()
+                    .filter_map(Value::as_f64)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|channels| channels.len() == 3)
+            .ok_or_else(|| {
+                anyhow::anyhow!("expected three sRGB components, but found {components}")
+            })?;
+        let alpha = value.get("alpha").and_then(Value::as_f64).unwrap_or(1.0);
+        let to_hex =
+            |channel: f64| format!("{:02x}", (channel.clamp(0.0, 1.0) * 255.0).round() as u8);
+        let mut hex = format!(
+            "#{}{}{}",
+            to_hex(channels[0]),
+            to_hex(channels[1]),
+            to_hex(channels[2])
+        );
+        if alpha < 1.0 {
+            hex += &to_hex(alpha);
+        }
+        return Ok(hex);
+    }
+
+    let text = value
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("expected a hex color, but found {value}"))?;
+    let digits = text
+        .trim()
+        .strip_prefix('#')
+        .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| {
+            anyhow::anyhow!("expected a hex color like \"#0a84ff\", but found {text:?}")
+        })?
+        .to_ascii_lowercase();
+    match digits.len() {
+        3 => Ok(format!(
+            "#{}",
+            digits.chars().flat_map(|c| [c, c]).collect::<String>()
+        )),
+        6 | 8 => Ok(format!("#{digits}")),
+        _ => anyhow::bail!("expected a hex color like \"#0a84ff\", but found {text:?}"),
+    }
+}
+
+/// Accepts `"16px"`, `"1rem"` (using the default root font size), `0`, or `{ "value": 16, "unit": "px" }`.
+fn parse_dimension_px(value: &Value) -> Result<f64> {
+    let (number, unit) = match value {
+        Value::Number(number) if number.as_f64() == Some(0.0) => return Ok(0.0),
+        Value::String(text) => split_number_unit(text)?,
+        Value::Object(_) => (
+            value
+                .get("value")
+                .and_then(Value::as_f64)
+                .ok_or_else(|| anyhow::anyhow!("expected a numeric \"value\" in {value}"))?,
+            value
+                .get("unit")
+                .and_then(Value::as_str)
+                .unwrap_or("px")
+                .to_string(),
+        ),
+        _ => anyhow::bail!("expected a dimension like \"16px\", but found {value}"),
+    };
+    match unit.as_str() {
+        "px" => Ok(number),
+        "rem" => Ok(number * TypographyUnits::default().root_font_size_px()),
+        "" if number == 0.0 => Ok(0.0),
+        other => {
+            anyhow::bail!("dimension unit {other:?} is not supported, only \"px\" and \"rem\"")
+        }
+    }
+}
+
+/// DTCG describes line heights as a number multiplied by the font size,
+/// but Tokens Studio also exports percentages and dimensions.
+fn parse_line_height_px(value: &Value, font_size_px: f64) -> Result<f64> {
+    match value {
+        Value::Number(number) => Ok(number.as_f64().unwrap_or_default() * font_size_px),
+        Value::String(text) if text.trim().ends_with('%') => {
+            let (percent, _) = split_number_unit(text)?;
+            Ok(percent / 100.0 * font_size_px)
+        }
+        other => parse_dimension_px(other),
+    }
+}
+
+/// e.g. `"-0.5px"` is `(-0.5, "px")`
+fn split_number_unit(text: &str) -> Result<(f64, String)> {
+    let text = text.trim();
+    let unit_start = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(text.len());
+    let number = text[..unit_start]
+        .parse::<f64>()
+        .with_context(|| format!("expected a number with a unit, but found {text:?}"))?;
+    Ok((number, text[unit_start..].trim().to_string()))
+}
+
+fn parse_font_family(value: &Value) -> Result<Vec<String>> {
+    match value {
+        Value::String(name) => Ok(name
+            .split(',')
+            .map(|name| {
+                name.trim()
+                    .trim_matches(|c| c == '"' || c == '\'')
+                    .to_string()
+            })
+            .filter(|name| !name.is_empty())
+            .collect()),
+        Value::Array(names) => names
+            .iter()
+            .map(|name| {
+                name.as_str()
+                    .map(str::to_string)
+                    .ok_or_else(|| anyhow::anyhow!("expected a font family name, but found {name}"))
+            })
+            .collect(),
+        _ => anyhow::bail!("expected a font family name or list of names, but found {value}"),
+    }
+}
+
+/// Accepts a number between 1 and 1000, or the names listed by DTCG like `"semi-bold"`.
+fn parse_font_weight(value: &Value) -> Result<usize> {
+    let weight = match value {
+        Value::Number(number) => number.as_f64().unwrap_or_default(),
+        Value::String(name) => match name.trim().parse::<f64>() {
+            Ok(weight) => weight,
+            Err(_) => return font_weight_by_name(name),
+        },
+        _ => anyhow::bail!("expected a font weight, but found {value}"),
+    };
+    if weight.fract() != 0.0 || !(1.0..=1000.0).contains(&weight) {
+        anyhow::bail!("expected a font weight between 1 and 1000, but found {value}");
+    }
+    Ok(weight as usize)
+}
+
+fn font_weight_by_name(name: &str) -> Result<usize> {
+    let normalized = name.to_ascii_lowercase().replace(['-', ' ', '_'], "");
+    Ok(match normalized.as_str() {
+        "thin" | "hairline" => 100,
+        "extralight" | "ultralight" => 200,
+        "light" => 300,
+        "normal" | "regular" | "book" => 400,
+        "medium" => 500,
+        "semibold" | "demibold" => 600,
+        "bold" => 700,
+        "extrabold" | "ultrabold" => 800,
+        "black" | "heavy" => 900,
+        "extrablack" | "ultrablack" => 950,
+        _ => anyhow::bail!("unknown font weight name {name:?}"),
+    })
+}
+
+/// Figma finds font styles by name, so a weight given by name (like Tokens Studio's `"Semi Bold"`)
+/// is used as is, and other weights use the OpenType names of their weight class.
+fn font_style_rule(weight: usize, name_opt: Option<&str>) -> FontStyleRule {
+    let suffix = match name_opt {
+        Some(name) => format!(" {name}"),
+        None => format!(" {}", open_type_weight_name(weight)),
+    };
+    FontStyleRule(serde_json::json!({
+        "CSS": [{ "FontWeight": weight }],
+        "Figma": { "FontSuffix": [suffix, 1] },
+    }))
+}
+
+/// `color.blue.500` is `color:blue-500`, while a top-level `primary` color is `color:primary`.
+fn identity_token(path: &[String], type_name: &str) -> Token {
+    let segment = |segment: &str| -> String {
+        segment.trim().replace(
+            |c: char| c.is_whitespace() || matches!(c, ':' | ',' | '/'),
+            "-",
+        )
+    };
+    let type_key = || -> String {
+        type_name
+            .chars()
+            .flat_map(|c| match c.is_ascii_uppercase() {
+                true => vec!['-', c.to_ascii_lowercase()],
+                false => vec![c],
+            })
+            .collect()
+    };
+    match path {
+        [] => Token::Kind(type_key().into()),
+        [name] => Token::Value(type_key().into(), segment(name).into()),
+        [key, rest @ ..] => Token::Value(
+            segment(key).into(),
+            rest.iter()
+                .map(|name| segment(name))
+                .collect::<Vec<_>>()
+                .join("-")
+                .into(),
+        ),
+    }
+}
+
+/// Reads groups named by [crate::tokens::nested::group_path], so `text.italic.role/ui` is
+/// `text italic:true role:ui`. Paths without any `key/value` group are `None`.
+fn path_tokens(path: &[String]) -> Option<TokenSet> {
+    if !path.iter().any(|segment| segment.contains('/')) {
+        return None;
+    }
+    let tokens =
+        path.iter()
+            .enumerate()
+            .map(|(idx, segment)| match (idx, segment.split_once('/')) {
+                (_, Some((key, value))) => {
+                    Token::Value(key.to_string().into(), value.to_string().into())
+                }
+                (0, None) => Token::Kind(segment.clone().into()),
+                (_, None) => Token::Value(segment.clone().into(), "true".into()),
+            });
+    Some(TokenSet::from(tokens))
+}
+
+fn color_palette(
+    tokens: &[DTCGToken],
+    unsupported: &mut Vec<DTCGUnsupported>,
+) -> Option<ColorPalette> {
+    let colors: Vec<(&DTCGToken, &str)> = tokens
+        .iter()
+        .filter_map(|token| match &token.value {
+            DTCGValue::Color { hex } => Some((token, hex.as_str())),
+            _ => None,
+        })
+        .collect();
+    let primary_idx = colors
+        .iter()
+        .position(|(token, _)| {
+            token
+                .path
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case("primary"))
+        })
+        .unwrap_or(0);
+    let mut opaque_hex = |token: &DTCGToken, hex: &str| -> String {
+        // `#rrggbbaa`
+        if hex.len() == 9 {
+            unsupported.push(DTCGUnsupported {
+                path: token.path.join("."),
+                type_name: Some("color".to_string()),
+                reason: format!("alpha of {hex} is dropped, since the color palette is opaque"),
+            });
+        }
+        hex.chars().take(7).collect()
+    };
+    let (primary_token, primary_hex) = colors.get(primary_idx)?;
+
+    Some(ColorPalette {
+        Primary: InputColor::Hex(opaque_hex(primary_token, primary_hex)),
+        Extensions: colors
+            .iter()
+            .enumerate()
+            .filter(|(idx, (token, _))| *idx != primary_idx && token.alias_of.is_none())
+            .map(|(_, (token, hex))| ColorExtension {
+                Token: match &token.token {
+                    Token::Kind(name) | Token::Value(_, name) => name.to_string(),
+                },
+                Source: SourceColor::Exactly(InputColor::Hex(opaque_hex(token, hex))),
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+    use crate::{
+        color::output::{generate_colors, ColorExport},
+        input::SystemInput,
+        tokens::{
+            aliases::TokenAliases,
+            nested::{color_group_path, group_path},
+            query::Query,
+            split_tokens,
+        },
+        typography::{
+            output::{generate_typography_all_tokens, TypographyExport},
+            styles::materialize_text_styles,
+        },
+    };
+
+    fn fixtures_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src/dtcg/fixtures")
+    }
+
+    fn import_fixture(name: &str) -> DTCGImport {
+        let json = serde_json::from_str::<Value>(
+            &std::fs::read_to_string(fixtures_dir().join(name)).unwrap(),
+        )
+        .unwrap();
+        import_dtcg(&json).unwrap()
+    }
+
+    /// Each token as `path => token (alias of)`, to compare imports at a glance
+    fn token_lines(import: &DTCGImport) -> Vec<String> {
+        import
+            .tokens
+            .iter()
+            .map(|token| match &token.alias_of {
+                Some(target) => format!(
+                    "{} => {} (alias of {})",
+                    token.path.join("."),
+                    token.token.to_string(),
+                    target.to_string()
+                ),
+                None => format!("{} => {}", token.path.join("."), token.token.to_string()),
+            })
+            .collect()
+    }
+
+    fn unsupported_lines(import: &DTCGImport) -> Vec<String> {
+        import.unsupported.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn aliases_and_references() {
+        let import = import_fixture("aliases.json");
+        assert_eq!(
+            token_lines(&import),
+            [
+                "color.link => color:link (alias of color:primary)",
+                "color.primary => color:primary",
+                "font.family.base => font:family-base",
+                "font.weight.regular => font:weight-regular",
+                "font.weight.semi => font:weight-semi",
+                "font.weight.strong => font:weight-strong (alias of font:weight-semi)",
+                "size.base => size:base",
+                "size.body => size:body (alias of size:base)",
+                "size.lg => size:lg",
+                "typography.body => typography:body",
+                "typography.heading => typography:heading",
+                "typography.title => typography:title (alias of typography:heading)",
+            ]
+        );
+        assert_eq!(
+            unsupported_lines(&import),
+            [
+                "color.loop-a (color): references form a cycle: color.loop-a -> color.loop-b -> color.loop-a",
+                "color.loop-b (color): references form a cycle: color.loop-b -> color.loop-a -> color.loop-b",
+                "color.missing (color): references unknown token {color.nope}",
+                "font.family.unused (fontFamily): only imported when referenced by a typography composite, since the system input has no standalone dimensions or fonts",
+                "shadow.card (shadow): `$type` \"shadow\" has no equivalent in the system input",
+            ]
+        );
+        let aliases = TokenAliases::from_input(&import.aliases).unwrap();
+        assert_eq!(
+            aliases.resolve(&TokenSet::from([Token::of_value("typography", "title")])),
+            TokenSet::from([Token::of_value("typography", "heading")])
+        );
+
+        // references in composites resolve through aliases, and keep weight names for Figma
+        assert_eq!(
+            import.tokens[10].value,
+            DTCGValue::Typography {
+                font_family: vec!["Inter".to_string(), "sans-serif".to_string()],
+                font_size_px: 20.0,
+                font_weight: 600,
+                font_style_name: Some("Semi Bold".to_string()),
+                letter_spacing_px: -0.2,
+                line_height_px: 28.0,
+            }
+        );
+        let err = import.system_input_json().unwrap_err();
+        assert!(
+            err.to_string().starts_with(
+                "typography composites (typography.body, typography.heading, typography.title) \
+                can't be described by the typography input"
+            ),
+            "{err}"
+        );
+
+        let typography = TypographyExport::from(import.typography);
+        let body = typography.as_lookup().query_with_set(&TokenSet::from([
+            Token::of_kind("text"),
+            Token::of_value("typography", "body"),
+        ]));
+        assert_eq!(
+            body.properties,
+            [
+                &TypographyProperty::FontFamily {
+                    family_name: "Inter".into()
+                },
+                &TypographyProperty::FontSize { px: 16.0 },
+                &TypographyProperty::LineHeight { px: 24.0 },
+                &TypographyProperty::LetterSpacing { px: 0.0, em: 0.0 },
+                &TypographyProperty::FontStyle(font_style_rule(400, None)),
+            ]
+        );
+    }
+
+    #[test]
+    fn root_tokens() {
+        let import = import_fixture("root.json");
+        assert_eq!(
+            token_lines(&import),
+            [
+                "color.accent => color:accent (alias of color:blue)",
+                "color.blue => color:blue",
+                "color.blue.40 => color:blue-40",
+                "color.primary => color:primary (alias of color:blue-40)",
+            ]
+        );
+        assert_eq!(
+            unsupported_lines(&import),
+            [
+                r##"color.broken.$root (color): expected a token, but found {"nested":{"$value":"#ffffff"}}"##
+            ]
+        );
+
+        let system_input = import.system_input_json().unwrap();
+        assert_eq!(
+            system_input["color_palette"],
+            serde_json::json!({
+                "Primary": { "Hex": "#0060df" },
+                "Extensions": [
+                    { "Token": "blue", "Source": { "Exactly": { "Hex": "#0a84ff" } } },
+                    { "Token": "blue-40", "Source": { "Exactly": { "Hex": "#0060df" } } },
+                ],
+            })
+        );
+        assert_eq!(
+            system_input["aliases"],
+            serde_json::json!([
+                { "token": "color:accent", "references": "color:blue" },
+                { "token": "color:primary", "references": "color:blue-40" },
+            ])
+        );
+    }
+
+    /// The platform fixture exported with [crate::dtcg::export::export_dtcg]
+    fn export_fixture(query: &Query) -> (TypographyExport, TokenAliases, ColorExport, Value) {
+        let input = serde_json::from_str::<SystemInput>(
+            &std::fs::read_to_string(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("src/platforms/fixtures/system-input.json"),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let all_tokens: TypographyExport = generate_typography_all_tokens(&input.typography)
+            .unwrap()
+            .into();
+        let aliases =
+            TokenAliases::from_input(input.aliases.as_deref().unwrap_or_default()).unwrap();
+        let colors = generate_colors(input.color_palette.as_ref().unwrap()).unwrap();
+        let exported =
+            crate::dtcg::export::export_dtcg(&all_tokens, Some(&colors), &aliases, query).unwrap();
+        (all_tokens, aliases, colors, exported)
+    }
+
+    /// Imports the exported platform fixture back
+    #[test]
+    fn export_round_trip() {
+        let query = Query::parse("text role:* size:*").unwrap();
+        let (all_tokens, aliases, colors, exported) = export_fixture(&query);
+        let import = import_dtcg(&exported).unwrap();
+
+        // exported numbers are rounded to 4 decimals, and line heights are multiplied back
+        let close = |imported: f64, expected: f64| (imported - expected).abs() < 0.01;
+        let text_styles = materialize_text_styles(&all_tokens, &aliases, &query).unwrap();
+        assert!(!text_styles.is_empty());
+        for style in &text_styles {
+            let path = group_path(&style.tokens, &[]);
+            let imported = import
+                .tokens
+                .iter()
+                .find(|token| token.path == path)
+                .unwrap_or_else(|| panic!("{path:?} was not imported"));
+            let DTCGValue::Typography {
+                font_family,
+                font_size_px,
+                font_weight,
+                font_style_name,
+                letter_spacing_px,
+                line_height_px,
+            } = &imported.value
+            else {
+                panic!("{path:?} imported as {:?}", imported.value);
+            };
+            assert_eq!(
+                font_family,
+                std::slice::from_ref(&style.family_name),
+                "{path:?}"
+            );
+            assert!(close(*font_size_px, style.font_size_px), "{path:?}");
+            assert_eq!(*font_weight, style.font_weight, "{path:?}");
+            assert_eq!(*font_style_name, None, "{path:?}");
+            assert!(
+                close(
+                    *letter_spacing_px,
+                    style.letter_spacing_px.unwrap_or_default()
+                ),
+                "{path:?}"
+            );
+            assert!(
+                close(*line_height_px, style.line_height_px.unwrap()),
+                "{path:?}"
+            );
+        }
+
+        for color in &colors.colors {
+            let path = color_group_path(&color.tokens, &[]);
+            let imported = import.tokens.iter().find(|token| token.path == path);
+            assert_eq!(
+                imported.map(|token| &token.value),
+                Some(&DTCGValue::Color {
+                    hex: color.hex.clone()
+                }),
+                "{path:?}"
+            );
+        }
+        assert!(
+            import
+                .aliases
+                .iter()
+                .any(|alias| alias.token == "color:accent"
+                    && alias.references == "color:blue-tone-40"),
+            "{:?}",
+            import.aliases
+        );
+
+        // the dimensions of each rule are only written for other tools
+        for unsupported in &import.unsupported {
+            assert!(
+                [
+                    "font-family.",
+                    "font-size.",
+                    "line-height.",
+                    "letter-spacing."
+                ]
+                .iter()
+                .any(|group| unsupported.path.starts_with(group)),
+                "{unsupported}"
+            );
+        }
+    }
+
+    /// Flags like `italic:true` and values like `weight:700` keep their keys in the exported
+    /// groups, so the imported rule requires the same tokens as the exported text style.
+    #[test]
+    fn italic_and_weight_round_trip() {
+        let query = Query::parse("text role:ui size:sm weight:700 italic:true").unwrap();
+        let (all_tokens, aliases, _, exported) = export_fixture(&query);
+        assert_eq!(
+            exported["text"]["italic"]["role/ui"]["size/sm"]["weight/700"]["$type"], "typography",
+            "{exported:#}"
+        );
+
+        let import = import_dtcg(&exported).unwrap();
+        let typography = TypographyExport::from(import.typography);
+        let tokens =
+            TokenSet::from(split_tokens("text role:ui size:sm weight:700 italic:true").unwrap());
+        let [style] = &materialize_text_styles(&all_tokens, &aliases, &query).unwrap()[..] else {
+            panic!("expected a single text style");
+        };
+        assert_eq!(style.tokens, tokens);
+        let properties = typography.as_lookup().query_with_set(&tokens).properties;
+        assert!(
+            properties.contains(&&TypographyProperty::FontSize {
+                px: style.font_size_px
+            }),
+            "{properties:?}"
+        );
+    }
+}
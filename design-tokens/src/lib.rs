@@ -0,0 +1,56 @@
+#![allow(unused)]
+use std::process::Command;
+
+pub(crate) mod prelude {
+    pub use anyhow::{Context, Error, Result};
+    pub use derive_codegen::Codegen;
+    pub use serde::{Deserialize, Serialize};
+    pub use std::borrow::Cow;
+
+    pub type Cowstr = Cow<'static, str>;
+
+    pub fn align_to(value: f64, to_opt: Option<f64>) -> f64 {
+        match to_opt {
+            Some(to) => (value / to).round() * to,
+            None => value,
+        }
+    }
+}
+
+pub(crate) mod input {
+    use crate::prelude::*;
+
+    #[derive(Debug, Deserialize, Codegen)]
+    #[codegen(tags = "input")]
+    pub struct SystemInput {
+        pub color_palette: Option<crate::color::input::ColorPalette>,
+        /// e.g. `role:heading` referencing `role:ui weight:600`
+        pub aliases: Option<Vec<crate::tokens::aliases::TokenAliasInput>>,
+        pub typography: crate::typography::input::BaseTypographyInput,
+    }
+}
+
+mod cli;
+mod color;
+mod dtcg;
+pub mod platforms;
+mod style_dictionary;
+pub mod tokens;
+mod typography;
+
+/// Entry point of the `design-tokens` binary
+#[doc(hidden)]
+pub fn run_cli() {
+    cli::run();
+}
+
+/// TODO
+pub mod lengths {
+    use crate::prelude::*;
+
+    #[derive(Codegen, Serialize)]
+    #[codegen(tags = "lengths")]
+    pub struct LengthLogical {
+        pixels: f64,
+    }
+}
@@ -0,0 +1,364 @@
+//! Source code for each platform, generated from materialized text styles and colors.
+use std::path::{Path, PathBuf};
+
+use crate::{
+    color::input::InputColor,
+    prelude::*,
+    tokens::{split_tokens, TokenSet},
+    typography::styles::MaterializedTextStyle,
+};
+
+pub mod android;
+pub mod compose;
+pub mod css_preprocessors;
+pub mod flutter;
+pub mod rust;
+pub mod slint;
+pub mod swift;
+pub mod typescript;
+pub mod unity;
+
+pub struct GeneratedFile {
+    /// Relative to the output directory, e.g. `"Colors.xcassets/primary.colorset/Contents.json"`
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+pub fn write_files(output_dir: &Path, files: &[GeneratedFile]) -> Result<()> {
+    for file in files {
+        let path = output_dir.join(&file.path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("creating directory {parent:?}"))?;
+        }
+        std::fs::write(&path, &file.contents).with_context(|| format!("writing {path:?}"))?;
+    }
+    Ok(())
+}
+
+/// Lowercase alphanumeric words, e.g. `["text", "ui", "sm"]` for the path of `text role:ui size:sm`,
+/// or `["on", "primary"]` for `on-primary`.
+pub fn words<S: AsRef<str>>(segments: impl IntoIterator<Item = S>) -> Vec<String> {
+    segments
+        .into_iter()
+        .flat_map(|segment| {
+            segment
+                .as_ref()
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(str::to_ascii_lowercase)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// e.g. `"text role:ui size:sm"`, for doc comments and keys of generated code
+pub fn token_set_string(tokens: &TokenSet) -> String {
+    tokens
+        .iter()
+        .map(|token| token.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// e.g. `textUiSm`
+pub fn camel_case(words: &[String]) -> String {
+    let mut ident = pascal_case(words);
+    if let Some(first) = ident.get_mut(..1) {
+        first.make_ascii_lowercase();
+    }
+    ident
+}
+
+/// e.g. `TextUiSm`
+pub fn pascal_case(words: &[String]) -> String {
+    identifier(
+        words
+            .iter()
+            .map(|word| {
+                let mut word = word.clone();
+                if let Some(first) = word.get_mut(..1) {
+                    first.make_ascii_uppercase();
+                }
+                word
+            })
+            .collect::<String>(),
+    )
+}
+
+/// e.g. `text_ui_sm`
+pub fn snake_case(words: &[String]) -> String {
+    identifier(words.join("_"))
+}
+
+/// e.g. `text-ui-sm`
+pub fn kebab_case(words: &[String]) -> String {
+    identifier(words.join("-"))
+}
+
+/// Identifiers can't start with a digit in most languages, e.g. `40` is `_40`.
+fn identifier(ident: String) -> String {
+    match ident.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("_{ident}"),
+        false => ident,
+    }
+}
+
+/// Material 3 type roles, as in Compose's `Typography` and Flutter's `TextTheme`
+pub const MATERIAL_TYPE_ROLES: [&str; 15] = [
+    "displayLarge",
+    "displayMedium",
+    "displaySmall",
+    "headlineLarge",
+    "headlineMedium",
+    "headlineSmall",
+    "titleLarge",
+    "titleMedium",
+    "titleSmall",
+    "bodyLarge",
+    "bodyMedium",
+    "bodySmall",
+    "labelLarge",
+    "labelMedium",
+    "labelSmall",
+];
+
+/// Finds the text style for each configured Material type role,
+/// e.g. `("bodyLarge", "text role:content size:lg")`.
+pub fn material_type_roles<'a, 'r>(
+    text_styles: &'a [MaterializedTextStyle],
+    type_roles: impl IntoIterator<Item = (&'r str, &'r str)>,
+) -> Result<Vec<(&'r str, &'a MaterializedTextStyle)>> {
+    type_roles
+        .into_iter()
+        .map(|(type_role, tokens)| {
+            if !MATERIAL_TYPE_ROLES.contains(&type_role) {
+                anyhow::bail!(
+                    "unknown Material type role ({type_role:?}), expected one of {MATERIAL_TYPE_ROLES:?}"
+                );
+            }
+            let tokens = TokenSet::from(split_tokens(tokens).with_context(|| {
+                format!("while reading the tokens for Material type role ({type_role:?})")
+            })?);
+            let style = text_styles
+                .iter()
+                .find(|style| style.tokens == tokens)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Material type role ({type_role:?}) uses {tokens:?}, which is not one of the materialized text styles"
+                    )
+                })?;
+            Ok((type_role, style))
+        })
+        .collect()
+}
+
+/// e.g. `"#0a84ff"` is `[10, 132, 255]`
+pub fn hex_rgb(hex: &str) -> Result<[u8; 3]> {
+    let [_, red, green, blue] = InputColor::Hex(hex.to_string()).to_argb()?;
+    Ok([red, green, blue])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        color::output::{generate_colors, ColorExport},
+        input::SystemInput,
+        tokens::{aliases::TokenAliases, query::Query},
+        typography::{
+            output::{generate_typography_all_tokens, TypographyExport},
+            styles::materialize_text_styles,
+        },
+    };
+
+    const QUERY: &str = "text role:* size:*";
+
+    struct Fixture {
+        input: SystemInput,
+        all_tokens: TypographyExport,
+        aliases: TokenAliases,
+        colors: Option<ColorExport>,
+        text_styles: Vec<MaterializedTextStyle>,
+    }
+
+    fn fixtures_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src/platforms/fixtures")
+    }
+
+    /// `fixtures/system-input.json` with the `role:heading` alias for a bold italic style
+    fn fixture() -> Fixture {
+        let input = serde_json::from_str::<SystemInput>(
+            &std::fs::read_to_string(fixtures_dir().join("system-input.json")).unwrap(),
+        )
+        .unwrap();
+        let all_tokens: TypographyExport = generate_typography_all_tokens(&input.typography)
+            .unwrap()
+            .into();
+        let aliases =
+            TokenAliases::from_input(input.aliases.as_deref().unwrap_or_default()).unwrap();
+        let colors = input
+            .color_palette
+            .as_ref()
+            .map(generate_colors)
+            .transpose()
+            .unwrap();
+        let text_styles =
+            materialize_text_styles(&all_tokens, &aliases, &Query::parse(QUERY).unwrap()).unwrap();
+        Fixture {
+            input,
+            all_tokens,
+            aliases,
+            colors,
+            text_styles,
+        }
+    }
+
+    fn extension<T: serde::de::DeserializeOwned>(fixture: &Fixture) -> T {
+        serde_json::from_value(fixture.input.typography.Extensions.clone()).unwrap()
+    }
+
+    /// Compares with the files in `fixtures/{platform}`, which `UPDATE_GOLDEN=1` rewrites.
+    fn assert_golden(platform: &str, files: Vec<GeneratedFile>) {
+        let golden_dir = fixtures_dir().join(platform);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            let _ = std::fs::remove_dir_all(&golden_dir);
+            write_files(&golden_dir, &files).unwrap();
+            return;
+        }
+        for file in &files {
+            let path = golden_dir.join(&file.path);
+            let golden = std::fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("reading {path:?} ({err}), try UPDATE_GOLDEN=1"));
+            assert_eq!(
+                file.contents, golden,
+                "{path:?} changed, check the diff and run with UPDATE_GOLDEN=1"
+            );
+        }
+    }
+
+    #[test]
+    fn android() {
+        let fixture = fixture();
+        let files =
+            android::generate_android(&fixture.text_styles, fixture.colors.as_ref()).unwrap();
+        assert_golden("android", files);
+    }
+
+    #[test]
+    fn compose() {
+        let fixture = fixture();
+        let config = extension::<compose::compose_config::TypographyExtensionInput>(&fixture)
+            .Compose
+            .unwrap_or_default();
+        let files =
+            compose::generate_compose(&fixture.text_styles, fixture.colors.as_ref(), &config)
+                .unwrap();
+        assert_golden("compose", files);
+    }
+
+    #[test]
+    fn css_preprocessors() {
+        let fixture = fixture();
+        let files = css_preprocessors::generate_scss_and_less(
+            &fixture.text_styles,
+            &fixture.input.typography.Families,
+            fixture.colors.as_ref(),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_golden("css_preprocessors", files);
+    }
+
+    /// The `text` mixin joins `role`, `size`, `weight` and `italic` in that order
+    #[test]
+    fn scss_text_style_keys() {
+        let fixture = fixture();
+        let query = Query::parse("text role:ui size:sm weight:700 italic:true").unwrap();
+        let text_styles =
+            materialize_text_styles(&fixture.all_tokens, &fixture.aliases, &query).unwrap();
+        let files = css_preprocessors::generate_scss_and_less(
+            &text_styles,
+            &fixture.input.typography.Families,
+            None,
+            &Default::default(),
+        )
+        .unwrap();
+        assert!(files[0]
+            .contents
+            .contains("\"role:ui size:sm weight:700 italic:true\": ("));
+    }
+
+    #[test]
+    fn flutter() {
+        let fixture = fixture();
+        let config = extension::<flutter::flutter_config::TypographyExtensionInput>(&fixture)
+            .Flutter
+            .unwrap_or_default();
+        let files =
+            flutter::generate_flutter(&fixture.text_styles, fixture.colors.as_ref(), &config)
+                .unwrap();
+        assert_golden("flutter", files);
+    }
+
+    #[test]
+    fn rust() {
+        let fixture = fixture();
+        let config = extension::<rust::rust_config::TypographyExtensionInput>(&fixture)
+            .Rust
+            .unwrap_or_default();
+        let files =
+            rust::generate_rust(&fixture.text_styles, fixture.colors.as_ref(), &config).unwrap();
+        assert_eq!(
+            rust::generate_rust_for_build_script(
+                &std::fs::read_to_string(fixtures_dir().join("system-input.json")).unwrap(),
+                QUERY
+            )
+            .unwrap(),
+            files[0].contents
+        );
+        assert_golden("rust", files);
+    }
+
+    #[test]
+    fn slint() {
+        let fixture = fixture();
+        let files = slint::generate_slint(&fixture.text_styles, fixture.colors.as_ref()).unwrap();
+        assert_golden("slint", files);
+    }
+
+    #[test]
+    fn swift() {
+        let fixture = fixture();
+        let files = swift::generate_swift(
+            &fixture.text_styles,
+            &fixture.input.typography.Families,
+            fixture.colors.as_ref(),
+        )
+        .unwrap();
+        assert!(files[0]
+            .contents
+            .contains("Font.custom(\"Inter-BoldItalic\", size: "));
+        assert_golden("swift", files);
+    }
+
+    #[test]
+    fn typescript() {
+        let fixture = fixture();
+        let files = typescript::generate_typescript(
+            &fixture.all_tokens,
+            &fixture.text_styles,
+            fixture.colors.as_ref(),
+            &fixture.aliases,
+        )
+        .unwrap();
+        assert_golden("typescript", files);
+    }
+
+    #[test]
+    fn unity() {
+        let fixture = fixture();
+        let files = unity::generate_unity(&fixture.text_styles, fixture.colors.as_ref()).unwrap();
+        assert_golden("unity", files);
+    }
+}
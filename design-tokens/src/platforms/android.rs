@@ -0,0 +1,125 @@
+//! Android resources for View-based screens: `dimens.xml` and `TextAppearance` styles for each
+//! materialized text style, and `colors.xml` with the dark scheme in `values-night/`.
+//!
+//! Font families are referenced as `@font/<family>` (e.g. `@font/roboto_mono`),
+//! so the app provides a font resource of that name.
+use std::{fmt::Write, path::PathBuf};
+
+use super::{pascal_case, snake_case, token_set_string, words, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    typography::{styles::MaterializedTextStyle, units::format_number},
+};
+
+const XML_HEADER: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<!-- Generated by design-tokens, do not edit. -->";
+
+/// `res/values/dimens.xml`, `res/values/styles.xml`, `res/values/colors.xml`, and `res/values-night/colors.xml`
+pub fn generate_android(
+    text_styles: &[MaterializedTextStyle],
+    colors: Option<&ColorExport>,
+) -> Result<Vec<GeneratedFile>> {
+    let mut dimens = String::new();
+    let mut styles = String::new();
+    writeln!(dimens, "{XML_HEADER}")?;
+    writeln!(dimens, "<resources>")?;
+    writeln!(styles, "{XML_HEADER}")?;
+    writeln!(styles, "<resources>")?;
+    for style in text_styles {
+        let doc = token_set_string(&style.tokens);
+        let path = words(style.tokens.path());
+        let name = snake_case(&path);
+
+        writeln!(dimens, "    <!-- {doc} -->")?;
+        writeln!(
+            dimens,
+            "    <dimen name=\"{name}_font_size\">{}sp</dimen>",
+            format_number(style.font_size_px)
+        )?;
+        if let Some(px) = style.line_height_px {
+            writeln!(
+                dimens,
+                "    <dimen name=\"{name}_line_height\">{}sp</dimen>",
+                format_number(px)
+            )?;
+        }
+
+        writeln!(styles, "    <!-- {doc} -->")?;
+        writeln!(
+            styles,
+            "    <style name=\"TextAppearance.DesignTokens.{}\" parent=\"android:TextAppearance\">",
+            pascal_case(&path)
+        )?;
+        let mut item = |attr: &str, value: String| {
+            writeln!(styles, "        <item name=\"android:{attr}\">{value}</item>")
+        };
+        item(
+            "fontFamily",
+            format!("@font/{}", snake_case(&words([&style.family_name]))),
+        )?;
+        item("textSize", format!("@dimen/{name}_font_size"))?;
+        if style.line_height_px.is_some() {
+            item("lineHeight", format!("@dimen/{name}_line_height"))?;
+        }
+        if let Some(px) = style.letter_spacing_px {
+            // Android letter spacing is in ems
+            item("letterSpacing", format_number(px / style.font_size_px))?;
+        }
+        item("textFontWeight", style.font_weight.to_string())?;
+        item(
+            "textStyle",
+            if style.italic { "italic" } else { "normal" }.to_string(),
+        )?;
+        writeln!(styles, "    </style>")?;
+    }
+    writeln!(dimens, "</resources>")?;
+    writeln!(styles, "</resources>")?;
+
+    let mut files = vec![
+        GeneratedFile {
+            path: PathBuf::from("res/values/dimens.xml"),
+            contents: dimens,
+        },
+        GeneratedFile {
+            path: PathBuf::from("res/values/styles.xml"),
+            contents: styles,
+        },
+    ];
+
+    if let Some(colors) = colors {
+        let mut light = String::new();
+        let mut night = String::new();
+        writeln!(light, "{XML_HEADER}")?;
+        writeln!(light, "<resources>")?;
+        writeln!(night, "{XML_HEADER}")?;
+        writeln!(night, "<resources>")?;
+        // Scheme roles share a name between `values` and `values-night`, e.g. `color_on_primary`
+        for role in colors.scheme_roles() {
+            let name = snake_case(&words(["color", role.role]));
+            writeln!(light, "    <color name=\"{name}\">{}</color>", role.light_hex)?;
+            writeln!(night, "    <color name=\"{name}\">{}</color>", role.dark_hex)?;
+        }
+        // Tones and exact colors are the same in both, e.g. `color_primary_40`
+        for color in &colors.colors {
+            if color.tokens.get("scheme").is_some() {
+                continue;
+            }
+            let name = snake_case(&words(
+                std::iter::once("color".to_string()).chain(color.tokens.path()),
+            ));
+            writeln!(light, "    <color name=\"{name}\">{}</color>", color.hex)?;
+        }
+        writeln!(light, "</resources>")?;
+        writeln!(night, "</resources>")?;
+        files.push(GeneratedFile {
+            path: PathBuf::from("res/values/colors.xml"),
+            contents: light,
+        });
+        files.push(GeneratedFile {
+            path: PathBuf::from("res/values-night/colors.xml"),
+            contents: night,
+        });
+    }
+
+    Ok(files)
+}
@@ -0,0 +1,217 @@
+//! Jetpack Compose `TextStyle`s for each materialized text style, light and dark
+//! `ColorScheme`s for the scheme roles, and a Material `Typography` built from the text styles.
+//!
+//! Sizes are written in `sp` treating one CSS pixel as one `sp`, and letter spacing in `em`
+//! so it follows the user's font scale along with the font size.
+use std::{fmt::Write, path::PathBuf};
+
+use super::{camel_case, hex_rgb, material_type_roles, token_set_string, words, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    typography::{styles::MaterializedTextStyle, units::format_number},
+};
+
+pub mod compose_config {
+    use crate::prelude::*;
+
+    // Must be named `TypographyExtensionInput` to ensure it merges with other typography extensions
+    #[derive(Debug, Codegen, Deserialize)]
+    #[codegen(tags = "compose-input")]
+    #[codegen(ts_interface_merge)]
+    #[allow(non_snake_case)]
+    pub struct TypographyExtensionInput {
+        #[serde(alias = "compose")]
+        pub Compose: Option<ComposeConfig>,
+    }
+
+    #[derive(Codegen, Debug, Default, Deserialize)]
+    #[codegen(tags = "compose-input")]
+    #[allow(non_snake_case)]
+    pub struct ComposeConfig {
+        /// e.g. `"com.example.designsystem"`. Defaults to `"designtokens"`.
+        pub PackageOption: Option<String>,
+        /// Which text style fills each Material type role, e.g. `bodyLarge` from `text role:content size:lg`.
+        /// Roles left out keep the Material defaults.
+        pub MaterialTypographyOption: Option<Vec<ComposeMaterialTypeRole>>,
+    }
+
+    #[derive(Codegen, Debug, Deserialize)]
+    #[codegen(tags = "compose-input")]
+    #[allow(non_snake_case)]
+    pub struct ComposeMaterialTypeRole {
+        /// e.g. `"bodyLarge"` or `"labelSmall"`
+        pub Role: String,
+        /// e.g. `"text role:content size:lg"`
+        pub Tokens: String,
+    }
+}
+
+/// Parameters of Material 3's `lightColorScheme` and `darkColorScheme`.
+/// Other scheme roles (like `shadow`) have no place in a `ColorScheme`.
+const COLOR_SCHEME_ROLES: [&str; 27] = [
+    "primary",
+    "onPrimary",
+    "primaryContainer",
+    "onPrimaryContainer",
+    "inversePrimary",
+    "secondary",
+    "onSecondary",
+    "secondaryContainer",
+    "onSecondaryContainer",
+    "tertiary",
+    "onTertiary",
+    "tertiaryContainer",
+    "onTertiaryContainer",
+    "background",
+    "onBackground",
+    "surface",
+    "onSurface",
+    "surfaceVariant",
+    "onSurfaceVariant",
+    "inverseSurface",
+    "inverseOnSurface",
+    "error",
+    "onError",
+    "errorContainer",
+    "onErrorContainer",
+    "outline",
+    "outlineVariant",
+];
+
+/// `DesignTokens.kt`
+pub fn generate_compose(
+    text_styles: &[MaterializedTextStyle],
+    colors: Option<&ColorExport>,
+    config: &compose_config::ComposeConfig,
+) -> Result<Vec<GeneratedFile>> {
+    let package = config.PackageOption.as_deref().unwrap_or("designtokens");
+    let mut kotlin = String::new();
+    writeln!(kotlin, "// Generated by design-tokens, do not edit.")?;
+    writeln!(kotlin, "package {package}")?;
+    writeln!(kotlin)?;
+    for import in [
+        "androidx.compose.material3.Typography",
+        "androidx.compose.material3.darkColorScheme",
+        "androidx.compose.material3.lightColorScheme",
+        "androidx.compose.ui.graphics.Color",
+        "androidx.compose.ui.text.TextStyle",
+        "androidx.compose.ui.text.font.FontFamily",
+        "androidx.compose.ui.text.font.FontStyle",
+        "androidx.compose.ui.text.font.FontWeight",
+        "androidx.compose.ui.unit.em",
+        "androidx.compose.ui.unit.sp",
+    ] {
+        writeln!(kotlin, "import {import}")?;
+    }
+
+    // Font families are loaded by the app (e.g. from `res/font`), so they are passed in
+    let mut family_names = text_styles
+        .iter()
+        .map(|style| style.family_name.as_str())
+        .collect::<Vec<_>>();
+    family_names.sort_unstable();
+    family_names.dedup();
+    writeln!(kotlin)?;
+    writeln!(kotlin, "class DesignTokenFonts(")?;
+    for family_name in &family_names {
+        writeln!(
+            kotlin,
+            "    val {}: FontFamily,",
+            camel_case(&words([family_name]))
+        )?;
+    }
+    writeln!(kotlin, ")")?;
+
+    writeln!(kotlin)?;
+    writeln!(kotlin, "class TextStyles(fonts: DesignTokenFonts) {{")?;
+    for style in text_styles {
+        let doc = token_set_string(&style.tokens);
+        writeln!(kotlin, "    /** `{doc}` */")?;
+        writeln!(
+            kotlin,
+            "    val {} = TextStyle(",
+            camel_case(&words(style.tokens.path()))
+        )?;
+        writeln!(
+            kotlin,
+            "        fontFamily = fonts.{},",
+            camel_case(&words([&style.family_name]))
+        )?;
+        writeln!(
+            kotlin,
+            "        fontSize = {}.sp,",
+            format_number(style.font_size_px)
+        )?;
+        if let Some(px) = style.line_height_px {
+            writeln!(kotlin, "        lineHeight = {}.sp,", format_number(px))?;
+        }
+        if let Some(px) = style.letter_spacing_px {
+            writeln!(
+                kotlin,
+                "        letterSpacing = {}.em,",
+                format_number(px / style.font_size_px)
+            )?;
+        }
+        writeln!(
+            kotlin,
+            "        fontWeight = FontWeight({}),",
+            style.font_weight
+        )?;
+        writeln!(
+            kotlin,
+            "        fontStyle = FontStyle.{},",
+            if style.italic { "Italic" } else { "Normal" }
+        )?;
+        writeln!(kotlin, "    )")?;
+    }
+    writeln!(kotlin, "}}")?;
+
+    writeln!(kotlin)?;
+    writeln!(
+        kotlin,
+        "fun materialTypography(textStyles: TextStyles) = Typography("
+    )?;
+    let type_roles = config
+        .MaterialTypographyOption
+        .iter()
+        .flatten()
+        .map(|type_role| (type_role.Role.as_str(), type_role.Tokens.as_str()));
+    for (type_role, style) in material_type_roles(text_styles, type_roles)? {
+        writeln!(
+            kotlin,
+            "    {type_role} = textStyles.{},",
+            camel_case(&words(style.tokens.path()))
+        )?;
+    }
+    writeln!(kotlin, ")")?;
+
+    if let Some(colors) = colors {
+        let roles = colors.scheme_roles();
+        for (name, function, dark) in [
+            ("LightColorScheme", "lightColorScheme", false),
+            ("DarkColorScheme", "darkColorScheme", true),
+        ] {
+            writeln!(kotlin)?;
+            writeln!(kotlin, "val {name} = {function}(")?;
+            for role in roles.iter() {
+                let name = camel_case(&words([role.role]));
+                if !COLOR_SCHEME_ROLES.contains(&name.as_str()) {
+                    continue;
+                }
+                let hex = if dark { role.dark_hex } else { role.light_hex };
+                let [red, green, blue] = hex_rgb(hex)?;
+                writeln!(
+                    kotlin,
+                    "    {name} = Color(0xFF{red:02X}{green:02X}{blue:02X}),"
+                )?;
+            }
+            writeln!(kotlin, ")")?;
+        }
+    }
+
+    Ok(vec![GeneratedFile {
+        path: PathBuf::from("DesignTokens.kt"),
+        contents: kotlin,
+    }])
+}
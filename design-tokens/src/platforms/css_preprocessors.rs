@@ -0,0 +1,214 @@
+//! SCSS and Less variables for each materialized text style and color, using the same
+//! units and font stacks as the CSS export (see [css_config::CSSTypographyConfig]).
+//!
+//! ```scss
+//! @import "design-tokens";
+//!
+//! .caption {
+//!   @include text(ui, sm);
+//!   color: map-get(map-get($color-schemes, light), on-surface);
+//! }
+//! ```
+use std::{collections::BTreeMap, fmt::Write, path::PathBuf};
+
+use super::{kebab_case, token_set_string, words, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    typography::{
+        css::{css_config, css_export::css_font_family},
+        input::FontFamilyInfo,
+        styles::MaterializedTextStyle,
+        units::TypographyUnits,
+    },
+};
+
+/// Keys of the `text` mixin's arguments, in the order it joins them into a `$text-styles` key
+const SCSS_MIXIN_KEYS: [&str; 4] = ["role", "size", "weight", "italic"];
+
+/// `_design-tokens.scss` and `design-tokens.less`
+pub fn generate_scss_and_less(
+    text_styles: &[MaterializedTextStyle],
+    families: &[FontFamilyInfo],
+    colors: Option<&ColorExport>,
+    css_config: &css_config::CSSTypographyConfig,
+) -> Result<Vec<GeneratedFile>> {
+    let default_units = TypographyUnits::default();
+    let units = css_config.UnitsOption.as_ref().unwrap_or(&default_units);
+
+    let mut scss = String::new();
+    let mut less = String::new();
+    writeln!(scss, "// Generated by design-tokens, do not edit.")?;
+    writeln!(less, "// Generated by design-tokens, do not edit.")?;
+
+    let mut scss_text_styles = String::new();
+    let mut less_mixins = String::new();
+    let mixin_keys = SCSS_MIXIN_KEYS.map(String::from);
+    for style in text_styles {
+        let doc = token_set_string(&style.tokens);
+        let name = kebab_case(&words(style.tokens.path()));
+        let declarations = declarations(style, families, units)
+            .with_context(|| format!("creating declarations for ({:?})", style.tokens))?;
+
+        writeln!(scss)?;
+        writeln!(scss, "// {doc}")?;
+        writeln!(less)?;
+        writeln!(less, "// {doc}")?;
+        for (property, value) in &declarations {
+            writeln!(scss, "${name}-{property}: {value};")?;
+            writeln!(less, "@{name}-{property}: {value};")?;
+        }
+
+        // e.g. `"role:ui size:sm weight:700 italic:true"`, the key the `text` mixin builds from its arguments
+        let key = style
+            .tokens
+            .values_ordered_by(&mixin_keys)
+            .into_iter()
+            .map(|(key, value)| format!("{key}:{value}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(scss_text_styles, "  {key:?}: (")?;
+        for (property, _) in &declarations {
+            writeln!(scss_text_styles, "    {property}: ${name}-{property},")?;
+        }
+        writeln!(scss_text_styles, "  ),")?;
+
+        writeln!(less_mixins)?;
+        writeln!(less_mixins, ".{name}() {{")?;
+        for (property, _) in &declarations {
+            writeln!(less_mixins, "  {property}: @{name}-{property};")?;
+        }
+        writeln!(less_mixins, "}}")?;
+    }
+
+    writeln!(scss)?;
+    writeln!(scss, "$text-styles: (")?;
+    scss += &scss_text_styles;
+    writeln!(scss, ");")?;
+    writeln!(scss)?;
+    writeln!(
+        scss,
+        "/// e.g. `@include text(ui, sm)` or `@include text(ui, sm, 700, true)`."
+    )?;
+    writeln!(
+        scss,
+        "/// Weights and italics need text styles with those tokens, e.g. `text role:* size:* weight:*`."
+    )?;
+    writeln!(
+        scss,
+        "@mixin text($role, $size, $weight: null, $italic: false) {{"
+    )?;
+    writeln!(scss, "  $key: \"role:#{{$role}} size:#{{$size}}\";")?;
+    writeln!(scss, "  @if $weight != null {{")?;
+    writeln!(scss, "    $key: \"#{{$key}} weight:#{{$weight}}\";")?;
+    writeln!(scss, "  }}")?;
+    writeln!(scss, "  @if $italic {{")?;
+    writeln!(scss, "    $key: \"#{{$key}} italic:true\";")?;
+    writeln!(scss, "  }}")?;
+    writeln!(scss, "  $style: map-get($text-styles, $key);")?;
+    writeln!(scss, "  @if $style == null {{")?;
+    writeln!(
+        scss,
+        "    @error \"No text style for #{{$key}}, check that the text styles query includes its tokens\";"
+    )?;
+    writeln!(scss, "  }}")?;
+    writeln!(scss, "  @each $property, $value in $style {{")?;
+    writeln!(scss, "    #{{$property}}: $value;")?;
+    writeln!(scss, "  }}")?;
+    writeln!(scss, "}}")?;
+    less += &less_mixins;
+
+    if let Some(colors) = colors {
+        writeln!(scss)?;
+        writeln!(less)?;
+        // e.g. `color:primary tone:40` is `$color-primary-40`, and `40` of `"primary"` in `$color-tones`
+        let mut tones = BTreeMap::<&str, Vec<(&str, String)>>::new();
+        let mut schemes = BTreeMap::<&str, Vec<(&str, String)>>::new();
+        for color in &colors.colors {
+            let name = kebab_case(&words(
+                std::iter::once("color".to_string()).chain(color.tokens.path()),
+            ));
+            writeln!(scss, "${name}: {};", color.hex)?;
+            writeln!(less, "@{name}: {};", color.hex)?;
+            let Some(color_value) = color.tokens.get("color") else {
+                continue;
+            };
+            if let Some(tone) = color.tokens.get("tone") {
+                tones
+                    .entry(color_value)
+                    .or_default()
+                    .push((tone, format!("${name}")));
+            } else if let Some(scheme) = color.tokens.get("scheme") {
+                schemes
+                    .entry(scheme)
+                    .or_default()
+                    .push((color_value, format!("${name}")));
+            }
+        }
+        for (map_name, map) in [("color-tones", &tones), ("color-schemes", &schemes)] {
+            writeln!(scss)?;
+            writeln!(scss, "${map_name}: (")?;
+            for (outer, entries) in map {
+                writeln!(scss, "  {outer:?}: (")?;
+                for (inner, variable) in entries {
+                    writeln!(scss, "    {inner}: {variable},")?;
+                }
+                writeln!(scss, "  ),")?;
+            }
+            writeln!(scss, ");")?;
+        }
+    }
+
+    Ok(vec![
+        GeneratedFile {
+            path: PathBuf::from("_design-tokens.scss"),
+            contents: scss,
+        },
+        GeneratedFile {
+            path: PathBuf::from("design-tokens.less"),
+            contents: less,
+        },
+    ])
+}
+
+/// The same properties the CSS export declares for a text style
+fn declarations(
+    style: &MaterializedTextStyle,
+    families: &[FontFamilyInfo],
+    units: &TypographyUnits,
+) -> Result<Vec<(&'static str, String)>> {
+    let mut declarations = vec![
+        (
+            "font-family",
+            css_font_family(&style.family_name, families)?,
+        ),
+        (
+            "font-size",
+            units.font_size(style.font_size_px).to_string(),
+        ),
+    ];
+    if let Some(px) = style.line_height_px {
+        declarations.push((
+            "line-height",
+            units.line_height(px, style.font_size_px).to_string(),
+        ));
+    }
+    if let Some(px) = style.letter_spacing_px {
+        declarations.push((
+            "letter-spacing",
+            units.letter_spacing(px, style.font_size_px).to_string(),
+        ));
+    }
+    declarations.push(("font-weight", style.font_weight.to_string()));
+    declarations.push((
+        "font-style",
+        if style.italic { "italic" } else { "normal" }.to_string(),
+    ));
+    if !style.font_variation_settings.is_empty() {
+        declarations.push((
+            "font-variation-settings",
+            style.font_variation_settings.join(", "),
+        ));
+    }
+    Ok(declarations)
+}
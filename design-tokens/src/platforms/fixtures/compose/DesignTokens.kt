@@ -0,0 +1,139 @@
+// Generated by design-tokens, do not edit.
+package com.example.tokens
+
+import androidx.compose.material3.Typography
+import androidx.compose.material3.darkColorScheme
+import androidx.compose.material3.lightColorScheme
+import androidx.compose.ui.graphics.Color
+import androidx.compose.ui.text.TextStyle
+import androidx.compose.ui.text.font.FontFamily
+import androidx.compose.ui.text.font.FontStyle
+import androidx.compose.ui.text.font.FontWeight
+import androidx.compose.ui.unit.em
+import androidx.compose.ui.unit.sp
+
+class DesignTokenFonts(
+    val inter: FontFamily,
+    val robotoMono: FontFamily,
+)
+
+class TextStyles(fonts: DesignTokenFonts) {
+    /** `text role:code size:lg` */
+    val textCodeLg = TextStyle(
+        fontFamily = fonts.robotoMono,
+        fontSize = 21.0989.sp,
+        lineHeight = 28.sp,
+        letterSpacing = 0.em,
+        fontWeight = FontWeight(400),
+        fontStyle = FontStyle.Normal,
+    )
+    /** `text role:code size:sm` */
+    val textCodeSm = TextStyle(
+        fontFamily = fonts.robotoMono,
+        fontSize = 12.6593.sp,
+        lineHeight = 16.sp,
+        letterSpacing = 0.em,
+        fontWeight = FontWeight(400),
+        fontStyle = FontStyle.Normal,
+    )
+    /** `text role:heading size:lg` */
+    val textHeadingLg = TextStyle(
+        fontFamily = fonts.inter,
+        fontSize = 20.625.sp,
+        lineHeight = 28.sp,
+        letterSpacing = -0.0116.em,
+        fontWeight = FontWeight(700),
+        fontStyle = FontStyle.Italic,
+    )
+    /** `text role:heading size:sm` */
+    val textHeadingSm = TextStyle(
+        fontFamily = fonts.inter,
+        fontSize = 12.375.sp,
+        lineHeight = 16.sp,
+        letterSpacing = 0.0091.em,
+        fontWeight = FontWeight(700),
+        fontStyle = FontStyle.Italic,
+    )
+    /** `text role:ui size:lg` */
+    val textUiLg = TextStyle(
+        fontFamily = fonts.inter,
+        fontSize = 20.625.sp,
+        lineHeight = 28.sp,
+        letterSpacing = -0.0116.em,
+        fontWeight = FontWeight(400),
+        fontStyle = FontStyle.Normal,
+    )
+    /** `text role:ui size:sm` */
+    val textUiSm = TextStyle(
+        fontFamily = fonts.inter,
+        fontSize = 12.375.sp,
+        lineHeight = 16.sp,
+        letterSpacing = 0.0091.em,
+        fontWeight = FontWeight(400),
+        fontStyle = FontStyle.Normal,
+    )
+}
+
+fun materialTypography(textStyles: TextStyles) = Typography(
+    bodyLarge = textStyles.textUiLg,
+)
+
+val LightColorScheme = lightColorScheme(
+    primary = Color(0xFF6750A4),
+    onPrimary = Color(0xFFFFFFFF),
+    primaryContainer = Color(0xFFE9DDFF),
+    onPrimaryContainer = Color(0xFF22005D),
+    secondary = Color(0xFF625B71),
+    onSecondary = Color(0xFFFFFFFF),
+    secondaryContainer = Color(0xFFE8DEF8),
+    onSecondaryContainer = Color(0xFF1E192B),
+    tertiary = Color(0xFF7E5260),
+    onTertiary = Color(0xFFFFFFFF),
+    tertiaryContainer = Color(0xFFFFD9E3),
+    onTertiaryContainer = Color(0xFF31101D),
+    error = Color(0xFFBA1A1A),
+    onError = Color(0xFFFFFFFF),
+    errorContainer = Color(0xFFFFDAD6),
+    onErrorContainer = Color(0xFF410002),
+    background = Color(0xFFFFFBFF),
+    onBackground = Color(0xFF1C1B1E),
+    surface = Color(0xFFFFFBFF),
+    onSurface = Color(0xFF1C1B1E),
+    surfaceVariant = Color(0xFFE7E0EB),
+    onSurfaceVariant = Color(0xFF49454E),
+    outline = Color(0xFF7A757F),
+    outlineVariant = Color(0xFFCAC4CF),
+    inverseSurface = Color(0xFF313033),
+    inverseOnSurface = Color(0xFFF4EFF4),
+    inversePrimary = Color(0xFFCFBCFF),
+)
+
+val DarkColorScheme = darkColorScheme(
+    primary = Color(0xFFCFBCFF),
+    onPrimary = Color(0xFF381E72),
+    primaryContainer = Color(0xFF4F378A),
+    onPrimaryContainer = Color(0xFFE9DDFF),
+    secondary = Color(0xFFCBC2DB),
+    onSecondary = Color(0xFF332D41),
+    secondaryContainer = Color(0xFF4A4458),
+    onSecondaryContainer = Color(0xFFE8DEF8),
+    tertiary = Color(0xFFEFB8C8),
+    onTertiary = Color(0xFF4A2532),
+    tertiaryContainer = Color(0xFF633B48),
+    onTertiaryContainer = Color(0xFFFFD9E3),
+    error = Color(0xFFFFB4AB),
+    onError = Color(0xFF690005),
+    errorContainer = Color(0xFF93000A),
+    onErrorContainer = Color(0xFFFFB4AB),
+    background = Color(0xFF1C1B1E),
+    onBackground = Color(0xFFE6E1E6),
+    surface = Color(0xFF1C1B1E),
+    onSurface = Color(0xFFE6E1E6),
+    surfaceVariant = Color(0xFF49454E),
+    onSurfaceVariant = Color(0xFFCAC4CF),
+    outline = Color(0xFF948F99),
+    outlineVariant = Color(0xFF49454E),
+    inverseSurface = Color(0xFFE6E1E6),
+    inverseOnSurface = Color(0xFF313033),
+    inversePrimary = Color(0xFF6750A4),
+)
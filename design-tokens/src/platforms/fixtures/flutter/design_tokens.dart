@@ -0,0 +1,136 @@
+// Generated by design-tokens, do not edit.
+import 'package:flutter/material.dart';
+
+class DesignTextStyles {
+  DesignTextStyles._();
+
+  /// `text role:code size:lg`
+  static const textCodeLg = TextStyle(
+    fontFamily: 'Roboto Mono',
+    fontSize: 21.0989,
+    height: 1.3271,
+    letterSpacing: 0,
+    fontWeight: FontWeight.w400,
+    fontStyle: FontStyle.normal,
+  );
+
+  /// `text role:code size:sm`
+  static const textCodeSm = TextStyle(
+    fontFamily: 'Roboto Mono',
+    fontSize: 12.6593,
+    height: 1.2639,
+    letterSpacing: 0,
+    fontWeight: FontWeight.w400,
+    fontStyle: FontStyle.normal,
+  );
+
+  /// `text role:heading size:lg`
+  static const textHeadingLg = TextStyle(
+    fontFamily: 'Inter',
+    fontSize: 20.625,
+    height: 1.3576,
+    letterSpacing: -0.2385,
+    fontWeight: FontWeight.w700,
+    fontStyle: FontStyle.italic,
+  );
+
+  /// `text role:heading size:sm`
+  static const textHeadingSm = TextStyle(
+    fontFamily: 'Inter',
+    fontSize: 12.375,
+    height: 1.2929,
+    letterSpacing: 0.1121,
+    fontWeight: FontWeight.w700,
+    fontStyle: FontStyle.italic,
+  );
+
+  /// `text role:ui size:lg`
+  static const textUiLg = TextStyle(
+    fontFamily: 'Inter',
+    fontSize: 20.625,
+    height: 1.3576,
+    letterSpacing: -0.2385,
+    fontWeight: FontWeight.w400,
+    fontStyle: FontStyle.normal,
+  );
+
+  /// `text role:ui size:sm`
+  static const textUiSm = TextStyle(
+    fontFamily: 'Inter',
+    fontSize: 12.375,
+    height: 1.2929,
+    letterSpacing: 0.1121,
+    fontWeight: FontWeight.w400,
+    fontStyle: FontStyle.normal,
+  );
+}
+
+const designTextTheme = TextTheme(
+  bodyLarge: DesignTextStyles.textUiLg,
+);
+
+const lightColorScheme = ColorScheme(
+  brightness: Brightness.light,
+  primary: Color(0xFF6750A4),
+  onPrimary: Color(0xFFFFFFFF),
+  primaryContainer: Color(0xFFE9DDFF),
+  onPrimaryContainer: Color(0xFF22005D),
+  secondary: Color(0xFF625B71),
+  onSecondary: Color(0xFFFFFFFF),
+  secondaryContainer: Color(0xFFE8DEF8),
+  onSecondaryContainer: Color(0xFF1E192B),
+  tertiary: Color(0xFF7E5260),
+  onTertiary: Color(0xFFFFFFFF),
+  tertiaryContainer: Color(0xFFFFD9E3),
+  onTertiaryContainer: Color(0xFF31101D),
+  error: Color(0xFFBA1A1A),
+  onError: Color(0xFFFFFFFF),
+  errorContainer: Color(0xFFFFDAD6),
+  onErrorContainer: Color(0xFF410002),
+  background: Color(0xFFFFFBFF),
+  onBackground: Color(0xFF1C1B1E),
+  surface: Color(0xFFFFFBFF),
+  onSurface: Color(0xFF1C1B1E),
+  surfaceVariant: Color(0xFFE7E0EB),
+  onSurfaceVariant: Color(0xFF49454E),
+  outline: Color(0xFF7A757F),
+  outlineVariant: Color(0xFFCAC4CF),
+  shadow: Color(0xFF000000),
+  scrim: Color(0xFF000000),
+  inverseSurface: Color(0xFF313033),
+  onInverseSurface: Color(0xFFF4EFF4),
+  inversePrimary: Color(0xFFCFBCFF),
+);
+
+const darkColorScheme = ColorScheme(
+  brightness: Brightness.dark,
+  primary: Color(0xFFCFBCFF),
+  onPrimary: Color(0xFF381E72),
+  primaryContainer: Color(0xFF4F378A),
+  onPrimaryContainer: Color(0xFFE9DDFF),
+  secondary: Color(0xFFCBC2DB),
+  onSecondary: Color(0xFF332D41),
+  secondaryContainer: Color(0xFF4A4458),
+  onSecondaryContainer: Color(0xFFE8DEF8),
+  tertiary: Color(0xFFEFB8C8),
+  onTertiary: Color(0xFF4A2532),
+  tertiaryContainer: Color(0xFF633B48),
+  onTertiaryContainer: Color(0xFFFFD9E3),
+  error: Color(0xFFFFB4AB),
+  onError: Color(0xFF690005),
+  errorContainer: Color(0xFF93000A),
+  onErrorContainer: Color(0xFFFFB4AB),
+  background: Color(0xFF1C1B1E),
+  onBackground: Color(0xFFE6E1E6),
+  surface: Color(0xFF1C1B1E),
+  onSurface: Color(0xFFE6E1E6),
+  surfaceVariant: Color(0xFF49454E),
+  onSurfaceVariant: Color(0xFFCAC4CF),
+  outline: Color(0xFF948F99),
+  outlineVariant: Color(0xFF49454E),
+  shadow: Color(0xFF000000),
+  scrim: Color(0xFF000000),
+  inverseSurface: Color(0xFFE6E1E6),
+  onInverseSurface: Color(0xFF313033),
+  inversePrimary: Color(0xFF6750A4),
+);
@@ -0,0 +1,249 @@
+// Generated by design-tokens, do not edit.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextStyle {
+    pub font_family: &'static str,
+    pub font_size: f32,
+    pub line_height: Option<f32>,
+    pub letter_spacing: f32,
+    pub font_weight: u16,
+    pub italic: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color(pub [u8; 4]);
+
+impl Color {
+    /// Each channel from `0.0` to `1.0`
+    pub fn to_f32(self) -> [f32; 4] {
+        self.0.map(|channel| channel as f32 / 255.0)
+    }
+}
+
+/// `text role:code size:lg`
+pub const TEXT_CODE_LG: TextStyle = TextStyle {
+    font_family: "Roboto Mono",
+    font_size: 21.0989,
+    line_height: Some(28.0),
+    letter_spacing: 0.0,
+    font_weight: 400,
+    italic: false,
+};
+
+/// `text role:code size:sm`
+pub const TEXT_CODE_SM: TextStyle = TextStyle {
+    font_family: "Roboto Mono",
+    font_size: 12.6593,
+    line_height: Some(16.0),
+    letter_spacing: 0.0,
+    font_weight: 400,
+    italic: false,
+};
+
+/// `text role:heading size:lg`
+pub const TEXT_HEADING_LG: TextStyle = TextStyle {
+    font_family: "Inter",
+    font_size: 20.625,
+    line_height: Some(28.0),
+    letter_spacing: -0.2385,
+    font_weight: 700,
+    italic: true,
+};
+
+/// `text role:heading size:sm`
+pub const TEXT_HEADING_SM: TextStyle = TextStyle {
+    font_family: "Inter",
+    font_size: 12.375,
+    line_height: Some(16.0),
+    letter_spacing: 0.1121,
+    font_weight: 700,
+    italic: true,
+};
+
+/// `text role:ui size:lg`
+pub const TEXT_UI_LG: TextStyle = TextStyle {
+    font_family: "Inter",
+    font_size: 20.625,
+    line_height: Some(28.0),
+    letter_spacing: -0.2385,
+    font_weight: 400,
+    italic: false,
+};
+
+/// `text role:ui size:sm`
+pub const TEXT_UI_SM: TextStyle = TextStyle {
+    font_family: "Inter",
+    font_size: 12.375,
+    line_height: Some(16.0),
+    letter_spacing: 0.1121,
+    font_weight: 400,
+    italic: false,
+};
+
+pub mod colors {
+    use super::Color;
+    pub const PRIMARY_0: Color = Color([0, 0, 0, 255]);
+    pub const PRIMARY_10: Color = Color([34, 0, 93, 255]);
+    pub const PRIMARY_20: Color = Color([56, 30, 114, 255]);
+    pub const PRIMARY_30: Color = Color([79, 55, 138, 255]);
+    pub const PRIMARY_40: Color = Color([103, 80, 164, 255]);
+    pub const PRIMARY_50: Color = Color([128, 105, 191, 255]);
+    pub const PRIMARY_60: Color = Color([154, 131, 219, 255]);
+    pub const PRIMARY_70: Color = Color([182, 157, 248, 255]);
+    pub const PRIMARY_80: Color = Color([207, 188, 255, 255]);
+    pub const PRIMARY_90: Color = Color([233, 221, 255, 255]);
+    pub const PRIMARY_95: Color = Color([246, 238, 255, 255]);
+    pub const PRIMARY_99: Color = Color([255, 251, 255, 255]);
+    pub const PRIMARY_100: Color = Color([255, 255, 255, 255]);
+    pub const SECONDARY_0: Color = Color([0, 0, 0, 255]);
+    pub const SECONDARY_10: Color = Color([30, 25, 43, 255]);
+    pub const SECONDARY_20: Color = Color([51, 45, 65, 255]);
+    pub const SECONDARY_30: Color = Color([74, 68, 88, 255]);
+    pub const SECONDARY_40: Color = Color([98, 91, 113, 255]);
+    pub const SECONDARY_50: Color = Color([123, 116, 138, 255]);
+    pub const SECONDARY_60: Color = Color([149, 141, 164, 255]);
+    pub const SECONDARY_70: Color = Color([176, 167, 192, 255]);
+    pub const SECONDARY_80: Color = Color([203, 194, 219, 255]);
+    pub const SECONDARY_90: Color = Color([232, 222, 248, 255]);
+    pub const SECONDARY_95: Color = Color([246, 238, 255, 255]);
+    pub const SECONDARY_99: Color = Color([255, 251, 255, 255]);
+    pub const SECONDARY_100: Color = Color([255, 255, 255, 255]);
+    pub const TERTIARY_0: Color = Color([0, 0, 0, 255]);
+    pub const TERTIARY_10: Color = Color([49, 16, 29, 255]);
+    pub const TERTIARY_20: Color = Color([74, 37, 50, 255]);
+    pub const TERTIARY_30: Color = Color([99, 59, 72, 255]);
+    pub const TERTIARY_40: Color = Color([126, 82, 96, 255]);
+    pub const TERTIARY_50: Color = Color([153, 106, 121, 255]);
+    pub const TERTIARY_60: Color = Color([181, 131, 146, 255]);
+    pub const TERTIARY_70: Color = Color([210, 157, 173, 255]);
+    pub const TERTIARY_80: Color = Color([239, 184, 200, 255]);
+    pub const TERTIARY_90: Color = Color([255, 217, 227, 255]);
+    pub const TERTIARY_95: Color = Color([255, 236, 240, 255]);
+    pub const TERTIARY_99: Color = Color([255, 251, 255, 255]);
+    pub const TERTIARY_100: Color = Color([255, 255, 255, 255]);
+    pub const NEUTRAL_0: Color = Color([0, 0, 0, 255]);
+    pub const NEUTRAL_10: Color = Color([28, 27, 30, 255]);
+    pub const NEUTRAL_20: Color = Color([49, 48, 51, 255]);
+    pub const NEUTRAL_30: Color = Color([72, 70, 74, 255]);
+    pub const NEUTRAL_40: Color = Color([96, 93, 98, 255]);
+    pub const NEUTRAL_50: Color = Color([121, 118, 122, 255]);
+    pub const NEUTRAL_60: Color = Color([147, 143, 148, 255]);
+    pub const NEUTRAL_70: Color = Color([174, 170, 174, 255]);
+    pub const NEUTRAL_80: Color = Color([202, 197, 202, 255]);
+    pub const NEUTRAL_90: Color = Color([230, 225, 230, 255]);
+    pub const NEUTRAL_95: Color = Color([244, 239, 244, 255]);
+    pub const NEUTRAL_99: Color = Color([255, 251, 255, 255]);
+    pub const NEUTRAL_100: Color = Color([255, 255, 255, 255]);
+    pub const NEUTRAL_VARIANT_0: Color = Color([0, 0, 0, 255]);
+    pub const NEUTRAL_VARIANT_10: Color = Color([29, 26, 34, 255]);
+    pub const NEUTRAL_VARIANT_20: Color = Color([50, 47, 56, 255]);
+    pub const NEUTRAL_VARIANT_30: Color = Color([73, 69, 78, 255]);
+    pub const NEUTRAL_VARIANT_40: Color = Color([97, 93, 102, 255]);
+    pub const NEUTRAL_VARIANT_50: Color = Color([122, 117, 127, 255]);
+    pub const NEUTRAL_VARIANT_60: Color = Color([148, 143, 153, 255]);
+    pub const NEUTRAL_VARIANT_70: Color = Color([175, 169, 180, 255]);
+    pub const NEUTRAL_VARIANT_80: Color = Color([202, 196, 207, 255]);
+    pub const NEUTRAL_VARIANT_90: Color = Color([231, 224, 235, 255]);
+    pub const NEUTRAL_VARIANT_95: Color = Color([245, 238, 250, 255]);
+    pub const NEUTRAL_VARIANT_99: Color = Color([255, 251, 255, 255]);
+    pub const NEUTRAL_VARIANT_100: Color = Color([255, 255, 255, 255]);
+    pub const ERROR_0: Color = Color([0, 0, 0, 255]);
+    pub const ERROR_10: Color = Color([65, 0, 2, 255]);
+    pub const ERROR_20: Color = Color([105, 0, 5, 255]);
+    pub const ERROR_30: Color = Color([147, 0, 10, 255]);
+    pub const ERROR_40: Color = Color([186, 26, 26, 255]);
+    pub const ERROR_50: Color = Color([222, 55, 48, 255]);
+    pub const ERROR_60: Color = Color([255, 84, 73, 255]);
+    pub const ERROR_70: Color = Color([255, 137, 125, 255]);
+    pub const ERROR_80: Color = Color([255, 180, 171, 255]);
+    pub const ERROR_90: Color = Color([255, 218, 214, 255]);
+    pub const ERROR_95: Color = Color([255, 237, 234, 255]);
+    pub const ERROR_99: Color = Color([255, 251, 255, 255]);
+    pub const ERROR_100: Color = Color([255, 255, 255, 255]);
+    pub const PRIMARY_LIGHT: Color = Color([103, 80, 164, 255]);
+    pub const ON_PRIMARY_LIGHT: Color = Color([255, 255, 255, 255]);
+    pub const PRIMARY_CONTAINER_LIGHT: Color = Color([233, 221, 255, 255]);
+    pub const ON_PRIMARY_CONTAINER_LIGHT: Color = Color([34, 0, 93, 255]);
+    pub const SECONDARY_LIGHT: Color = Color([98, 91, 113, 255]);
+    pub const ON_SECONDARY_LIGHT: Color = Color([255, 255, 255, 255]);
+    pub const SECONDARY_CONTAINER_LIGHT: Color = Color([232, 222, 248, 255]);
+    pub const ON_SECONDARY_CONTAINER_LIGHT: Color = Color([30, 25, 43, 255]);
+    pub const TERTIARY_LIGHT: Color = Color([126, 82, 96, 255]);
+    pub const ON_TERTIARY_LIGHT: Color = Color([255, 255, 255, 255]);
+    pub const TERTIARY_CONTAINER_LIGHT: Color = Color([255, 217, 227, 255]);
+    pub const ON_TERTIARY_CONTAINER_LIGHT: Color = Color([49, 16, 29, 255]);
+    pub const ERROR_LIGHT: Color = Color([186, 26, 26, 255]);
+    pub const ON_ERROR_LIGHT: Color = Color([255, 255, 255, 255]);
+    pub const ERROR_CONTAINER_LIGHT: Color = Color([255, 218, 214, 255]);
+    pub const ON_ERROR_CONTAINER_LIGHT: Color = Color([65, 0, 2, 255]);
+    pub const BACKGROUND_LIGHT: Color = Color([255, 251, 255, 255]);
+    pub const ON_BACKGROUND_LIGHT: Color = Color([28, 27, 30, 255]);
+    pub const SURFACE_LIGHT: Color = Color([255, 251, 255, 255]);
+    pub const ON_SURFACE_LIGHT: Color = Color([28, 27, 30, 255]);
+    pub const SURFACE_VARIANT_LIGHT: Color = Color([231, 224, 235, 255]);
+    pub const ON_SURFACE_VARIANT_LIGHT: Color = Color([73, 69, 78, 255]);
+    pub const OUTLINE_LIGHT: Color = Color([122, 117, 127, 255]);
+    pub const OUTLINE_VARIANT_LIGHT: Color = Color([202, 196, 207, 255]);
+    pub const SHADOW_LIGHT: Color = Color([0, 0, 0, 255]);
+    pub const SCRIM_LIGHT: Color = Color([0, 0, 0, 255]);
+    pub const INVERSE_SURFACE_LIGHT: Color = Color([49, 48, 51, 255]);
+    pub const INVERSE_ON_SURFACE_LIGHT: Color = Color([244, 239, 244, 255]);
+    pub const INVERSE_PRIMARY_LIGHT: Color = Color([207, 188, 255, 255]);
+    pub const PRIMARY_DARK: Color = Color([207, 188, 255, 255]);
+    pub const ON_PRIMARY_DARK: Color = Color([56, 30, 114, 255]);
+    pub const PRIMARY_CONTAINER_DARK: Color = Color([79, 55, 138, 255]);
+    pub const ON_PRIMARY_CONTAINER_DARK: Color = Color([233, 221, 255, 255]);
+    pub const SECONDARY_DARK: Color = Color([203, 194, 219, 255]);
+    pub const ON_SECONDARY_DARK: Color = Color([51, 45, 65, 255]);
+    pub const SECONDARY_CONTAINER_DARK: Color = Color([74, 68, 88, 255]);
+    pub const ON_SECONDARY_CONTAINER_DARK: Color = Color([232, 222, 248, 255]);
+    pub const TERTIARY_DARK: Color = Color([239, 184, 200, 255]);
+    pub const ON_TERTIARY_DARK: Color = Color([74, 37, 50, 255]);
+    pub const TERTIARY_CONTAINER_DARK: Color = Color([99, 59, 72, 255]);
+    pub const ON_TERTIARY_CONTAINER_DARK: Color = Color([255, 217, 227, 255]);
+    pub const ERROR_DARK: Color = Color([255, 180, 171, 255]);
+    pub const ON_ERROR_DARK: Color = Color([105, 0, 5, 255]);
+    pub const ERROR_CONTAINER_DARK: Color = Color([147, 0, 10, 255]);
+    pub const ON_ERROR_CONTAINER_DARK: Color = Color([255, 180, 171, 255]);
+    pub const BACKGROUND_DARK: Color = Color([28, 27, 30, 255]);
+    pub const ON_BACKGROUND_DARK: Color = Color([230, 225, 230, 255]);
+    pub const SURFACE_DARK: Color = Color([28, 27, 30, 255]);
+    pub const ON_SURFACE_DARK: Color = Color([230, 225, 230, 255]);
+    pub const SURFACE_VARIANT_DARK: Color = Color([73, 69, 78, 255]);
+    pub const ON_SURFACE_VARIANT_DARK: Color = Color([202, 196, 207, 255]);
+    pub const OUTLINE_DARK: Color = Color([148, 143, 153, 255]);
+    pub const OUTLINE_VARIANT_DARK: Color = Color([73, 69, 78, 255]);
+    pub const SHADOW_DARK: Color = Color([0, 0, 0, 255]);
+    pub const SCRIM_DARK: Color = Color([0, 0, 0, 255]);
+    pub const INVERSE_SURFACE_DARK: Color = Color([230, 225, 230, 255]);
+    pub const INVERSE_ON_SURFACE_DARK: Color = Color([49, 48, 51, 255]);
+    pub const INVERSE_PRIMARY_DARK: Color = Color([103, 80, 164, 255]);
+    pub const BLUE: Color = Color([10, 132, 255, 255]);
+    pub const BLUE_0: Color = Color([0, 0, 0, 255]);
+    pub const BLUE_10: Color = Color([0, 27, 62, 255]);
+    pub const BLUE_20: Color = Color([0, 48, 100, 255]);
+    pub const BLUE_30: Color = Color([0, 70, 141, 255]);
+    pub const BLUE_40: Color = Color([0, 93, 184, 255]);
+    pub const BLUE_50: Color = Color([0, 117, 229, 255]);
+    pub const BLUE_60: Color = Color([62, 144, 255, 255]);
+    pub const BLUE_70: Color = Color([122, 172, 255, 255]);
+    pub const BLUE_80: Color = Color([170, 199, 255, 255]);
+    pub const BLUE_90: Color = Color([214, 227, 255, 255]);
+    pub const BLUE_95: Color = Color([236, 240, 255, 255]);
+    pub const BLUE_99: Color = Color([253, 251, 255, 255]);
+    pub const BLUE_100: Color = Color([255, 255, 255, 255]);
+}
+
+#[cfg(feature = "egui")]
+impl From<Color> for egui::Color32 {
+    fn from(Color([r, g, b, a]): Color) -> Self {
+        egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+    }
+}
+
+#[cfg(feature = "egui")]
+impl From<TextStyle> for egui::FontId {
+    fn from(style: TextStyle) -> Self {
+        egui::FontId::new(style.font_size, egui::FontFamily::Name(style.font_family.into()))
+    }
+}
@@ -0,0 +1,79 @@
+// Generated by design-tokens, do not edit.
+import SwiftUI
+
+public struct TextStyleToken {
+    public let font: Font
+    public let size: CGFloat
+    /// Added between lines to reach the line height of the text style
+    public let lineSpacing: CGFloat
+    public let tracking: CGFloat
+}
+
+extension View {
+    public func textStyle(_ style: TextStyleToken) -> some View {
+        self.font(style.font).lineSpacing(style.lineSpacing).tracking(style.tracking)
+    }
+}
+
+extension Font {
+    /// `text role:code size:lg`
+    public static let textCodeLg = Font.custom("RobotoMono-Regular", size: 21.0989)
+    /// `text role:code size:sm`
+    public static let textCodeSm = Font.custom("RobotoMono-Regular", size: 12.6593)
+    /// `text role:heading size:lg`
+    public static let textHeadingLg = Font.custom("Inter-BoldItalic", size: 20.625)
+    /// `text role:heading size:sm`
+    public static let textHeadingSm = Font.custom("Inter-BoldItalic", size: 12.375)
+    /// `text role:ui size:lg`
+    public static let textUiLg = Font.custom("Inter-Regular", size: 20.625)
+    /// `text role:ui size:sm`
+    public static let textUiSm = Font.custom("Inter-Regular", size: 12.375)
+}
+
+extension TextStyleToken {
+    /// `text role:code size:lg`
+    public static let textCodeLg = TextStyleToken(font: .textCodeLg, size: 21.0989, lineSpacing: 0.1738, tracking: 0)
+    /// `text role:code size:sm`
+    public static let textCodeSm = TextStyleToken(font: .textCodeSm, size: 12.6593, lineSpacing: 0, tracking: 0)
+    /// `text role:heading size:lg`
+    public static let textHeadingLg = TextStyleToken(font: .textHeadingLg, size: 20.625, lineSpacing: 3.0391, tracking: -0.2385)
+    /// `text role:heading size:sm`
+    public static let textHeadingSm = TextStyleToken(font: .textHeadingSm, size: 12.375, lineSpacing: 1.0234, tracking: 0.1121)
+    /// `text role:ui size:lg`
+    public static let textUiLg = TextStyleToken(font: .textUiLg, size: 20.625, lineSpacing: 3.0391, tracking: -0.2385)
+    /// `text role:ui size:sm`
+    public static let textUiSm = TextStyleToken(font: .textUiSm, size: 12.375, lineSpacing: 1.0234, tracking: 0.1121)
+}
+
+/// Light and dark colors from `Colors.xcassets`
+public enum ColorToken {
+    public static let primary = Color("primary")
+    public static let onPrimary = Color("onPrimary")
+    public static let primaryContainer = Color("primaryContainer")
+    public static let onPrimaryContainer = Color("onPrimaryContainer")
+    public static let secondary = Color("secondary")
+    public static let onSecondary = Color("onSecondary")
+    public static let secondaryContainer = Color("secondaryContainer")
+    public static let onSecondaryContainer = Color("onSecondaryContainer")
+    public static let tertiary = Color("tertiary")
+    public static let onTertiary = Color("onTertiary")
+    public static let tertiaryContainer = Color("tertiaryContainer")
+    public static let onTertiaryContainer = Color("onTertiaryContainer")
+    public static let error = Color("error")
+    public static let onError = Color("onError")
+    public static let errorContainer = Color("errorContainer")
+    public static let onErrorContainer = Color("onErrorContainer")
+    public static let background = Color("background")
+    public static let onBackground = Color("onBackground")
+    public static let surface = Color("surface")
+    public static let onSurface = Color("onSurface")
+    public static let surfaceVariant = Color("surfaceVariant")
+    public static let onSurfaceVariant = Color("onSurfaceVariant")
+    public static let outline = Color("outline")
+    public static let outlineVariant = Color("outlineVariant")
+    public static let shadow = Color("shadow")
+    public static let scrim = Color("scrim")
+    public static let inverseSurface = Color("inverseSurface")
+    public static let inverseOnSurface = Color("inverseOnSurface")
+    public static let inversePrimary = Color("inversePrimary")
+}
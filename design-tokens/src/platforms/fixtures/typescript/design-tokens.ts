@@ -0,0 +1,231 @@
+// Generated by design-tokens, do not edit.
+
+export const textStyles = {
+  "text role:code size:lg": { fontFamily: "Roboto Mono", fontSize: 21.0989, lineHeight: 28, letterSpacing: 0, fontWeight: 400, italic: false },
+  "text role:code size:sm": { fontFamily: "Roboto Mono", fontSize: 12.6593, lineHeight: 16, letterSpacing: 0, fontWeight: 400, italic: false },
+  "text role:heading size:lg": { fontFamily: "Inter", fontSize: 20.625, lineHeight: 28, letterSpacing: -0.2385, fontWeight: 700, italic: true },
+  "text role:heading size:sm": { fontFamily: "Inter", fontSize: 12.375, lineHeight: 16, letterSpacing: 0.1121, fontWeight: 700, italic: true },
+  "text role:ui size:lg": { fontFamily: "Inter", fontSize: 20.625, lineHeight: 28, letterSpacing: -0.2385, fontWeight: 400, italic: false },
+  "text role:ui size:sm": { fontFamily: "Inter", fontSize: 12.375, lineHeight: 16, letterSpacing: 0.1121, fontWeight: 400, italic: false },
+} as const;
+
+export type TextStyleName = keyof typeof textStyles;
+
+export const colors = {
+  "color:primary tone:0": "#000000",
+  "color:primary tone:10": "#22005d",
+  "color:primary tone:20": "#381e72",
+  "color:primary tone:30": "#4f378a",
+  "color:primary tone:40": "#6750a4",
+  "color:primary tone:50": "#8069bf",
+  "color:primary tone:60": "#9a83db",
+  "color:primary tone:70": "#b69df8",
+  "color:primary tone:80": "#cfbcff",
+  "color:primary tone:90": "#e9ddff",
+  "color:primary tone:95": "#f6eeff",
+  "color:primary tone:99": "#fffbff",
+  "color:primary tone:100": "#ffffff",
+  "color:secondary tone:0": "#000000",
+  "color:secondary tone:10": "#1e192b",
+  "color:secondary tone:20": "#332d41",
+  "color:secondary tone:30": "#4a4458",
+  "color:secondary tone:40": "#625b71",
+  "color:secondary tone:50": "#7b748a",
+  "color:secondary tone:60": "#958da4",
+  "color:secondary tone:70": "#b0a7c0",
+  "color:secondary tone:80": "#cbc2db",
+  "color:secondary tone:90": "#e8def8",
+  "color:secondary tone:95": "#f6eeff",
+  "color:secondary tone:99": "#fffbff",
+  "color:secondary tone:100": "#ffffff",
+  "color:tertiary tone:0": "#000000",
+  "color:tertiary tone:10": "#31101d",
+  "color:tertiary tone:20": "#4a2532",
+  "color:tertiary tone:30": "#633b48",
+  "color:tertiary tone:40": "#7e5260",
+  "color:tertiary tone:50": "#996a79",
+  "color:tertiary tone:60": "#b58392",
+  "color:tertiary tone:70": "#d29dad",
+  "color:tertiary tone:80": "#efb8c8",
+  "color:tertiary tone:90": "#ffd9e3",
+  "color:tertiary tone:95": "#ffecf0",
+  "color:tertiary tone:99": "#fffbff",
+  "color:tertiary tone:100": "#ffffff",
+  "color:neutral tone:0": "#000000",
+  "color:neutral tone:10": "#1c1b1e",
+  "color:neutral tone:20": "#313033",
+  "color:neutral tone:30": "#48464a",
+  "color:neutral tone:40": "#605d62",
+  "color:neutral tone:50": "#79767a",
+  "color:neutral tone:60": "#938f94",
+  "color:neutral tone:70": "#aeaaae",
+  "color:neutral tone:80": "#cac5ca",
+  "color:neutral tone:90": "#e6e1e6",
+  "color:neutral tone:95": "#f4eff4",
+  "color:neutral tone:99": "#fffbff",
+  "color:neutral tone:100": "#ffffff",
+  "color:neutral-variant tone:0": "#000000",
+  "color:neutral-variant tone:10": "#1d1a22",
+  "color:neutral-variant tone:20": "#322f38",
+  "color:neutral-variant tone:30": "#49454e",
+  "color:neutral-variant tone:40": "#615d66",
+  "color:neutral-variant tone:50": "#7a757f",
+  "color:neutral-variant tone:60": "#948f99",
+  "color:neutral-variant tone:70": "#afa9b4",
+  "color:neutral-variant tone:80": "#cac4cf",
+  "color:neutral-variant tone:90": "#e7e0eb",
+  "color:neutral-variant tone:95": "#f5eefa",
+  "color:neutral-variant tone:99": "#fffbff",
+  "color:neutral-variant tone:100": "#ffffff",
+  "color:error tone:0": "#000000",
+  "color:error tone:10": "#410002",
+  "color:error tone:20": "#690005",
+  "color:error tone:30": "#93000a",
+  "color:error tone:40": "#ba1a1a",
+  "color:error tone:50": "#de3730",
+  "color:error tone:60": "#ff5449",
+  "color:error tone:70": "#ff897d",
+  "color:error tone:80": "#ffb4ab",
+  "color:error tone:90": "#ffdad6",
+  "color:error tone:95": "#ffedea",
+  "color:error tone:99": "#fffbff",
+  "color:error tone:100": "#ffffff",
+  "color:primary scheme:light": "#6750a4",
+  "color:on-primary scheme:light": "#ffffff",
+  "color:primary-container scheme:light": "#e9ddff",
+  "color:on-primary-container scheme:light": "#22005d",
+  "color:secondary scheme:light": "#625b71",
+  "color:on-secondary scheme:light": "#ffffff",
+  "color:secondary-container scheme:light": "#e8def8",
+  "color:on-secondary-container scheme:light": "#1e192b",
+  "color:tertiary scheme:light": "#7e5260",
+  "color:on-tertiary scheme:light": "#ffffff",
+  "color:tertiary-container scheme:light": "#ffd9e3",
+  "color:on-tertiary-container scheme:light": "#31101d",
+  "color:error scheme:light": "#ba1a1a",
+  "color:on-error scheme:light": "#ffffff",
+  "color:error-container scheme:light": "#ffdad6",
+  "color:on-error-container scheme:light": "#410002",
+  "color:background scheme:light": "#fffbff",
+  "color:on-background scheme:light": "#1c1b1e",
+  "color:surface scheme:light": "#fffbff",
+  "color:on-surface scheme:light": "#1c1b1e",
+  "color:surface-variant scheme:light": "#e7e0eb",
+  "color:on-surface-variant scheme:light": "#49454e",
+  "color:outline scheme:light": "#7a757f",
+  "color:outline-variant scheme:light": "#cac4cf",
+  "color:shadow scheme:light": "#000000",
+  "color:scrim scheme:light": "#000000",
+  "color:inverse-surface scheme:light": "#313033",
+  "color:inverse-on-surface scheme:light": "#f4eff4",
+  "color:inverse-primary scheme:light": "#cfbcff",
+  "color:primary scheme:dark": "#cfbcff",
+  "color:on-primary scheme:dark": "#381e72",
+  "color:primary-container scheme:dark": "#4f378a",
+  "color:on-primary-container scheme:dark": "#e9ddff",
+  "color:secondary scheme:dark": "#cbc2db",
+  "color:on-secondary scheme:dark": "#332d41",
+  "color:secondary-container scheme:dark": "#4a4458",
+  "color:on-secondary-container scheme:dark": "#e8def8",
+  "color:tertiary scheme:dark": "#efb8c8",
+  "color:on-tertiary scheme:dark": "#4a2532",
+  "color:tertiary-container scheme:dark": "#633b48",
+  "color:on-tertiary-container scheme:dark": "#ffd9e3",
+  "color:error scheme:dark": "#ffb4ab",
+  "color:on-error scheme:dark": "#690005",
+  "color:error-container scheme:dark": "#93000a",
+  "color:on-error-container scheme:dark": "#ffb4ab",
+  "color:background scheme:dark": "#1c1b1e",
+  "color:on-background scheme:dark": "#e6e1e6",
+  "color:surface scheme:dark": "#1c1b1e",
+  "color:on-surface scheme:dark": "#e6e1e6",
+  "color:surface-variant scheme:dark": "#49454e",
+  "color:on-surface-variant scheme:dark": "#cac4cf",
+  "color:outline scheme:dark": "#948f99",
+  "color:outline-variant scheme:dark": "#49454e",
+  "color:shadow scheme:dark": "#000000",
+  "color:scrim scheme:dark": "#000000",
+  "color:inverse-surface scheme:dark": "#e6e1e6",
+  "color:inverse-on-surface scheme:dark": "#313033",
+  "color:inverse-primary scheme:dark": "#6750a4",
+  "color:blue": "#0a84ff",
+  "color:blue tone:0": "#000000",
+  "color:blue tone:10": "#001b3e",
+  "color:blue tone:20": "#003064",
+  "color:blue tone:30": "#00468d",
+  "color:blue tone:40": "#005db8",
+  "color:blue tone:50": "#0075e5",
+  "color:blue tone:60": "#3e90ff",
+  "color:blue tone:70": "#7aacff",
+  "color:blue tone:80": "#aac7ff",
+  "color:blue tone:90": "#d6e3ff",
+  "color:blue tone:95": "#ecf0ff",
+  "color:blue tone:99": "#fdfbff",
+  "color:blue tone:100": "#ffffff",
+} as const;
+
+export type ColorName = keyof typeof colors;
+
+export type Token =
+  | "color:accent"
+  | "color:background"
+  | "color:blue"
+  | "color:error"
+  | "color:error-container"
+  | "color:inverse-on-surface"
+  | "color:inverse-primary"
+  | "color:inverse-surface"
+  | "color:neutral"
+  | "color:neutral-variant"
+  | "color:on-background"
+  | "color:on-error"
+  | "color:on-error-container"
+  | "color:on-primary"
+  | "color:on-primary-container"
+  | "color:on-secondary"
+  | "color:on-secondary-container"
+  | "color:on-surface"
+  | "color:on-surface-variant"
+  | "color:on-tertiary"
+  | "color:on-tertiary-container"
+  | "color:outline"
+  | "color:outline-variant"
+  | "color:primary"
+  | "color:primary-container"
+  | "color:scrim"
+  | "color:secondary"
+  | "color:secondary-container"
+  | "color:shadow"
+  | "color:surface"
+  | "color:surface-variant"
+  | "color:tertiary"
+  | "color:tertiary-container"
+  | "italic:true"
+  | "role:code"
+  | "role:heading"
+  | "role:ui"
+  | "scheme:dark"
+  | "scheme:light"
+  | "size:lg"
+  | "size:sm"
+  | "text"
+  | "tone:0"
+  | "tone:10"
+  | "tone:100"
+  | "tone:20"
+  | "tone:30"
+  | "tone:40"
+  | "tone:50"
+  | "tone:60"
+  | "tone:70"
+  | "tone:80"
+  | "tone:90"
+  | "tone:95"
+  | "tone:99"
+  | "weight:400"
+  | "weight:600"
+  | "weight:700"
+  ;
+
+/** e.g. `TokenOf<"size">` is `"size:sm" | "size:lg"` */
+export type TokenOf<Key extends string> = Extract<Token, `${Key}:${string}`>;
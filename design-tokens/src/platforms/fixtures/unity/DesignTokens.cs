@@ -0,0 +1,195 @@
+// Generated by design-tokens, do not edit.
+using UnityEngine;
+
+namespace DesignTokens
+{
+    public readonly struct TextStyleToken
+    {
+        public readonly string FontFamily;
+        public readonly float FontSize;
+        public readonly float? LineHeight;
+        public readonly float LetterSpacing;
+        public readonly int FontWeight;
+        public readonly bool Italic;
+
+        public TextStyleToken(string fontFamily, float fontSize, float? lineHeight, float letterSpacing, int fontWeight, bool italic)
+        {
+            FontFamily = fontFamily;
+            FontSize = fontSize;
+            LineHeight = lineHeight;
+            LetterSpacing = letterSpacing;
+            FontWeight = fontWeight;
+            Italic = italic;
+        }
+    }
+
+    public static class TextStyles
+    {
+        /// <summary><c>text role:code size:lg</c></summary>
+        public static readonly TextStyleToken TextCodeLg = new TextStyleToken("Roboto Mono", 21.0989f, 28f, 0f, 400, false);
+        /// <summary><c>text role:code size:sm</c></summary>
+        public static readonly TextStyleToken TextCodeSm = new TextStyleToken("Roboto Mono", 12.6593f, 16f, 0f, 400, false);
+        /// <summary><c>text role:heading size:lg</c></summary>
+        public static readonly TextStyleToken TextHeadingLg = new TextStyleToken("Inter", 20.625f, 28f, -0.2385f, 700, true);
+        /// <summary><c>text role:heading size:sm</c></summary>
+        public static readonly TextStyleToken TextHeadingSm = new TextStyleToken("Inter", 12.375f, 16f, 0.1121f, 700, true);
+        /// <summary><c>text role:ui size:lg</c></summary>
+        public static readonly TextStyleToken TextUiLg = new TextStyleToken("Inter", 20.625f, 28f, -0.2385f, 400, false);
+        /// <summary><c>text role:ui size:sm</c></summary>
+        public static readonly TextStyleToken TextUiSm = new TextStyleToken("Inter", 12.375f, 16f, 0.1121f, 400, false);
+    }
+
+    public static class Colors
+    {
+        public static readonly Color32 Primary0 = new Color32(0, 0, 0, 255);
+        public static readonly Color32 Primary10 = new Color32(34, 0, 93, 255);
+        public static readonly Color32 Primary20 = new Color32(56, 30, 114, 255);
+        public static readonly Color32 Primary30 = new Color32(79, 55, 138, 255);
+        public static readonly Color32 Primary40 = new Color32(103, 80, 164, 255);
+        public static readonly Color32 Primary50 = new Color32(128, 105, 191, 255);
+        public static readonly Color32 Primary60 = new Color32(154, 131, 219, 255);
+        public static readonly Color32 Primary70 = new Color32(182, 157, 248, 255);
+        public static readonly Color32 Primary80 = new Color32(207, 188, 255, 255);
+        public static readonly Color32 Primary90 = new Color32(233, 221, 255, 255);
+        public static readonly Color32 Primary95 = new Color32(246, 238, 255, 255);
+        public static readonly Color32 Primary99 = new Color32(255, 251, 255, 255);
+        public static readonly Color32 Primary100 = new Color32(255, 255, 255, 255);
+        public static readonly Color32 Secondary0 = new Color32(0, 0, 0, 255);
+        public static readonly Color32 Secondary10 = new Color32(30, 25, 43, 255);
+        public static readonly Color32 Secondary20 = new Color32(51, 45, 65, 255);
+        public static readonly Color32 Secondary30 = new Color32(74, 68, 88, 255);
+        public static readonly Color32 Secondary40 = new Color32(98, 91, 113, 255);
+        public static readonly Color32 Secondary50 = new Color32(123, 116, 138, 255);
+        public static readonly Color32 Secondary60 = new Color32(149, 141, 164, 255);
+        public static readonly Color32 Secondary70 = new Color32(176, 167, 192, 255);
+        public static readonly Color32 Secondary80 = new Color32(203, 194, 219, 255);
+        public static readonly Color32 Secondary90 = new Color32(232, 222, 248, 255);
+        public static readonly Color32 Secondary95 = new Color32(246, 238, 255, 255);
+        public static readonly Color32 Secondary99 = new Color32(255, 251, 255, 255);
+        public static readonly Color32 Secondary100 = new Color32(255, 255, 255, 255);
+        public static readonly Color32 Tertiary0 = new Color32(0, 0, 0, 255);
+        public static readonly Color32 Tertiary10 = new Color32(49, 16, 29, 255);
+        public static readonly Color32 Tertiary20 = new Color32(74, 37, 50, 255);
+        public static readonly Color32 Tertiary30 = new Color32(99, 59, 72, 255);
+        public static readonly Color32 Tertiary40 = new Color32(126, 82, 96, 255);
+        public static readonly Color32 Tertiary50 = new Color32(153, 106, 121, 255);
+        public static readonly Color32 Tertiary60 = new Color32(181, 131, 146, 255);
+        public static readonly Color32 Tertiary70 = new Color32(210, 157, 173, 255);
+        public static readonly Color32 Tertiary80 = new Color32(239, 184, 200, 255);
+        public static readonly Color32 Tertiary90 = new Color32(255, 217, 227, 255);
+        public static readonly Color32 Tertiary95 = new Color32(255, 236, 240, 255);
+        public static readonly Color32 Tertiary99 = new Color32(255, 251, 255, 255);
+        public static readonly Color32 Tertiary100 = new Color32(255, 255, 255, 255);
+        public static readonly Color32 Neutral0 = new Color32(0, 0, 0, 255);
+        public static readonly Color32 Neutral10 = new Color32(28, 27, 30, 255);
+        public static readonly Color32 Neutral20 = new Color32(49, 48, 51, 255);
+        public static readonly Color32 Neutral30 = new Color32(72, 70, 74, 255);
+        public static readonly Color32 Neutral40 = new Color32(96, 93, 98, 255);
+        public static readonly Color32 Neutral50 = new Color32(121, 118, 122, 255);
+        public static readonly Color32 Neutral60 = new Color32(147, 143, 148, 255);
+        public static readonly Color32 Neutral70 = new Color32(174, 170, 174, 255);
+        public static readonly Color32 Neutral80 = new Color32(202, 197, 202, 255);
+        public static readonly Color32 Neutral90 = new Color32(230, 225, 230, 255);
+        public static readonly Color32 Neutral95 = new Color32(244, 239, 244, 255);
+        public static readonly Color32 Neutral99 = new Color32(255, 251, 255, 255);
+        public static readonly Color32 Neutral100 = new Color32(255, 255, 255, 255);
+        public static readonly Color32 NeutralVariant0 = new Color32(0, 0, 0, 255);
+        public static readonly Color32 NeutralVariant10 = new Color32(29, 26, 34, 255);
+        public static readonly Color32 NeutralVariant20 = new Color32(50, 47, 56, 255);
+        public static readonly Color32 NeutralVariant30 = new Color32(73, 69, 78, 255);
+        public static readonly Color32 NeutralVariant40 = new Color32(97, 93, 102, 255);
+        public static readonly Color32 NeutralVariant50 = new Color32(122, 117, 127, 255);
+        public static readonly Color32 NeutralVariant60 = new Color32(148, 143, 153, 255);
+        public static readonly Color32 NeutralVariant70 = new Color32(175, 169, 180, 255);
+        public static readonly Color32 NeutralVariant80 = new Color32(202, 196, 207, 255);
+        public static readonly Color32 NeutralVariant90 = new Color32(231, 224, 235, 255);
+        public static readonly Color32 NeutralVariant95 = new Color32(245, 238, 250, 255);
+        public static readonly Color32 NeutralVariant99 = new Color32(255, 251, 255, 255);
+        public static readonly Color32 NeutralVariant100 = new Color32(255, 255, 255, 255);
+        public static readonly Color32 Error0 = new Color32(0, 0, 0, 255);
+        public static readonly Color32 Error10 = new Color32(65, 0, 2, 255);
+        public static readonly Color32 Error20 = new Color32(105, 0, 5, 255);
+        public static readonly Color32 Error30 = new Color32(147, 0, 10, 255);
+        public static readonly Color32 Error40 = new Color32(186, 26, 26, 255);
+        public static readonly Color32 Error50 = new Color32(222, 55, 48, 255);
+        public static readonly Color32 Error60 = new Color32(255, 84, 73, 255);
+        public static readonly Color32 Error70 = new Color32(255, 137, 125, 255);
+        public static readonly Color32 Error80 = new Color32(255, 180, 171, 255);
+        public static readonly Color32 Error90 = new Color32(255, 218, 214, 255);
+        public static readonly Color32 Error95 = new Color32(255, 237, 234, 255);
+        public static readonly Color32 Error99 = new Color32(255, 251, 255, 255);
+        public static readonly Color32 Error100 = new Color32(255, 255, 255, 255);
+        public static readonly Color32 PrimaryLight = new Color32(103, 80, 164, 255);
+        public static readonly Color32 OnPrimaryLight = new Color32(255, 255, 255, 255);
+        public static readonly Color32 PrimaryContainerLight = new Color32(233, 221, 255, 255);
+        public static readonly Color32 OnPrimaryContainerLight = new Color32(34, 0, 93, 255);
+        public static readonly Color32 SecondaryLight = new Color32(98, 91, 113, 255);
+        public static readonly Color32 OnSecondaryLight = new Color32(255, 255, 255, 255);
+        public static readonly Color32 SecondaryContainerLight = new Color32(232, 222, 248, 255);
+        public static readonly Color32 OnSecondaryContainerLight = new Color32(30, 25, 43, 255);
+        public static readonly Color32 TertiaryLight = new Color32(126, 82, 96, 255);
+        public static readonly Color32 OnTertiaryLight = new Color32(255, 255, 255, 255);
+        public static readonly Color32 TertiaryContainerLight = new Color32(255, 217, 227, 255);
+        public static readonly Color32 OnTertiaryContainerLight = new Color32(49, 16, 29, 255);
+        public static readonly Color32 ErrorLight = new Color32(186, 26, 26, 255);
+        public static readonly Color32 OnErrorLight = new Color32(255, 255, 255, 255);
+        public static readonly Color32 ErrorContainerLight = new Color32(255, 218, 214, 255);
+        public static readonly Color32 OnErrorContainerLight = new Color32(65, 0, 2, 255);
+        public static readonly Color32 BackgroundLight = new Color32(255, 251, 255, 255);
+        public static readonly Color32 OnBackgroundLight = new Color32(28, 27, 30, 255);
+        public static readonly Color32 SurfaceLight = new Color32(255, 251, 255, 255);
+        public static readonly Color32 OnSurfaceLight = new Color32(28, 27, 30, 255);
+        public static readonly Color32 SurfaceVariantLight = new Color32(231, 224, 235, 255);
+        public static readonly Color32 OnSurfaceVariantLight = new Color32(73, 69, 78, 255);
+        public static readonly Color32 OutlineLight = new Color32(122, 117, 127, 255);
+        public static readonly Color32 OutlineVariantLight = new Color32(202, 196, 207, 255);
+        public static readonly Color32 ShadowLight = new Color32(0, 0, 0, 255);
+        public static readonly Color32 ScrimLight = new Color32(0, 0, 0, 255);
+        public static readonly Color32 InverseSurfaceLight = new Color32(49, 48, 51, 255);
+        public static readonly Color32 InverseOnSurfaceLight = new Color32(244, 239, 244, 255);
+        public static readonly Color32 InversePrimaryLight = new Color32(207, 188, 255, 255);
+        public static readonly Color32 PrimaryDark = new Color32(207, 188, 255, 255);
+        public static readonly Color32 OnPrimaryDark = new Color32(56, 30, 114, 255);
+        public static readonly Color32 PrimaryContainerDark = new Color32(79, 55, 138, 255);
+        public static readonly Color32 OnPrimaryContainerDark = new Color32(233, 221, 255, 255);
+        public static readonly Color32 SecondaryDark = new Color32(203, 194, 219, 255);
+        public static readonly Color32 OnSecondaryDark = new Color32(51, 45, 65, 255);
+        public static readonly Color32 SecondaryContainerDark = new Color32(74, 68, 88, 255);
+        public static readonly Color32 OnSecondaryContainerDark = new Color32(232, 222, 248, 255);
+        public static readonly Color32 TertiaryDark = new Color32(239, 184, 200, 255);
+        public static readonly Color32 OnTertiaryDark = new Color32(74, 37, 50, 255);
+        public static readonly Color32 TertiaryContainerDark = new Color32(99, 59, 72, 255);
+        public static readonly Color32 OnTertiaryContainerDark = new Color32(255, 217, 227, 255);
+        public static readonly Color32 ErrorDark = new Color32(255, 180, 171, 255);
+        public static readonly Color32 OnErrorDark = new Color32(105, 0, 5, 255);
+        public static readonly Color32 ErrorContainerDark = new Color32(147, 0, 10, 255);
+        public static readonly Color32 OnErrorContainerDark = new Color32(255, 180, 171, 255);
+        public static readonly Color32 BackgroundDark = new Color32(28, 27, 30, 255);
+        public static readonly Color32 OnBackgroundDark = new Color32(230, 225, 230, 255);
+        public static readonly Color32 SurfaceDark = new Color32(28, 27, 30, 255);
+        public static readonly Color32 OnSurfaceDark = new Color32(230, 225, 230, 255);
+        public static readonly Color32 SurfaceVariantDark = new Color32(73, 69, 78, 255);
+        public static readonly Color32 OnSurfaceVariantDark = new Color32(202, 196, 207, 255);
+        public static readonly Color32 OutlineDark = new Color32(148, 143, 153, 255);
+        public static readonly Color32 OutlineVariantDark = new Color32(73, 69, 78, 255);
+        public static readonly Color32 ShadowDark = new Color32(0, 0, 0, 255);
+        public static readonly Color32 ScrimDark = new Color32(0, 0, 0, 255);
+        public static readonly Color32 InverseSurfaceDark = new Color32(230, 225, 230, 255);
+        public static readonly Color32 InverseOnSurfaceDark = new Color32(49, 48, 51, 255);
+        public static readonly Color32 InversePrimaryDark = new Color32(103, 80, 164, 255);
+        public static readonly Color32 Blue = new Color32(10, 132, 255, 255);
+        public static readonly Color32 Blue0 = new Color32(0, 0, 0, 255);
+        public static readonly Color32 Blue10 = new Color32(0, 27, 62, 255);
+        public static readonly Color32 Blue20 = new Color32(0, 48, 100, 255);
+        public static readonly Color32 Blue30 = new Color32(0, 70, 141, 255);
+        public static readonly Color32 Blue40 = new Color32(0, 93, 184, 255);
+        public static readonly Color32 Blue50 = new Color32(0, 117, 229, 255);
+        public static readonly Color32 Blue60 = new Color32(62, 144, 255, 255);
+        public static readonly Color32 Blue70 = new Color32(122, 172, 255, 255);
+        public static readonly Color32 Blue80 = new Color32(170, 199, 255, 255);
+        public static readonly Color32 Blue90 = new Color32(214, 227, 255, 255);
+        public static readonly Color32 Blue95 = new Color32(236, 240, 255, 255);
+        public static readonly Color32 Blue99 = new Color32(253, 251, 255, 255);
+        public static readonly Color32 Blue100 = new Color32(255, 255, 255, 255);
+    }
+}
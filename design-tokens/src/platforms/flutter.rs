@@ -0,0 +1,159 @@
+//! Flutter `TextStyle`s for each materialized text style, a Material `TextTheme` built from them,
+//! and light and dark `ColorScheme`s for the scheme roles.
+//!
+//! Flutter's `height` multiplies the font size, and `letterSpacing` is in logical pixels
+//! (one CSS pixel each).
+use std::{fmt::Write, path::PathBuf};
+
+use super::{camel_case, hex_rgb, material_type_roles, token_set_string, words, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    typography::{styles::MaterializedTextStyle, units::format_number},
+};
+
+pub mod flutter_config {
+    use crate::prelude::*;
+
+    // Must be named `TypographyExtensionInput` to ensure it merges with other typography extensions
+    #[derive(Debug, Codegen, Deserialize)]
+    #[codegen(tags = "flutter-input")]
+    #[codegen(ts_interface_merge)]
+    #[allow(non_snake_case)]
+    pub struct TypographyExtensionInput {
+        #[serde(alias = "flutter")]
+        pub Flutter: Option<FlutterConfig>,
+    }
+
+    #[derive(Codegen, Debug, Default, Deserialize)]
+    #[codegen(tags = "flutter-input")]
+    #[allow(non_snake_case)]
+    pub struct FlutterConfig {
+        /// Which text style fills each `TextTheme` role, e.g. `bodyLarge` from `text role:content size:lg`.
+        /// Roles left out keep the Material defaults.
+        pub TextThemeOption: Option<Vec<FlutterTextThemeRole>>,
+    }
+
+    #[derive(Codegen, Debug, Deserialize)]
+    #[codegen(tags = "flutter-input")]
+    #[allow(non_snake_case)]
+    pub struct FlutterTextThemeRole {
+        /// e.g. `"bodyLarge"` or `"labelSmall"`
+        pub Role: String,
+        /// e.g. `"text role:content size:lg"`
+        pub Tokens: String,
+    }
+}
+
+/// `design_tokens.dart`
+pub fn generate_flutter(
+    text_styles: &[MaterializedTextStyle],
+    colors: Option<&ColorExport>,
+    config: &flutter_config::FlutterConfig,
+) -> Result<Vec<GeneratedFile>> {
+    let mut dart = String::new();
+    writeln!(dart, "// Generated by design-tokens, do not edit.")?;
+    writeln!(dart, "import 'package:flutter/material.dart';")?;
+
+    writeln!(dart)?;
+    writeln!(dart, "class DesignTextStyles {{")?;
+    writeln!(dart, "  DesignTextStyles._();")?;
+    for style in text_styles {
+        let doc = token_set_string(&style.tokens);
+        writeln!(dart)?;
+        writeln!(dart, "  /// `{doc}`")?;
+        writeln!(
+            dart,
+            "  static const {} = TextStyle(",
+            camel_case(&words(style.tokens.path()))
+        )?;
+        writeln!(dart, "    fontFamily: {},", dart_string(&style.family_name))?;
+        writeln!(dart, "    fontSize: {},", format_number(style.font_size_px))?;
+        if let Some(px) = style.line_height_px {
+            writeln!(
+                dart,
+                "    height: {},",
+                format_number(px / style.font_size_px)
+            )?;
+        }
+        if let Some(px) = style.letter_spacing_px {
+            writeln!(dart, "    letterSpacing: {},", format_number(px))?;
+        }
+        // `FontWeight` only has the hundreds, so variable fonts also get the exact `wght`
+        let rounded_weight = ((style.font_weight + 50) / 100).clamp(1, 9) * 100;
+        writeln!(dart, "    fontWeight: FontWeight.w{rounded_weight},")?;
+        if rounded_weight != style.font_weight {
+            writeln!(
+                dart,
+                "    fontVariations: [FontVariation('wght', {})],",
+                style.font_weight
+            )?;
+        }
+        writeln!(
+            dart,
+            "    fontStyle: FontStyle.{},",
+            if style.italic { "italic" } else { "normal" }
+        )?;
+        writeln!(dart, "  );")?;
+    }
+    writeln!(dart, "}}")?;
+
+    writeln!(dart)?;
+    writeln!(dart, "const designTextTheme = TextTheme(")?;
+    let type_roles = config
+        .TextThemeOption
+        .iter()
+        .flatten()
+        .map(|type_role| (type_role.Role.as_str(), type_role.Tokens.as_str()));
+    for (type_role, style) in material_type_roles(text_styles, type_roles)? {
+        writeln!(
+            dart,
+            "  {type_role}: DesignTextStyles.{},",
+            camel_case(&words(style.tokens.path()))
+        )?;
+    }
+    writeln!(dart, ");")?;
+
+    if let Some(colors) = colors {
+        let roles = colors.scheme_roles();
+        for (name, brightness, dark) in [
+            ("lightColorScheme", "light", false),
+            ("darkColorScheme", "dark", true),
+        ] {
+            writeln!(dart)?;
+            writeln!(dart, "const {name} = ColorScheme(")?;
+            writeln!(dart, "  brightness: Brightness.{brightness},")?;
+            for role in roles.iter() {
+                let hex = if dark { role.dark_hex } else { role.light_hex };
+                let [red, green, blue] = hex_rgb(hex)?;
+                writeln!(
+                    dart,
+                    "  {}: Color(0xFF{red:02X}{green:02X}{blue:02X}),",
+                    color_scheme_parameter(role.role)
+                )?;
+            }
+            writeln!(dart, ");")?;
+        }
+    }
+
+    Ok(vec![GeneratedFile {
+        path: PathBuf::from("design_tokens.dart"),
+        contents: dart,
+    }])
+}
+
+/// e.g. `inverse-on-surface` is `onInverseSurface` in Flutter's `ColorScheme`
+fn color_scheme_parameter(role: &str) -> String {
+    match role {
+        "inverse-on-surface" => "onInverseSurface".to_string(),
+        other => camel_case(&words([other])),
+    }
+}
+
+fn dart_string(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('$', "\\$");
+    format!("'{escaped}'")
+}
@@ -0,0 +1,207 @@
+//! A Rust module with a `TextStyle` constant for each materialized text style and a `Color`
+//! constant for each color, with optional conversions into egui, iced and Bevy types.
+//!
+//! From `build.rs`, [generate_rust_for_build_script] runs the whole pipeline:
+//!
+//! ```ignore
+//! let settings_json = std::fs::read_to_string("design-system.json")?;
+//! let module = design_tokens::platforms::rust::generate_rust_for_build_script(&settings_json, "text role:* size:*")?;
+//! std::fs::write(Path::new(&std::env::var("OUT_DIR")?).join("design_tokens.rs"), module)?;
+//! // then `include!(concat!(env!("OUT_DIR"), "/design_tokens.rs"));` in the app
+//! ```
+use std::{fmt::Write, path::PathBuf};
+
+use super::{hex_rgb, snake_case, token_set_string, words, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    tokens::{aliases::TokenAliases, query::Query},
+    typography::{
+        output::{generate_typography_all_tokens, TypographyExport},
+        styles::{materialize_text_styles, MaterializedTextStyle},
+        units::format_number,
+    },
+};
+
+pub mod rust_config {
+    use crate::prelude::*;
+
+    // Must be named `TypographyExtensionInput` to ensure it merges with other typography extensions
+    #[derive(Debug, Codegen, Deserialize)]
+    #[codegen(tags = "rust-input")]
+    #[codegen(ts_interface_merge)]
+    #[allow(non_snake_case)]
+    pub struct TypographyExtensionInput {
+        #[serde(alias = "rust")]
+        pub Rust: Option<RustConfig>,
+    }
+
+    #[derive(Codegen, Debug, Default, Deserialize)]
+    #[codegen(tags = "rust-input")]
+    #[allow(non_snake_case)]
+    pub struct RustConfig {
+        /// Conversions to generate, each behind the app's feature of the same name,
+        /// e.g. `["egui", "bevy"]`. Supports `"egui"`, `"iced"`, and `"bevy"`.
+        pub FrameworksOption: Option<Vec<String>>,
+    }
+}
+
+/// `design_tokens.rs`
+pub fn generate_rust(
+    text_styles: &[MaterializedTextStyle],
+    colors: Option<&ColorExport>,
+    config: &rust_config::RustConfig,
+) -> Result<Vec<GeneratedFile>> {
+    let mut rust = String::new();
+    writeln!(rust, "// Generated by design-tokens, do not edit.")?;
+    writeln!(rust)?;
+    writeln!(rust, "#[derive(Clone, Copy, Debug, PartialEq)]")?;
+    writeln!(rust, "pub struct TextStyle {{")?;
+    writeln!(rust, "    pub font_family: &'static str,")?;
+    writeln!(rust, "    pub font_size: f32,")?;
+    writeln!(rust, "    pub line_height: Option<f32>,")?;
+    writeln!(rust, "    pub letter_spacing: f32,")?;
+    writeln!(rust, "    pub font_weight: u16,")?;
+    writeln!(rust, "    pub italic: bool,")?;
+    writeln!(rust, "}}")?;
+    writeln!(rust)?;
+    writeln!(rust, "#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]")?;
+    writeln!(rust, "pub struct Color(pub [u8; 4]);")?;
+    writeln!(rust)?;
+    writeln!(rust, "impl Color {{")?;
+    writeln!(rust, "    /// Each channel from `0.0` to `1.0`")?;
+    writeln!(rust, "    pub fn to_f32(self) -> [f32; 4] {{")?;
+    writeln!(rust, "        self.0.map(|channel| channel as f32 / 255.0)")?;
+    writeln!(rust, "    }}")?;
+    writeln!(rust, "}}")?;
+
+    for style in text_styles {
+        let doc = token_set_string(&style.tokens);
+        writeln!(rust)?;
+        writeln!(rust, "/// `{doc}`")?;
+        writeln!(
+            rust,
+            "pub const {}: TextStyle = TextStyle {{",
+            snake_case(&words(style.tokens.path())).to_ascii_uppercase()
+        )?;
+        writeln!(rust, "    font_family: {:?},", style.family_name)?;
+        writeln!(rust, "    font_size: {},", float(style.font_size_px))?;
+        match style.line_height_px {
+            Some(px) => writeln!(rust, "    line_height: Some({}),", float(px))?,
+            None => writeln!(rust, "    line_height: None,")?,
+        }
+        writeln!(
+            rust,
+            "    letter_spacing: {},",
+            float(style.letter_spacing_px.unwrap_or(0.0))
+        )?;
+        writeln!(rust, "    font_weight: {},", style.font_weight)?;
+        writeln!(rust, "    italic: {},", style.italic)?;
+        writeln!(rust, "}};")?;
+    }
+
+    if let Some(colors) = colors {
+        writeln!(rust)?;
+        writeln!(rust, "pub mod colors {{")?;
+        writeln!(rust, "    use super::Color;")?;
+        for color in &colors.colors {
+            // e.g. `color:primary tone:40` is `PRIMARY_40`
+            let [red, green, blue] = hex_rgb(&color.hex)?;
+            writeln!(
+                rust,
+                "    pub const {}: Color = Color([{red}, {green}, {blue}, 255]);",
+                snake_case(&words(color.tokens.path())).to_ascii_uppercase()
+            )?;
+        }
+        writeln!(rust, "}}")?;
+    }
+
+    for framework in config.FrameworksOption.iter().flatten() {
+        writeln!(rust)?;
+        match framework.as_str() {
+            "egui" => {
+                writeln!(rust, "#[cfg(feature = \"egui\")]")?;
+                writeln!(rust, "impl From<Color> for egui::Color32 {{")?;
+                writeln!(rust, "    fn from(Color([r, g, b, a]): Color) -> Self {{")?;
+                writeln!(rust, "        egui::Color32::from_rgba_unmultiplied(r, g, b, a)")?;
+                writeln!(rust, "    }}")?;
+                writeln!(rust, "}}")?;
+                writeln!(rust)?;
+                writeln!(rust, "#[cfg(feature = \"egui\")]")?;
+                writeln!(rust, "impl From<TextStyle> for egui::FontId {{")?;
+                writeln!(rust, "    fn from(style: TextStyle) -> Self {{")?;
+                writeln!(
+                    rust,
+                    "        egui::FontId::new(style.font_size, egui::FontFamily::Name(style.font_family.into()))"
+                )?;
+                writeln!(rust, "    }}")?;
+                writeln!(rust, "}}")?;
+            }
+            "iced" => {
+                writeln!(rust, "#[cfg(feature = \"iced\")]")?;
+                writeln!(rust, "impl From<Color> for iced::Color {{")?;
+                writeln!(rust, "    fn from(Color([r, g, b, a]): Color) -> Self {{")?;
+                writeln!(rust, "        iced::Color::from_rgba8(r, g, b, a as f32 / 255.0)")?;
+                writeln!(rust, "    }}")?;
+                writeln!(rust, "}}")?;
+            }
+            "bevy" => {
+                writeln!(rust, "#[cfg(feature = \"bevy\")]")?;
+                writeln!(rust, "impl From<Color> for bevy::color::Color {{")?;
+                writeln!(rust, "    fn from(Color([r, g, b, a]): Color) -> Self {{")?;
+                writeln!(rust, "        bevy::color::Color::srgba_u8(r, g, b, a)")?;
+                writeln!(rust, "    }}")?;
+                writeln!(rust, "}}")?;
+            }
+            other => anyhow::bail!(
+                "unknown Rust framework ({other:?}), expected one of \"egui\", \"iced\", or \"bevy\""
+            ),
+        }
+    }
+
+    Ok(vec![GeneratedFile {
+        path: PathBuf::from("design_tokens.rs"),
+        contents: rust,
+    }])
+}
+
+/// Generates the contents of `design_tokens.rs` straight from the system input,
+/// so a `build.rs` can keep the constants in sync with the design system.
+/// `settings_json` is the system input, like the JSON printed by `examples/get-settings-json-to-stdout.ts`.
+pub fn generate_rust_for_build_script(
+    settings_json: &str,
+    text_styles_query: &str,
+) -> Result<String> {
+    let input = serde_json::from_str::<crate::input::SystemInput>(settings_json)
+        .context("reading system input JSON")?;
+    let all_tokens: TypographyExport = generate_typography_all_tokens(&input.typography)
+        .context("generating all tokens")?
+        .into();
+    let aliases = TokenAliases::from_input(input.aliases.as_deref().unwrap_or_default())?;
+    let colors = input
+        .color_palette
+        .as_ref()
+        .map(crate::color::output::generate_colors)
+        .transpose()?;
+    let query = Query::parse(text_styles_query)
+        .map_err(|err| anyhow::anyhow!(err.display_with_source(text_styles_query)))?;
+    let text_styles = materialize_text_styles(&all_tokens, &aliases, &query)?;
+    let config = serde_json::from_value::<rust_config::TypographyExtensionInput>(
+        input.typography.Extensions.clone(),
+    )
+    .context("reading Rust extension input")?
+    .Rust
+    .unwrap_or_default();
+
+    let mut files = generate_rust(&text_styles, colors.as_ref(), &config)?;
+    Ok(files.remove(0).contents)
+}
+
+/// e.g. `16.0` since Rust float constants need the decimal point
+fn float(value: f64) -> String {
+    let number = format_number(value);
+    match number.contains('.') {
+        true => number,
+        false => format!("{number}.0"),
+    }
+}
@@ -0,0 +1,95 @@
+//! Slint globals: `Typography` with a `TextStyle` struct for each materialized text style,
+//! and `Palette` with a `brush` for each scheme role which follows its `dark-mode` property.
+//!
+//! ```slint
+//! import { Typography, Palette } from "design-tokens.slint";
+//!
+//! Text {
+//!     font-family: Typography.text-ui-sm.font-family;
+//!     font-size: Typography.text-ui-sm.font-size;
+//!     color: Palette.on-surface;
+//! }
+//! ```
+use std::{fmt::Write, path::PathBuf};
+
+use super::{kebab_case, token_set_string, words, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    typography::{styles::MaterializedTextStyle, units::Length},
+};
+
+/// `design-tokens.slint`
+pub fn generate_slint(
+    text_styles: &[MaterializedTextStyle],
+    colors: Option<&ColorExport>,
+) -> Result<Vec<GeneratedFile>> {
+    let mut slint = String::new();
+    writeln!(slint, "// Generated by design-tokens, do not edit.")?;
+    writeln!(slint)?;
+    writeln!(slint, "export struct TextStyle {{")?;
+    writeln!(slint, "    font-family: string,")?;
+    writeln!(slint, "    font-size: length,")?;
+    writeln!(
+        slint,
+        "    // 0px when the text style leaves it to the font"
+    )?;
+    writeln!(slint, "    line-height: length,")?;
+    writeln!(slint, "    letter-spacing: length,")?;
+    writeln!(slint, "    font-weight: int,")?;
+    writeln!(slint, "    font-italic: bool,")?;
+    writeln!(slint, "}}")?;
+
+    writeln!(slint)?;
+    writeln!(slint, "export global Typography {{")?;
+    for style in text_styles {
+        let doc = token_set_string(&style.tokens);
+        writeln!(slint, "    // {doc}")?;
+        writeln!(
+            slint,
+            "    out property <TextStyle> {}: {{",
+            kebab_case(&words(style.tokens.path()))
+        )?;
+        writeln!(slint, "        font-family: {:?},", style.family_name)?;
+        writeln!(
+            slint,
+            "        font-size: {},",
+            Length::Px(style.font_size_px)
+        )?;
+        writeln!(
+            slint,
+            "        line-height: {},",
+            Length::Px(style.line_height_px.unwrap_or(0.0))
+        )?;
+        writeln!(
+            slint,
+            "        letter-spacing: {},",
+            Length::Px(style.letter_spacing_px.unwrap_or(0.0))
+        )?;
+        writeln!(slint, "        font-weight: {},", style.font_weight)?;
+        writeln!(slint, "        font-italic: {},", style.italic)?;
+        writeln!(slint, "    }};")?;
+    }
+    writeln!(slint, "}}")?;
+
+    if let Some(colors) = colors {
+        writeln!(slint)?;
+        writeln!(slint, "export global Palette {{")?;
+        writeln!(slint, "    in-out property <bool> dark-mode: false;")?;
+        for role in colors.scheme_roles() {
+            writeln!(
+                slint,
+                "    out property <brush> {}: dark-mode ? {} : {};",
+                kebab_case(&words([role.role])),
+                role.dark_hex,
+                role.light_hex
+            )?;
+        }
+        writeln!(slint, "}}")?;
+    }
+
+    Ok(vec![GeneratedFile {
+        path: PathBuf::from("design-tokens.slint"),
+        contents: slint,
+    }])
+}
@@ -0,0 +1,151 @@
+//! SwiftUI `Font` and `TextStyleToken` helpers for each materialized text style,
+//! and `Colors.xcassets` color sets with light and dark appearances for each scheme role.
+use std::{fmt::Write, path::PathBuf};
+
+use serde_json::json;
+
+use super::{camel_case, hex_rgb, token_set_string, words, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    typography::{input::FontFamilyInfo, styles::MaterializedTextStyle, units::format_number},
+};
+
+/// `DesignTokens.swift` and `Colors.xcassets`
+pub fn generate_swift(
+    text_styles: &[MaterializedTextStyle],
+    families: &[FontFamilyInfo],
+    colors: Option<&ColorExport>,
+) -> Result<Vec<GeneratedFile>> {
+    let mut files = Vec::new();
+    let mut swift = String::new();
+    writeln!(swift, "// Generated by design-tokens, do not edit.")?;
+    writeln!(swift, "import SwiftUI")?;
+    writeln!(swift)?;
+    writeln!(swift, "public struct TextStyleToken {{")?;
+    writeln!(swift, "    public let font: Font")?;
+    writeln!(swift, "    public let size: CGFloat")?;
+    writeln!(
+        swift,
+        "    /// Added between lines to reach the line height of the text style"
+    )?;
+    writeln!(swift, "    public let lineSpacing: CGFloat")?;
+    writeln!(swift, "    public let tracking: CGFloat")?;
+    writeln!(swift, "}}")?;
+    writeln!(swift)?;
+    writeln!(swift, "extension View {{")?;
+    writeln!(
+        swift,
+        "    public func textStyle(_ style: TextStyleToken) -> some View {{"
+    )?;
+    writeln!(
+        swift,
+        "        self.font(style.font).lineSpacing(style.lineSpacing).tracking(style.tracking)"
+    )?;
+    writeln!(swift, "    }}")?;
+    writeln!(swift, "}}")?;
+
+    let mut fonts = String::new();
+    let mut tokens = String::new();
+    for style in text_styles {
+        let name = camel_case(&words(style.tokens.path()));
+        let family = families
+            .iter()
+            .find(|family| family.BaseName == style.family_name)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "text style ({:?}) uses family ({:?}) which does not have an entry in `Families`",
+                    style.tokens,
+                    style.family_name
+                )
+            })?;
+        let metrics = &family.Metrics;
+        // SwiftUI only adds spacing on top of the font's own line height
+        let natural_line_height_px = (metrics.ascent + metrics.descent.abs() + metrics.lineGap)
+            / metrics.unitsPerEm
+            * style.font_size_px;
+        let line_spacing_px = style
+            .line_height_px
+            .map_or(0.0, |px| (px - natural_line_height_px).max(0.0));
+
+        let doc = token_set_string(&style.tokens);
+        // `Font.custom` finds fonts by PostScript name, which already picks the weight and italic face
+        let font = format!(
+            "Font.custom({:?}, size: {})",
+            family.post_script_name(style.font_weight, style.italic),
+            format_number(style.font_size_px),
+        );
+        writeln!(fonts, "    /// `{doc}`")?;
+        writeln!(fonts, "    public static let {name} = {font}")?;
+        writeln!(tokens, "    /// `{doc}`")?;
+        writeln!(
+            tokens,
+            "    public static let {name} = TextStyleToken(font: .{name}, size: {}, lineSpacing: {}, tracking: {})",
+            format_number(style.font_size_px),
+            format_number(line_spacing_px),
+            format_number(style.letter_spacing_px.unwrap_or(0.0)),
+        )?;
+    }
+    writeln!(swift)?;
+    writeln!(swift, "extension Font {{\n{fonts}}}")?;
+    writeln!(swift)?;
+    writeln!(swift, "extension TextStyleToken {{\n{tokens}}}")?;
+
+    if let Some(colors) = colors {
+        let catalog = PathBuf::from("Colors.xcassets");
+        files.push(GeneratedFile {
+            path: catalog.join("Contents.json"),
+            contents: serde_json::to_string_pretty(&json!({
+                "info": { "author": "xcode", "version": 1 }
+            }))?,
+        });
+
+        writeln!(swift)?;
+        writeln!(swift, "/// Light and dark colors from `Colors.xcassets`")?;
+        writeln!(swift, "public enum ColorToken {{")?;
+        for role in colors.scheme_roles() {
+            let name = camel_case(&words([role.role]));
+            writeln!(swift, "    public static let {name} = Color({name:?})")?;
+            files.push(GeneratedFile {
+                path: catalog
+                    .join(format!("{name}.colorset"))
+                    .join("Contents.json"),
+                contents: serde_json::to_string_pretty(&json!({
+                    "colors": [
+                        { "color": color_set_color(role.light_hex)?, "idiom": "universal" },
+                        {
+                            "appearances": [{ "appearance": "luminosity", "value": "dark" }],
+                            "color": color_set_color(role.dark_hex)?,
+                            "idiom": "universal",
+                        },
+                    ],
+                    "info": { "author": "xcode", "version": 1 },
+                }))?,
+            });
+        }
+        writeln!(swift, "}}")?;
+    }
+
+    files.insert(
+        0,
+        GeneratedFile {
+            path: PathBuf::from("DesignTokens.swift"),
+            contents: swift,
+        },
+    );
+
+    Ok(files)
+}
+
+fn color_set_color(hex: &str) -> Result<serde_json::Value> {
+    let [red, green, blue] = hex_rgb(hex)?;
+    Ok(json!({
+        "color-space": "srgb",
+        "components": {
+            "alpha": "1.000",
+            "red": format!("0x{red:02X}"),
+            "green": format!("0x{green:02X}"),
+            "blue": format!("0x{blue:02X}"),
+        },
+    }))
+}
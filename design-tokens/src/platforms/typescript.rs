@@ -0,0 +1,113 @@
+//! A TypeScript module with `as const` objects for each materialized text style and color,
+//! and a `Token` union of every token known to the system (rules, colors, and aliases).
+//!
+//! ```ts
+//! import { textStyles, type TokenOf } from "./design-tokens";
+//!
+//! const size: TokenOf<"size"> = "size:sm"; // "size:huge" is a type error
+//! textStyles["text role:ui size:sm"].fontSize;
+//! ```
+use std::{collections::BTreeSet, fmt::Write, path::PathBuf};
+
+use super::{token_set_string, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    tokens::aliases::TokenAliases,
+    typography::{output::TypographyExport, styles::MaterializedTextStyle, units::format_number},
+};
+
+/// `design-tokens.ts`
+pub fn generate_typescript(
+    all_tokens: &TypographyExport,
+    text_styles: &[MaterializedTextStyle],
+    colors: Option<&ColorExport>,
+    aliases: &TokenAliases,
+) -> Result<Vec<GeneratedFile>> {
+    let mut ts = String::new();
+    writeln!(ts, "// Generated by design-tokens, do not edit.")?;
+
+    writeln!(ts)?;
+    writeln!(ts, "export const textStyles = {{")?;
+    for style in text_styles {
+        let mut fields = vec![
+            format!("fontFamily: {}", string(&style.family_name)?),
+            format!("fontSize: {}", format_number(style.font_size_px)),
+        ];
+        if let Some(px) = style.line_height_px {
+            fields.push(format!("lineHeight: {}", format_number(px)));
+        }
+        if let Some(px) = style.letter_spacing_px {
+            fields.push(format!("letterSpacing: {}", format_number(px)));
+        }
+        fields.push(format!("fontWeight: {}", style.font_weight));
+        fields.push(format!("italic: {}", style.italic));
+        writeln!(
+            ts,
+            "  {}: {{ {} }},",
+            string(&token_set_string(&style.tokens))?,
+            fields.join(", ")
+        )?;
+    }
+    writeln!(ts, "}} as const;")?;
+    writeln!(ts)?;
+    writeln!(ts, "export type TextStyleName = keyof typeof textStyles;")?;
+
+    if let Some(colors) = colors {
+        writeln!(ts)?;
+        writeln!(ts, "export const colors = {{")?;
+        for color in &colors.colors {
+            writeln!(
+                ts,
+                "  {}: {},",
+                string(&token_set_string(&color.tokens))?,
+                string(&color.hex)?
+            )?;
+        }
+        writeln!(ts, "}} as const;")?;
+        writeln!(ts)?;
+        writeln!(ts, "export type ColorName = keyof typeof colors;")?;
+    }
+
+    let mut known = BTreeSet::<String>::new();
+    for (reqs, _) in all_tokens.iter_rules() {
+        known.extend(reqs.iter().map(|token| token.to_string()));
+    }
+    for style in text_styles {
+        known.extend(style.tokens.iter().map(|token| token.to_string()));
+    }
+    for color in colors.iter().flat_map(|colors| colors.colors.iter()) {
+        known.extend(color.tokens.iter().map(|token| token.to_string()));
+    }
+    for (alias, references) in aliases.iter() {
+        known.insert(alias.to_string());
+        known.extend(references.iter().map(|token| token.to_string()));
+    }
+    writeln!(ts)?;
+    match known.is_empty() {
+        true => writeln!(ts, "export type Token = never;")?,
+        false => {
+            writeln!(ts, "export type Token =")?;
+            for token in &known {
+                writeln!(ts, "  | {}", string(token)?)?;
+            }
+            writeln!(ts, "  ;")?;
+        }
+    }
+    writeln!(ts)?;
+    writeln!(ts, "/** e.g. `TokenOf<\"size\">` is `\"size:sm\" | \"size:lg\"` */")?;
+    writeln!(
+        ts,
+        "export type TokenOf<Key extends string> = Extract<Token, `${{Key}}:${{string}}`>;"
+    )?;
+
+    Ok(vec![GeneratedFile {
+        path: PathBuf::from("design-tokens.ts"),
+        contents: ts,
+    }])
+}
+
+/// JSON strings are valid TypeScript string literals
+fn string(value: &str) -> Result<String> {
+    Ok(serde_json::to_string(value)?)
+}
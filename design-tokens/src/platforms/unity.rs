@@ -0,0 +1,162 @@
+//! Unity C# constants for each materialized text style and color, and UI Toolkit
+//! USS variables (with a class for each text style) for the same tokens.
+//!
+//! USS has no line height, and only `-unity-font-style` for weight, so the C# constants
+//! are the complete source for text styles.
+use std::{fmt::Write, path::PathBuf};
+
+use super::{hex_rgb, kebab_case, pascal_case, token_set_string, words, GeneratedFile};
+use crate::{
+    color::output::ColorExport,
+    prelude::*,
+    typography::{
+        styles::MaterializedTextStyle,
+        units::{format_number, Length},
+    },
+};
+
+/// `DesignTokens.cs` and `DesignTokens.uss`
+pub fn generate_unity(
+    text_styles: &[MaterializedTextStyle],
+    colors: Option<&ColorExport>,
+) -> Result<Vec<GeneratedFile>> {
+    let mut cs = String::new();
+    writeln!(cs, "// Generated by design-tokens, do not edit.")?;
+    writeln!(cs, "using UnityEngine;")?;
+    writeln!(cs)?;
+    writeln!(cs, "namespace DesignTokens")?;
+    writeln!(cs, "{{")?;
+    writeln!(cs, "    public readonly struct TextStyleToken")?;
+    writeln!(cs, "    {{")?;
+    for (field, ty) in [
+        ("FontFamily", "string"),
+        ("FontSize", "float"),
+        ("LineHeight", "float?"),
+        ("LetterSpacing", "float"),
+        ("FontWeight", "int"),
+        ("Italic", "bool"),
+    ] {
+        writeln!(cs, "        public readonly {ty} {field};")?;
+    }
+    writeln!(cs)?;
+    writeln!(
+        cs,
+        "        public TextStyleToken(string fontFamily, float fontSize, float? lineHeight, float letterSpacing, int fontWeight, bool italic)"
+    )?;
+    writeln!(cs, "        {{")?;
+    for (field, param) in [
+        ("FontFamily", "fontFamily"),
+        ("FontSize", "fontSize"),
+        ("LineHeight", "lineHeight"),
+        ("LetterSpacing", "letterSpacing"),
+        ("FontWeight", "fontWeight"),
+        ("Italic", "italic"),
+    ] {
+        writeln!(cs, "            {field} = {param};")?;
+    }
+    writeln!(cs, "        }}")?;
+    writeln!(cs, "    }}")?;
+
+    let mut uss = String::new();
+    let mut uss_classes = String::new();
+    writeln!(uss, "/* Generated by design-tokens, do not edit. */")?;
+    writeln!(uss, ":root {{")?;
+
+    writeln!(cs)?;
+    writeln!(cs, "    public static class TextStyles")?;
+    writeln!(cs, "    {{")?;
+    for style in text_styles {
+        let doc = token_set_string(&style.tokens);
+        let path = words(style.tokens.path());
+        writeln!(cs, "        /// <summary><c>{doc}</c></summary>")?;
+        writeln!(
+            cs,
+            "        public static readonly TextStyleToken {} = new TextStyleToken({:?}, {}, {}, {}, {}, {});",
+            pascal_case(&path),
+            style.family_name,
+            float(style.font_size_px),
+            style.line_height_px.map_or("null".to_string(), float),
+            float(style.letter_spacing_px.unwrap_or(0.0)),
+            style.font_weight,
+            style.italic,
+        )?;
+
+        let name = kebab_case(&path);
+        let font_style = match (style.font_weight >= 600, style.italic) {
+            (false, false) => "normal",
+            (true, false) => "bold",
+            (false, true) => "italic",
+            (true, true) => "bold-and-italic",
+        };
+        writeln!(uss, "    /* {doc} */")?;
+        writeln!(
+            uss,
+            "    --{name}-font-size: {};",
+            Length::Px(style.font_size_px)
+        )?;
+        writeln!(
+            uss,
+            "    --{name}-letter-spacing: {};",
+            Length::Px(style.letter_spacing_px.unwrap_or(0.0))
+        )?;
+        writeln!(uss, "    --{name}-font-style: {font_style};")?;
+        writeln!(uss_classes)?;
+        writeln!(uss_classes, ".{name} {{")?;
+        writeln!(uss_classes, "    font-size: var(--{name}-font-size);")?;
+        writeln!(
+            uss_classes,
+            "    letter-spacing: var(--{name}-letter-spacing);"
+        )?;
+        writeln!(
+            uss_classes,
+            "    -unity-font-style: var(--{name}-font-style);"
+        )?;
+        writeln!(uss_classes, "}}")?;
+    }
+    writeln!(cs, "    }}")?;
+
+    if let Some(colors) = colors {
+        writeln!(cs)?;
+        writeln!(cs, "    public static class Colors")?;
+        writeln!(cs, "    {{")?;
+        for color in &colors.colors {
+            // e.g. `color:primary tone:40` is `Primary40` and `--color-primary-40`
+            let path = words(color.tokens.path());
+            let [red, green, blue] = hex_rgb(&color.hex)?;
+            writeln!(
+                cs,
+                "        public static readonly Color32 {} = new Color32({red}, {green}, {blue}, 255);",
+                pascal_case(&path)
+            )?;
+            writeln!(
+                uss,
+                "    --{}: {};",
+                kebab_case(&words(
+                    std::iter::once("color".to_string()).chain(color.tokens.path())
+                )),
+                color.hex
+            )?;
+        }
+        writeln!(cs, "    }}")?;
+    }
+
+    writeln!(cs, "}}")?;
+    writeln!(uss, "}}")?;
+    uss += &uss_classes;
+
+    Ok(vec![
+        GeneratedFile {
+            path: PathBuf::from("DesignTokens.cs"),
+            contents: cs,
+        },
+        GeneratedFile {
+            path: PathBuf::from("DesignTokens.uss"),
+            contents: uss,
+        },
+    ])
+}
+
+/// e.g. `12.375f`
+fn float(value: f64) -> String {
+    format!("{}f", format_number(value))
+}
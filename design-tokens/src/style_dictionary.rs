@@ -0,0 +1,195 @@
+//! Style Dictionary's nested `{ "value", "type", "comment" }` JSON, see https://amzn.github.io/style-dictionary/#/tokens
+//!
+//! Teams with existing Style Dictionary transforms (e.g. for iOS and Android) can consume
+//! the materialized text styles and colors without changing their pipelines.
+use crate::prelude::*;
+
+pub mod style_dictionary_config {
+    use crate::prelude::*;
+
+    // Must be named `TypographyExtensionInput` to ensure it merges with other typography extensions
+    #[derive(Debug, Codegen, Deserialize)]
+    #[codegen(tags = "style-dictionary-input")]
+    #[codegen(ts_interface_merge)]
+    #[allow(non_snake_case)]
+    pub struct TypographyExtensionInput {
+        #[serde(alias = "styleDictionary")]
+        pub StyleDictionary: Option<StyleDictionaryConfig>,
+    }
+
+    #[derive(Codegen, Debug, Default, Deserialize)]
+    #[codegen(tags = "style-dictionary-input")]
+    #[allow(non_snake_case)]
+    pub struct StyleDictionaryConfig {
+        /// Order of token keys in each name path, e.g. `["size", "role"]` names
+        /// `text role:ui size:sm italic:true` as `text.size/sm.role/ui.italic`.
+        /// Other keys follow in alphabetical order.
+        pub PathKeysOption: Option<Vec<String>>,
+    }
+}
+
+pub mod style_dictionary_export {
+    use serde_json::{json, Map, Value};
+
+    use super::style_dictionary_config::StyleDictionaryConfig;
+    use crate::{
+        color::output::ColorExport,
+        prelude::*,
+        tokens::{
+            aliases::TokenAliases,
+            nested::{color_group_path, group_path, NestedFormat},
+            query::Query,
+            Token, TokenSet,
+        },
+        typography::{output::TypographyExport, styles::materialize_text_styles, units::Length},
+    };
+
+    /// Style Dictionary has no way for a token to also be a group, so when they
+    /// share a path (e.g. `color.brand` and `color.brand.40`) the token moves to `base`.
+    const STYLE_DICTIONARY: NestedFormat = NestedFormat {
+        name: "Style Dictionary",
+        value_key: "value",
+        root_key: "base",
+    };
+
+    pub fn generate_style_dictionary(
+        all_tokens: &TypographyExport,
+        colors: Option<&ColorExport>,
+        aliases: &TokenAliases,
+        text_styles_query: &Query,
+        config: &StyleDictionaryConfig,
+    ) -> Result<Value> {
+        let mut root = Map::new();
+        let key_order = config.PathKeysOption.as_deref().unwrap_or_default();
+
+        for style in materialize_text_styles(all_tokens, aliases, text_styles_query)? {
+            let mut value = Map::new();
+            value.insert("fontFamily".into(), json!(style.family_name));
+            value.insert(
+                "fontSize".into(),
+                json!(Length::Px(style.font_size_px).to_string()),
+            );
+            value.insert("fontWeight".into(), json!(style.font_weight));
+            if style.italic {
+                value.insert("fontStyle".into(), json!("italic"));
+            }
+            if let Some(px) = style.letter_spacing_px {
+                value.insert("letterSpacing".into(), json!(Length::Px(px).to_string()));
+            }
+            if let Some(px) = style.line_height_px {
+                value.insert("lineHeight".into(), json!(Length::Px(px).to_string()));
+            }
+            STYLE_DICTIONARY.insert_token(
+                &mut root,
+                &group_path(&style.tokens, key_order),
+                json!({
+                    "value": value,
+                    "type": "typography",
+                    "comment": comment(&style.tokens),
+                }),
+            )?;
+        }
+
+        if let Some(colors) = colors {
+            for color in &colors.colors {
+                STYLE_DICTIONARY.insert_token(
+                    &mut root,
+                    &color_group_path(&color.tokens, key_order),
+                    json!({
+                        "value": color.hex,
+                        "type": "color",
+                        "comment": comment(&color.tokens),
+                    }),
+                )?;
+            }
+
+            // e.g. `color:on-brand` referencing `color:brand tone:100` is `"{color.brand.tone/100.value}"`
+            for (alias, references) in aliases.iter() {
+                if !matches!(alias, Token::Value(key, _) if key == "color") {
+                    continue;
+                }
+                let alias_set = TokenSet::from([alias.clone()]);
+                if let Some(target) = colors.find(&alias_set, aliases) {
+                    let reference = STYLE_DICTIONARY
+                        .reference_to(&root, &color_group_path(&target.tokens, key_order));
+                    STYLE_DICTIONARY.insert_token(
+                        &mut root,
+                        &color_group_path(&alias_set, key_order),
+                        json!({
+                            "value": format!("{{{reference}.value}}"),
+                            "type": "color",
+                            "comment": format!("{} references {}", comment(&alias_set), comment(references)),
+                        }),
+                    )?;
+                }
+            }
+        }
+
+        Ok(Value::Object(root))
+    }
+
+    fn comment(tokens: &TokenSet) -> String {
+        tokens
+            .iter()
+            .map(|token| token.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{style_dictionary_config::StyleDictionaryConfig, style_dictionary_export::*};
+    use crate::{
+        color::output::generate_colors,
+        input::SystemInput,
+        tokens::{aliases::TokenAliases, query::Query},
+        typography::output::{generate_typography_all_tokens, TypographyExport},
+    };
+
+    #[test]
+    fn name_paths_keep_token_keys() {
+        let input = serde_json::from_str::<SystemInput>(
+            &std::fs::read_to_string(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("src/platforms/fixtures/system-input.json"),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let all_tokens: TypographyExport = generate_typography_all_tokens(&input.typography)
+            .unwrap()
+            .into();
+        let aliases =
+            TokenAliases::from_input(input.aliases.as_deref().unwrap_or_default()).unwrap();
+        let colors = generate_colors(input.color_palette.as_ref().unwrap()).unwrap();
+        let config = StyleDictionaryConfig {
+            PathKeysOption: Some(vec!["size".to_string(), "role".to_string()]),
+        };
+
+        let exported = generate_style_dictionary(
+            &all_tokens,
+            Some(&colors),
+            &aliases,
+            &Query::parse("text role:ui size:sm weight:700 italic:true").unwrap(),
+            &config,
+        )
+        .unwrap();
+        let style = &exported["text"]["size/sm"]["role/ui"]["italic"]["weight/700"];
+        assert_eq!(style["type"], "typography", "{exported:#}");
+        assert_eq!(style["value"]["fontWeight"], 700);
+        assert_eq!(style["value"]["fontStyle"], "italic");
+        assert_eq!(
+            style["comment"],
+            "text italic:true role:ui size:sm weight:700"
+        );
+
+        assert_eq!(exported["color"]["blue"]["tone/40"]["type"], "color");
+        assert_eq!(
+            exported["color"]["accent"]["value"],
+            "{color.blue.tone/40.value}"
+        );
+    }
+}
@@ -4,6 +4,10 @@ use std::{
     fmt::Display,
 };
 
+pub mod aliases;
+pub mod nested;
+pub mod query;
+
 #[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Token {
     Kind(Cowstr),
@@ -149,10 +153,61 @@ pub enum MatchErr {
     },
 }
 
+impl Display for MatchErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchErr::MissingKind(kind) => write!(f, "missing kind {kind}"),
+            MatchErr::MissingValueOf(key, value) => write!(f, "missing {key}:{value}"),
+            MatchErr::KindIsDifferent {
+                kind_expected,
+                kind_found,
+            } => write!(f, "kind is {kind_found}, expected {kind_expected}"),
+            MatchErr::ValueIsDifferent {
+                key,
+                value_expected,
+                value_found,
+            } => write!(f, "{key} is {value_found}, expected {value_expected}"),
+        }
+    }
+}
+
 impl TokenSet {
     pub fn new() -> Self {
         TokenSet(None, BTreeMap::new())
     }
+    pub fn kind(&self) -> Option<&Cowstr> {
+        self.0.as_ref()
+    }
+    pub fn get(&self, key: &str) -> Option<&Cowstr> {
+        self.1.get(key)
+    }
+    /// Number of tokens, including the kind, which is also the specificity of a rule.
+    pub fn len(&self) -> usize {
+        self.0.iter().count() + self.1.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none() && self.1.is_empty()
+    }
+    /// The kind followed by each value in key order,
+    /// e.g. `text role:ui size:sm` is `["text", "ui", "sm"]`.
+    pub fn path(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .chain(self.1.values())
+            .map(|segment| segment.to_string())
+            .collect()
+    }
+    /// Each key and value, with the keys in `key_order` first and then the rest in key order.
+    pub fn values_ordered_by<'a>(&'a self, key_order: &[String]) -> Vec<(&'a Cowstr, &'a Cowstr)> {
+        let mut values: Vec<_> = self.1.iter().collect();
+        values.sort_by_key(|(key, _)| {
+            key_order
+                .iter()
+                .position(|ordered| ordered == key.as_ref())
+                .unwrap_or(key_order.len())
+        });
+        values
+    }
     pub fn iter<'a>(&'a self) -> TokenSetIter<'a> {
         TokenSetIter(self.0.as_ref(), self.1.iter())
     }
@@ -205,6 +260,16 @@ impl TokenSet {
     }
 
     pub fn contains_all_of_explained(&self, other: &TokenSet) -> Result<()> {
+        let errs = self.mismatches(other);
+        if errs.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Failed to match: {errs:#?}"))
+        }
+    }
+
+    /// Each reason `other` is not contained in this set, empty when [TokenSet::contains_all_of].
+    pub fn mismatches(&self, other: &TokenSet) -> Vec<MatchErr> {
         let mut errs = Vec::new();
         match (&other.0, &self.0) {
             (None, _) => {} // other doesn't require a kind
@@ -237,10 +302,6 @@ impl TokenSet {
             }
         }
 
-        if errs.is_empty() {
-            Ok(())
-        } else {
-            Err(anyhow::anyhow!("Failed to match: {errs:#?}"))
-        }
+        errs
     }
 }
@@ -0,0 +1,196 @@
+//! Aliases layer semantic tokens over primitive ones, e.g. `role:heading` for
+//! `role:ui weight:600`, or `color:on-primary` for `color:primary tone:100`.
+use std::collections::BTreeMap;
+
+use crate::prelude::*;
+
+use super::{split_tokens, Token, TokenSet};
+
+#[derive(Codegen, Debug, Deserialize, Serialize)]
+#[codegen(tags = "input")]
+pub struct TokenAliasInput {
+    /// e.g. `"role:heading"`
+    pub token: String,
+    /// e.g. `"role:ui weight:600"`, which may include other aliases.
+    pub references: String,
+}
+
+/// Each alias with its references resolved down to tokens which are not aliases.
+#[derive(Debug, Default)]
+pub struct TokenAliases(BTreeMap<Token, TokenSet>);
+
+impl TokenAliases {
+    pub fn from_input(inputs: &[TokenAliasInput]) -> Result<Self> {
+        let mut declared = BTreeMap::<Token, Vec<Token>>::new();
+        for input in inputs {
+            let token = Token::try_from(input.token.as_str())
+                .with_context(|| format!("reading alias ({:?})", input.token))?;
+            let references = split_tokens(&input.references).with_context(|| {
+                format!(
+                    "reading references ({:?}) of alias ({:?})",
+                    input.references, input.token
+                )
+            })?;
+            if declared.insert(token, references).is_some() {
+                anyhow::bail!("alias ({:?}) is declared more than once", input.token);
+            }
+        }
+
+        let mut resolved = BTreeMap::new();
+        for token in declared.keys() {
+            let token_set = resolve_alias(token, &declared, &mut Vec::new())?;
+            resolved.insert(token.clone(), token_set);
+        }
+
+        Ok(TokenAliases(resolved))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Token, &TokenSet)> {
+        self.0.iter()
+    }
+
+    /// Replaces alias tokens with the tokens they reference.
+    /// Tokens given directly take precedence over tokens from an alias,
+    /// so `role:heading weight:700` is `role:ui weight:700`.
+    pub fn resolve(&self, token_set: &TokenSet) -> TokenSet {
+        if self.0.is_empty() {
+            return token_set.clone();
+        }
+
+        let mut resolved = TokenSet::new();
+        for token in token_set.iter() {
+            if let Some(references) = self.0.get(&token) {
+                resolved.append(references.iter());
+            }
+        }
+        for token in token_set.iter() {
+            if !self.0.contains_key(&token) {
+                resolved.insert(token);
+            }
+        }
+        resolved
+    }
+}
+
+fn resolve_alias(
+    token: &Token,
+    declared: &BTreeMap<Token, Vec<Token>>,
+    chain: &mut Vec<Token>,
+) -> Result<TokenSet> {
+    if chain.contains(token) {
+        anyhow::bail!(
+            "alias references form a cycle: {}",
+            chain
+                .iter()
+                .chain([token])
+                .map(|token| token.to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        );
+    }
+
+    chain.push(token.clone());
+    let mut resolved = TokenSet::new();
+    for reference in &declared[token] {
+        if declared.contains_key(reference) {
+            resolved.append(resolve_alias(reference, declared, chain)?.iter());
+        }
+    }
+    // like [TokenAliases::resolve], direct references win over ones from nested aliases
+    for reference in &declared[token] {
+        if !declared.contains_key(reference) {
+            resolved.insert(reference.clone());
+        }
+    }
+    chain.pop();
+
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases(declared: &[(&str, &str)]) -> Result<TokenAliases> {
+        let inputs = declared
+            .iter()
+            .map(|(token, references)| TokenAliasInput {
+                token: token.to_string(),
+                references: references.to_string(),
+            })
+            .collect::<Vec<_>>();
+        TokenAliases::from_input(&inputs)
+    }
+
+    fn token_set(tokens: &str) -> TokenSet {
+        TokenSet::from(split_tokens(tokens).unwrap())
+    }
+
+    #[test]
+    fn direct_cycle() {
+        let err = aliases(&[("role:loop", "role:loop size:sm")]).unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "alias references form a cycle: role:loop -> role:loop"
+        );
+    }
+
+    #[test]
+    fn indirect_cycle() {
+        let err = aliases(&[
+            ("role:a", "role:b weight:400"),
+            ("role:b", "role:c"),
+            ("role:c", "size:sm role:a"),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "alias references form a cycle: role:a -> role:b -> role:c -> role:a"
+        );
+    }
+
+    #[test]
+    fn duplicate_alias() {
+        let err = aliases(&[
+            ("role:heading", "role:ui"),
+            ("role:heading", "role:display"),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "alias (\"role:heading\") is declared more than once"
+        );
+    }
+
+    #[test]
+    fn explicit_tokens_win_over_aliases() {
+        let aliases = aliases(&[
+            ("role:heading", "role:ui weight:600 size:lg"),
+            // the explicit `weight:800` wins, even though it's listed first
+            ("role:title", "weight:800 role:heading"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            aliases.resolve(&token_set("text role:heading")),
+            token_set("text role:ui weight:600 size:lg")
+        );
+        assert_eq!(
+            aliases.resolve(&token_set("text role:heading weight:700")),
+            token_set("text role:ui weight:700 size:lg")
+        );
+        assert_eq!(
+            aliases.resolve(&token_set("text role:title")),
+            token_set("text role:ui weight:800 size:lg")
+        );
+        assert_eq!(
+            aliases.resolve(&token_set("text role:title size:sm weight:300")),
+            token_set("text role:ui weight:300 size:sm")
+        );
+        // tokens which aren't aliases pass through
+        assert_eq!(
+            aliases.resolve(&token_set("text role:ui")),
+            token_set("text role:ui")
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Nested JSON groups shared by the DTCG and Style Dictionary exports, where each token
+//! is an object with a value key (`"$value"` or `"value"`) inside groups named by its path.
+use serde_json::{json, Map, Value};
+
+use super::{Token, TokenSet};
+use crate::prelude::*;
+
+/// Group names for a token set: the kind, then `key/value` for each value, or just the key
+/// for `true` flags, e.g. `text role:ui weight:700 italic:true` is
+/// `["text", "italic", "role/ui", "weight/700"]`. Keeping the keys means values like
+/// `italic:true` and `mono:true` can't end up in the same group.
+/// Keys in `key_order` come first, then the rest in key order.
+pub fn group_path(tokens: &TokenSet, key_order: &[String]) -> Vec<String> {
+    tokens
+        .kind()
+        .map(|kind| kind.to_string())
+        .into_iter()
+        .chain(
+            tokens
+                .values_ordered_by(key_order)
+                .into_iter()
+                .map(|(key, value)| match value.as_ref() {
+                    "true" => key.to_string(),
+                    value => format!("{key}/{value}"),
+                }),
+        )
+        .collect()
+}
+
+/// Colors are grouped by name under `color`, and then by their other tokens,
+/// e.g. `color:blue tone:40` is `["color", "blue", "tone/40"]`.
+pub fn color_group_path(tokens: &TokenSet, key_order: &[String]) -> Vec<String> {
+    let others = tokens
+        .iter()
+        .filter(|token| !matches!(token, Token::Value(key, _) if key == "color"));
+    std::iter::once("color".to_string())
+        .chain(tokens.get("color").map(|name| name.to_string()))
+        .chain(group_path(&TokenSet::from(others), key_order))
+        .collect()
+}
+
+pub struct NestedFormat {
+    /// e.g. `"DTCG"`, for error messages
+    pub name: &'static str,
+    /// Key which marks an object as a token rather than a group, e.g. `"$value"`
+    pub value_key: &'static str,
+    /// Key holding a token which shares its path with a group, e.g. `"$root"`
+    pub root_key: &'static str,
+}
+
+impl NestedFormat {
+    /// When a token and a group share a path (e.g. `color.blue` and `color.blue.40`),
+    /// the token moves into the group under [NestedFormat::root_key].
+    pub fn insert_token(
+        &self,
+        root: &mut Map<String, Value>,
+        path: &[String],
+        token: Value,
+    ) -> Result<()> {
+        let (name, groups) = path
+            .split_last()
+            .ok_or_else(|| anyhow::anyhow!("cannot write a {} token without a name", self.name))?;
+
+        let mut group = root;
+        for segment in groups {
+            let entry = group
+                .entry(segment.clone())
+                .or_insert_with(|| Value::Object(Map::new()));
+            if entry.get(self.value_key).is_some() {
+                let existing = entry.take();
+                *entry = json!({ self.root_key: existing });
+            }
+            group = entry.as_object_mut().expect("groups are always objects");
+        }
+
+        match group.get_mut(name) {
+            None => {
+                group.insert(name.clone(), token);
+            }
+            Some(existing) if existing.get(self.value_key).is_some() => {
+                anyhow::bail!(self.conflict(path));
+            }
+            Some(existing_group) => {
+                let existing_group = existing_group
+                    .as_object_mut()
+                    .expect("groups are always objects");
+                if existing_group.contains_key(self.root_key) {
+                    anyhow::bail!(self.conflict(path));
+                }
+                existing_group.insert(self.root_key.to_string(), token);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// e.g. `"color.blue.$root"` if the token was moved by [NestedFormat::insert_token]
+    pub fn reference_to(&self, root: &Map<String, Value>, path: &[String]) -> String {
+        let mut reference = path.join(".");
+        let found = path
+            .iter()
+            .try_fold(root, |group, segment| group.get(segment)?.as_object());
+        if found.is_some_and(|node| {
+            !node.contains_key(self.value_key) && node.contains_key(self.root_key)
+        }) {
+            reference.push('.');
+            reference.push_str(self.root_key);
+        }
+        reference
+    }
+
+    fn conflict(&self, path: &[String]) -> String {
+        format!("more than one {} token at {:?}", self.name, path.join("."))
+    }
+}
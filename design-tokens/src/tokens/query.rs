@@ -0,0 +1,374 @@
+//! A small query syntax for selecting token sets, e.g.
+//!
+//! ```text
+//! text role:ui size:(sm|base) !italic:true weight:*
+//! ```
+//!
+//!  * `text` requires the kind
+//!  * `role:ui` requires the value
+//!  * `size:(sm|base)` requires one of the values
+//!  * `weight:*` requires any value for the key
+//!  * `!italic:true` requires the token to be absent (works with all of the above)
+//!
+//! Terms are separated by whitespace or commas, like [super::split_tokens].
+use std::ops::Range;
+
+use crate::prelude::*;
+
+use super::TokenSet;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query {
+    pub terms: Vec<QueryTerm>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryTerm {
+    pub negated: bool,
+    pub matcher: TokenMatcher,
+    /// Byte span of the term in the source query.
+    pub span: Range<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TokenMatcher {
+    Kind(Cowstr),
+    Value { key: Cowstr, value: ValuePattern },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValuePattern {
+    /// `*`
+    Any,
+    /// `sm` or `(sm|base)`
+    OneOf(Vec<Cowstr>),
+}
+
+#[derive(Debug)]
+pub struct QueryParseError {
+    pub message: String,
+    /// Byte span in the source query.
+    pub span: Range<usize>,
+}
+
+impl std::fmt::Display for QueryParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (at {}..{})",
+            self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for QueryParseError {}
+
+impl QueryParseError {
+    /// Shows the source with the span underlined, e.g.
+    ///
+    /// ```text
+    /// text size:(sm|
+    ///                ^ expected a value
+    /// ```
+    pub fn display_with_source(&self, source: &str) -> String {
+        let start = source[..self.span.start].chars().count();
+        let width = source[self.span.clone()].chars().count().max(1);
+        format!(
+            "{source}\n{}{} {}",
+            " ".repeat(start),
+            "^".repeat(width),
+            self.message
+        )
+    }
+}
+
+impl Query {
+    pub fn parse(source: &str) -> std::result::Result<Query, QueryParseError> {
+        Parser { source, pos: 0 }.parse_query()
+    }
+
+    /// Whether every term of the query is satisfied by the token set.
+    pub fn matches(&self, token_set: &TokenSet) -> bool {
+        self.terms.iter().all(|term| term.matches(token_set))
+    }
+}
+
+impl QueryTerm {
+    pub fn matches(&self, token_set: &TokenSet) -> bool {
+        let found = match &self.matcher {
+            TokenMatcher::Kind(kind) => token_set.kind() == Some(kind),
+            TokenMatcher::Value { key, value } => match (token_set.get(key), value) {
+                (None, _) => false,
+                (Some(_), ValuePattern::Any) => true,
+                (Some(found), ValuePattern::OneOf(values)) => values.contains(found),
+            },
+        };
+        found != self.negated
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || c == ','
+}
+
+fn is_ident_char(c: char) -> bool {
+    !is_separator(c) && !matches!(c, ':' | '!' | '(' | ')' | '|' | '*')
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_while(&mut self, f: impl Fn(char) -> bool) {
+        while self.peek().is_some_and(&f) {
+            self.bump();
+        }
+    }
+
+    fn error<T>(
+        &self,
+        message: impl Into<String>,
+        span: Range<usize>,
+    ) -> std::result::Result<T, QueryParseError> {
+        Err(QueryParseError {
+            message: message.into(),
+            span,
+        })
+    }
+
+    /// The span of the next character, or an empty span at the end of the source.
+    fn next_span(&self) -> Range<usize> {
+        self.pos..self.pos + self.peek().map_or(0, char::len_utf8)
+    }
+
+    fn parse_query(&mut self) -> std::result::Result<Query, QueryParseError> {
+        let mut terms = Vec::new();
+        loop {
+            self.skip_while(is_separator);
+            if self.peek().is_none() {
+                return Ok(Query { terms });
+            }
+            terms.push(self.parse_term()?);
+        }
+    }
+
+    fn parse_term(&mut self) -> std::result::Result<QueryTerm, QueryParseError> {
+        let start = self.pos;
+        let negated = self.peek() == Some('!');
+        if negated {
+            self.bump();
+        }
+        let key = self.parse_ident("expected a kind or key")?;
+        let matcher = if self.peek() == Some(':') {
+            self.bump();
+            TokenMatcher::Value {
+                key,
+                value: self.parse_value_pattern()?,
+            }
+        } else {
+            TokenMatcher::Kind(key)
+        };
+
+        match self.peek() {
+            Some(c) if !is_separator(c) => self.error(
+                "expected whitespace or a comma between terms",
+                self.next_span(),
+            ),
+            _ => Ok(QueryTerm {
+                negated,
+                matcher,
+                span: start..self.pos,
+            }),
+        }
+    }
+
+    fn parse_value_pattern(&mut self) -> std::result::Result<ValuePattern, QueryParseError> {
+        match self.peek() {
+            Some('*') => {
+                self.bump();
+                Ok(ValuePattern::Any)
+            }
+            Some('(') => {
+                let open = self.pos;
+                self.bump();
+                let mut values = Vec::new();
+                loop {
+                    self.skip_while(char::is_whitespace);
+                    values.push(self.parse_ident("expected a value")?);
+                    self.skip_while(char::is_whitespace);
+                    match self.peek() {
+                        Some('|') => {
+                            self.bump();
+                        }
+                        Some(')') => {
+                            self.bump();
+                            return Ok(ValuePattern::OneOf(values));
+                        }
+                        None => return self.error("unclosed parenthesis", open..open + 1),
+                        Some(_) => return self.error("expected `|` or `)`", self.next_span()),
+                    }
+                }
+            }
+            _ => Ok(ValuePattern::OneOf(vec![
+                self.parse_ident("expected a value, `*`, or `(`")?
+            ])),
+        }
+    }
+
+    fn parse_ident(&mut self, expected: &str) -> std::result::Result<Cowstr, QueryParseError> {
+        let start = self.pos;
+        self.skip_while(is_ident_char);
+        if start == self.pos {
+            return self.error(expected, self.next_span());
+        }
+        Ok(self.source[start..self.pos].to_string().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::split_tokens;
+
+    fn error(source: &str) -> (String, Range<usize>) {
+        let err = Query::parse(source).unwrap_err();
+        (err.message, err.span)
+    }
+
+    #[test]
+    fn parses_terms_with_spans() {
+        let source = "text role:ui, size:(sm | base) !italic:true weight:*";
+        let query = Query::parse(source).unwrap();
+        assert_eq!(
+            query.terms,
+            vec![
+                QueryTerm {
+                    negated: false,
+                    matcher: TokenMatcher::Kind("text".into()),
+                    span: 0..4,
+                },
+                QueryTerm {
+                    negated: false,
+                    matcher: TokenMatcher::Value {
+                        key: "role".into(),
+                        value: ValuePattern::OneOf(vec!["ui".into()]),
+                    },
+                    span: 5..12,
+                },
+                QueryTerm {
+                    negated: false,
+                    matcher: TokenMatcher::Value {
+                        key: "size".into(),
+                        value: ValuePattern::OneOf(vec!["sm".into(), "base".into()]),
+                    },
+                    span: 14..30,
+                },
+                QueryTerm {
+                    negated: true,
+                    matcher: TokenMatcher::Value {
+                        key: "italic".into(),
+                        value: ValuePattern::OneOf(vec!["true".into()]),
+                    },
+                    span: 31..43,
+                },
+                QueryTerm {
+                    negated: false,
+                    matcher: TokenMatcher::Value {
+                        key: "weight".into(),
+                        value: ValuePattern::Any,
+                    },
+                    span: 44..52,
+                },
+            ]
+        );
+        assert_eq!(&source[14..30], "size:(sm | base)");
+        assert_eq!(Query::parse("  ").unwrap().terms, vec![]);
+    }
+
+    #[test]
+    fn matches_token_sets() {
+        let query = Query::parse("text size:(sm|base) !italic:true weight:*").unwrap();
+        let matches = |tokens: &str| query.matches(&TokenSet::from(split_tokens(tokens).unwrap()));
+        assert!(matches("text role:ui size:sm weight:400"));
+        assert!(matches("text size:base weight:700 italic:false"));
+        assert!(!matches("text size:lg weight:400"));
+        assert!(!matches("text size:sm weight:400 italic:true"));
+        assert!(!matches("text size:sm"));
+        assert!(!matches("color size:sm weight:400"));
+    }
+
+    #[test]
+    fn reports_bad_tokens() {
+        assert_eq!(
+            error("text :ui"),
+            ("expected a kind or key".to_string(), 5..6)
+        );
+        assert_eq!(
+            error("text role:"),
+            ("expected a value, `*`, or `(`".to_string(), 10..10)
+        );
+        assert_eq!(
+            error("text role:ui!italic"),
+            (
+                "expected whitespace or a comma between terms".to_string(),
+                12..13
+            )
+        );
+        assert_eq!(
+            error("text weight:*700"),
+            (
+                "expected whitespace or a comma between terms".to_string(),
+                13..14
+            )
+        );
+    }
+
+    #[test]
+    fn reports_unclosed_groups() {
+        assert_eq!(
+            error("text size:(sm|base"),
+            ("unclosed parenthesis".to_string(), 10..11)
+        );
+        assert_eq!(
+            error("text size:(sm|"),
+            ("expected a value".to_string(), 14..14)
+        );
+        assert_eq!(
+            error("text size:(sm base)"),
+            ("expected `|` or `)`".to_string(), 14..15)
+        );
+    }
+
+    /// Spans are in bytes, but underlines are in characters
+    #[test]
+    fn displays_spans_with_source() {
+        let source = "text rôle:(sm base)";
+        let err = Query::parse(source).unwrap_err();
+        assert_eq!(err.span, 15..16);
+        assert_eq!(err.to_string(), "expected `|` or `)` (at 15..16)");
+        assert_eq!(
+            err.display_with_source(source),
+            "text rôle:(sm base)\n              ^ expected `|` or `)`"
+        );
+
+        let source = "text size:(sm|";
+        assert_eq!(
+            Query::parse(source)
+                .unwrap_err()
+                .display_with_source(source),
+            "text size:(sm|\n              ^ expected a value"
+        );
+    }
+}
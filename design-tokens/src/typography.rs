@@ -4,7 +4,9 @@ pub mod css;
 pub mod figma;
 pub mod input;
 pub mod output;
+pub mod styles;
 pub mod tailwind;
+pub mod units;
 pub mod scalars {
     use crate::prelude::*;
 
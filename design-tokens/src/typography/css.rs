@@ -6,16 +6,16 @@ pub mod css_scalars {
     /// This must have the same name as the [crate::typography::scalars::FontStyleRule].
     ///
     /// Another way to think of this is the "CSS-specific" settings.
-    #[derive(Codegen)]
+    #[derive(Codegen, Deserialize)]
     #[codegen(tags = "css-typography-scalar")]
     #[codegen(ts_interface_merge)] // so it can be combined with other specified scalars
     #[allow(non_snake_case)]
     pub struct FontStyleRule {
         #[serde(alias = "css")]
-        CSS: Vec<CSSFontStyleRule>,
+        pub CSS: Vec<CSSFontStyleRule>,
     }
 
-    #[derive(Codegen, Clone, Debug, Serialize)]
+    #[derive(Codegen, Clone, Debug, Serialize, Deserialize)]
     #[codegen(tags = "css-typography-scalar")]
     #[allow(non_snake_case)]
     pub enum CSSFontStyleRule {
@@ -27,3 +27,535 @@ pub mod css_scalars {
         FontVariationSetting(String),
     }
 }
+
+pub mod css_config {
+    use crate::{prelude::*, typography::units::TypographyUnits};
+
+    // Must be named `TypographyExtensionInput` to ensure it merges with other typography extensions
+    /// Depends on `css-typography-scalars`
+    #[derive(Debug, Codegen, Deserialize)]
+    #[codegen(tags = "css-typography-input")]
+    #[codegen(ts_interface_merge)]
+    #[allow(non_snake_case)]
+    pub struct TypographyExtensionInput {
+        /// Defaults to `px` for all units.
+        #[serde(alias = "css")]
+        pub CSS: Option<CSSTypographyConfig>,
+    }
+
+    #[derive(Codegen, Debug, Default, Deserialize)]
+    #[codegen(tags = "css-typography-input")]
+    #[allow(non_snake_case)]
+    pub struct CSSTypographyConfig {
+        /// e.g. `{ FontSizeOption: "Rem", LetterSpacingOption: "Em", LineHeightOption: "Unitless" }`
+        pub UnitsOption: Option<TypographyUnits>,
+    }
+}
+
+pub mod css_export {
+    use super::{css_config, css_scalars};
+    use crate::{
+        prelude::*,
+        tokens::{Token, TokenSet},
+        typography::{
+            input::{FontFamilyInfo, FontFamilyMetrics},
+            output::{TypographyExport, TypographyProperty},
+            units::{format_number, Length, TypographyUnits},
+        },
+    };
+
+    pub struct CSSTypographyExport {
+        pub font_faces: Vec<CSSFallbackFontFace>,
+        pub rules: Vec<CSSRule>,
+        /// e.g. fallbacks without metrics, see [fallback_warnings]
+        pub warnings: Vec<String>,
+    }
+
+    pub struct CSSRule {
+        /// e.g. `".text.role-ui.size-sm"`
+        pub selector: String,
+        pub declarations: Vec<(&'static str, String)>,
+    }
+
+    impl CSSTypographyExport {
+        pub fn to_css_string(&self) -> String {
+            let mut css = String::new();
+            for font_face in &self.font_faces {
+                css.push_str(&font_face.to_css_string());
+            }
+            for rule in &self.rules {
+                css.push_str(&rule.selector);
+                css.push_str(" {\n");
+                for (property, value) in &rule.declarations {
+                    css.push_str(&format!("  {property}: {value};\n"));
+                }
+                css.push_str("}\n");
+            }
+            css
+        }
+    }
+
+    /// Each token becomes a class, e.g. `text role:ui size:sm` becomes `.text.role-ui.size-sm`
+    /// and `italic:true` becomes `.italic`.
+    pub fn selector_for_tokens(tokens: &TokenSet) -> String {
+        tokens
+            .iter()
+            .map(|token| match token {
+                Token::Kind(kind) => format!(".{kind}"),
+                Token::Value(key, value) if value == "true" => format!(".{key}"),
+                Token::Value(key, value) => format!(".{key}-{value}"),
+            })
+            .collect()
+    }
+
+    pub fn generate_typography_for_css(
+        all_tokens: &TypographyExport,
+        families: &[FontFamilyInfo],
+        css_config: &css_config::CSSTypographyConfig,
+    ) -> Result<CSSTypographyExport> {
+        let default_units = TypographyUnits::default();
+        let units = css_config.UnitsOption.as_ref().unwrap_or(&default_units);
+
+        let mut rules = Vec::new();
+        for (tokens, properties) in all_tokens.iter_rules() {
+            let declarations = css_declarations(&properties, families, units)
+                .with_context(|| format!("creating CSS declarations for ({tokens:?})"))?;
+            rules.push(CSSRule {
+                selector: selector_for_tokens(tokens),
+                declarations,
+            });
+        }
+
+        // selectors are as specific as their rules, and the export keeps rules in declaration
+        // order, so the cascade resolves equally specific rules the same way token lookups do
+        Ok(CSSTypographyExport {
+            font_faces: families.iter().flat_map(fallback_font_faces).collect(),
+            rules,
+            warnings: families.iter().flat_map(fallback_warnings).collect(),
+        })
+    }
+
+    /// Font sizes are needed for relative units, so they are looked up among the same properties.
+    pub fn css_declarations(
+        properties: &[&TypographyProperty],
+        families: &[FontFamilyInfo],
+        units: &TypographyUnits,
+    ) -> Result<Vec<(&'static str, String)>> {
+        let font_size_px_opt = properties.iter().find_map(|prop| match prop {
+            TypographyProperty::FontSize { px } => Some(*px),
+            _ => None,
+        });
+
+        let mut declarations = Vec::new();
+        let mut variation_settings = Vec::new();
+        for prop in properties {
+            match prop {
+                TypographyProperty::FontFamily { family_name } => {
+                    declarations.push(("font-family", css_font_family(family_name, families)?));
+                }
+                TypographyProperty::FontSize { px } => {
+                    declarations.push(("font-size", units.font_size(*px).to_string()));
+                }
+                // `em` and unitless values are relative to the font size of the same rule,
+                // so rules without a font size keep `px`
+                TypographyProperty::LineHeight { px } => declarations.push((
+                    "line-height",
+                    match font_size_px_opt {
+                        Some(font_size_px) => units.line_height(*px, font_size_px),
+                        None => Length::Px(*px),
+                    }
+                    .to_string(),
+                )),
+                TypographyProperty::LetterSpacing { px, .. } => declarations.push((
+                    "letter-spacing",
+                    match font_size_px_opt {
+                        Some(font_size_px) => units.letter_spacing(*px, font_size_px),
+                        None => Length::Px(*px),
+                    }
+                    .to_string(),
+                )),
+                // keeps the line box on the baseline grid while moving the glyphs onto it
+                TypographyProperty::BaselineShift { px } => {
+                    declarations.push(("position", "relative".to_string()));
+                    declarations.push(("top", Length::Px(*px).to_string()));
+                }
+                TypographyProperty::FontStyle(style_scalar) => {
+                    let css_rules = serde_json::from_value::<css_scalars::FontStyleRule>(
+                        style_scalar.0.clone(),
+                    )
+                    .context("expecting TypographyProperty::FontStyle to support CSS")?
+                    .CSS;
+                    for css_rule in css_rules {
+                        match css_rule {
+                            css_scalars::CSSFontStyleRule::FontStyleItalics => {
+                                declarations.push(("font-style", "italic".to_string()))
+                            }
+                            css_scalars::CSSFontStyleRule::FontWeightBold => {
+                                declarations.push(("font-weight", "bold".to_string()))
+                            }
+                            css_scalars::CSSFontStyleRule::FontWeight(weight) => {
+                                declarations.push(("font-weight", weight.to_string()))
+                            }
+                            css_scalars::CSSFontStyleRule::FontVariationSetting(setting) => {
+                                variation_settings.push(setting)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if !variation_settings.is_empty() {
+            declarations.push(("font-variation-settings", variation_settings.join(", ")));
+        }
+
+        Ok(declarations)
+    }
+
+    /// e.g. `hnsans, system-ui, "Apple Color Emoji", sans-serif`
+    pub fn css_font_family(family_name: &str, families: &[FontFamilyInfo]) -> Result<String> {
+        let family = families
+            .iter()
+            .find(|family| family.BaseName == family_name)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Family name ({family_name:?}) does not have an entry in `Families`"
+                )
+            })?;
+        let adjusted_fallbacks = fallback_font_faces(family)
+            .into_iter()
+            .map(|font_face| font_face.family_name)
+            .collect::<Vec<_>>();
+        Ok(family
+            .CSSFontFamilyName
+            .iter()
+            .chain(adjusted_fallbacks.iter())
+            .chain(family.CSSFontFamilyFallbacks.iter())
+            .map(|name| match is_css_identifier(name) {
+                true => name.clone(),
+                false => css_string(name),
+            })
+            .collect::<Vec<_>>()
+            .join(", "))
+    }
+
+    /// Generic families like `system-ui` must stay unquoted, so names which are already
+    /// ASCII identifiers are left as is, e.g. `hnsans` but not `"Apple Color Emoji"`.
+    fn is_css_identifier(name: &str) -> bool {
+        const CSS_WIDE_KEYWORDS: [&str; 6] = [
+            "inherit",
+            "initial",
+            "unset",
+            "default",
+            "revert",
+            "revert-layer",
+        ];
+        let rest = name.strip_prefix('-').unwrap_or(name);
+        !rest.is_empty()
+            && !rest.starts_with(|c: char| c.is_ascii_digit())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            && !CSS_WIDE_KEYWORDS.contains(&name.to_ascii_lowercase().as_str())
+    }
+
+    /// e.g. `"Font \"Quoted\""`, see https://www.w3.org/TR/css-syntax-3/#consume-string-token
+    pub fn css_string(value: &str) -> String {
+        let mut css = String::from('"');
+        for c in value.chars() {
+            match c {
+                '"' | '\\' => {
+                    css.push('\\');
+                    css.push(c);
+                }
+                // newlines and other control characters can only appear as hex escapes,
+                // which end with a space so the next character is not read as a hex digit
+                c if c.is_control() => css.push_str(&format!("\\{:x} ", c as u32)),
+                c => css.push(c),
+            }
+        }
+        css.push('"');
+        css
+    }
+
+    /// A locally installed fallback font adjusted to take up the same space as the
+    /// primary font while it loads. See https://github.com/seek-oss/capsize#createfontstack
+    pub struct CSSFallbackFontFace {
+        /// e.g. `"hnsans Fallback: Arial"`
+        pub family_name: String,
+        /// e.g. `"Arial"`
+        pub local_name: String,
+        pub size_adjust: f64,
+        pub ascent_override: f64,
+        pub descent_override: f64,
+        pub line_gap_override: f64,
+    }
+
+    impl CSSFallbackFontFace {
+        pub fn from_metrics(
+            css_family_name: &str,
+            metrics: &FontFamilyMetrics,
+            fallback: &FontFamilyMetrics,
+        ) -> Self {
+            // Fallbacks are scaled so the average character widths match
+            let size_adjust = (metrics.xWidthAvg / metrics.unitsPerEm)
+                / (fallback.xWidthAvg / fallback.unitsPerEm);
+            let adjusted_em_square = metrics.unitsPerEm * size_adjust;
+            CSSFallbackFontFace {
+                family_name: format!("{css_family_name} Fallback: {}", fallback.familyName),
+                local_name: fallback.familyName.clone(),
+                size_adjust,
+                ascent_override: metrics.ascent / adjusted_em_square,
+                descent_override: metrics.descent.abs() / adjusted_em_square,
+                line_gap_override: metrics.lineGap / adjusted_em_square,
+            }
+        }
+
+        pub fn to_css_string(&self) -> String {
+            let percent = |value: f64| format!("{}%", format_number(value * 100.0));
+            let mut css = String::from("@font-face {\n");
+            css.push_str(&format!(
+                "  font-family: {};\n",
+                css_string(&self.family_name)
+            ));
+            css.push_str(&format!(
+                "  src: local({});\n",
+                css_string(&self.local_name)
+            ));
+            css.push_str(&format!("  size-adjust: {};\n", percent(self.size_adjust)));
+            css.push_str(&format!(
+                "  ascent-override: {};\n",
+                percent(self.ascent_override)
+            ));
+            css.push_str(&format!(
+                "  descent-override: {};\n",
+                percent(self.descent_override)
+            ));
+            css.push_str(&format!(
+                "  line-gap-override: {};\n",
+                percent(self.line_gap_override)
+            ));
+            css.push_str("}\n");
+            css
+        }
+    }
+
+    /// One font face for each fallback with known metrics, in the order of `CSSFontFamilyFallbacks`.
+    pub fn fallback_font_faces(family: &FontFamilyInfo) -> Vec<CSSFallbackFontFace> {
+        let css_family_name = family
+            .CSSFontFamilyName
+            .as_deref()
+            .unwrap_or(&family.BaseName);
+        family
+            .CSSFontFamilyFallbacks
+            .iter()
+            .filter_map(|fallback_name| {
+                let configured = family
+                    .CSSFallbackMetricsOption
+                    .iter()
+                    .flatten()
+                    .find(|metrics| &metrics.familyName == fallback_name);
+                match configured {
+                    Some(metrics) => Some(CSSFallbackFontFace::from_metrics(
+                        css_family_name,
+                        &family.Metrics,
+                        metrics,
+                    )),
+                    None => known_fallback_metrics(fallback_name).map(|metrics| {
+                        CSSFallbackFontFace::from_metrics(
+                            css_family_name,
+                            &family.Metrics,
+                            &metrics,
+                        )
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    /// Fallbacks which do not get an adjusted font face. Generic families like `system-ui` can't
+    /// be loaded with `local()`, so those only warn when no other fallback was adjusted.
+    pub fn fallback_warnings(family: &FontFamilyInfo) -> Vec<String> {
+        const GENERIC_FAMILIES: [&str; 13] = [
+            "serif",
+            "sans-serif",
+            "monospace",
+            "cursive",
+            "fantasy",
+            "system-ui",
+            "ui-serif",
+            "ui-sans-serif",
+            "ui-monospace",
+            "ui-rounded",
+            "math",
+            "emoji",
+            "fangsong",
+        ];
+        let has_metrics = |fallback_name: &String| {
+            family
+                .CSSFallbackMetricsOption
+                .iter()
+                .flatten()
+                .any(|metrics| &metrics.familyName == fallback_name)
+                || known_fallback_metrics(fallback_name).is_some()
+        };
+        let (generic, unknown): (Vec<_>, Vec<_>) = family
+            .CSSFontFamilyFallbacks
+            .iter()
+            .filter(|fallback_name| !has_metrics(fallback_name))
+            .partition(|fallback_name| GENERIC_FAMILIES.contains(&fallback_name.as_str()));
+
+        let mut warnings = unknown
+            .into_iter()
+            .map(|fallback_name| {
+                format!(
+                    "no metrics for fallback ({fallback_name:?}) of family ({:?}), add them to `CSSFallbackMetricsOption` to adjust it",
+                    family.BaseName
+                )
+            })
+            .collect::<Vec<_>>();
+        if !generic.is_empty() && fallback_font_faces(family).is_empty() {
+            warnings.push(format!(
+                "generic fallbacks {generic:?} of family ({:?}) can't be adjusted, list a font with known metrics like \"Arial\" before them",
+                family.BaseName
+            ));
+        }
+        warnings
+    }
+
+    /// From @capsizecss/metrics
+    fn known_fallback_metrics(family_name: &str) -> Option<FontFamilyMetrics> {
+        Some(match family_name {
+            "Arial" => FontFamilyMetrics {
+                familyName: family_name.to_string(),
+                category: "sans-serif".to_string(),
+                capHeight: 1467.0,
+                ascent: 1854.0,
+                descent: -434.0,
+                lineGap: 67.0,
+                unitsPerEm: 2048.0,
+                xHeight: 1062.0,
+                xWidthAvg: 904.0,
+            },
+            "Times New Roman" => FontFamilyMetrics {
+                familyName: family_name.to_string(),
+                category: "serif".to_string(),
+                capHeight: 1356.0,
+                ascent: 1825.0,
+                descent: -443.0,
+                lineGap: 87.0,
+                unitsPerEm: 2048.0,
+                xHeight: 916.0,
+                xWidthAvg: 819.0,
+            },
+            "Courier New" => FontFamilyMetrics {
+                familyName: family_name.to_string(),
+                category: "monospace".to_string(),
+                capHeight: 1170.0,
+                ascent: 1705.0,
+                descent: -615.0,
+                lineGap: 0.0,
+                unitsPerEm: 2048.0,
+                xHeight: 866.0,
+                xWidthAvg: 1229.0,
+            },
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::css_export::*;
+    use crate::typography::input::FontFamilyInfo;
+
+    fn family(css_name: &str, fallbacks: &[&str]) -> FontFamilyInfo {
+        serde_json::from_value(serde_json::json!({
+            "BaseName": "Inter",
+            "CSSFontFamilyName": css_name,
+            "CSSFontFamilyFallbacks": fallbacks,
+            "DefaultRules": [],
+            "Weights": [],
+            "Metrics": {
+                "familyName": "Inter", "category": "sans-serif", "capHeight": 1490.0,
+                "ascent": 1984.0, "descent": -494.0, "lineGap": 0.0, "unitsPerEm": 2048.0,
+                "xHeight": 1118.0, "xWidthAvg": 1083.0
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn css_strings() {
+        assert_eq!(css_string("Segoe UI"), r#""Segoe UI""#);
+        assert_eq!(css_string(r#"My "Font""#), r#""My \"Font\"""#);
+        assert_eq!(css_string(r"C:\fonts"), r#""C:\\fonts""#);
+        assert_eq!(css_string("Line\nBreak"), "\"Line\\a Break\"");
+        assert_eq!(css_string("Noto Sans 日本語"), "\"Noto Sans 日本語\"");
+    }
+
+    #[test]
+    fn font_family_quoting() {
+        let families = [family(
+            "hnsans",
+            &[
+                "system-ui",
+                "Segoe UI",
+                "Hiragino Kaku Gothic ProN",
+                "ヒラギノ角ゴ",
+                "3D",
+                "inherit",
+                "sans-serif",
+            ],
+        )];
+        assert_eq!(
+            css_font_family("Inter", &families).unwrap(),
+            r#"hnsans, system-ui, "Segoe UI", "Hiragino Kaku Gothic ProN", "ヒラギノ角ゴ", "3D", "inherit", sans-serif"#
+        );
+    }
+
+    /// Widths are matched by scaling the fallback, and the vertical metrics are relative to that
+    #[test]
+    fn fallback_font_face_overrides() {
+        let mut family = family("hnsans", &["Arial"]);
+        family.Metrics.unitsPerEm = 2000.0;
+        family.Metrics.ascent = 1800.0;
+        family.Metrics.descent = -400.0;
+        family.Metrics.lineGap = 100.0;
+        family.Metrics.xWidthAvg = 1000.0;
+
+        let font_faces = fallback_font_faces(&family);
+        assert_eq!(font_faces.len(), 1);
+        // (1000 / 2000) / (904 / 2048) for Arial
+        let size_adjust = 0.5 / (904.0 / 2048.0);
+        assert!((font_faces[0].size_adjust - size_adjust).abs() < 1e-9);
+        assert!((font_faces[0].ascent_override - 1800.0 / (2000.0 * size_adjust)).abs() < 1e-9);
+        assert_eq!(
+            font_faces[0].to_css_string(),
+            r#"@font-face {
+  font-family: "hnsans Fallback: Arial";
+  src: local("Arial");
+  size-adjust: 113.2743%;
+  ascent-override: 79.4531%;
+  descent-override: 17.6562%;
+  line-gap-override: 4.4141%;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn fallback_warnings_without_metrics() {
+        let adjusted = family("hnsans", &["system-ui", "Segoe UI", "Arial", "sans-serif"]);
+        assert_eq!(
+            fallback_warnings(&adjusted),
+            ["no metrics for fallback (\"Segoe UI\") of family (\"Inter\"), add them to `CSSFallbackMetricsOption` to adjust it"]
+        );
+
+        let generic_only = family("hnsans", &["system-ui", "sans-serif"]);
+        assert_eq!(
+            fallback_warnings(&generic_only),
+            ["generic fallbacks [\"system-ui\", \"sans-serif\"] of family (\"Inter\") can't be adjusted, list a font with known metrics like \"Arial\" before them"]
+        );
+    }
+}
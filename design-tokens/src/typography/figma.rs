@@ -42,9 +42,13 @@ pub mod figma_export {
     use std::collections::BTreeMap;
 
     use crate::{
+        color::output::ColorExport,
         prelude::*,
-        tokens::{split_tokens, Token, TokenSet},
-        typography::output::{TokenLookup, TokenQueryOutput, TypographyProperty},
+        tokens::{aliases::TokenAliases, split_tokens, Token, TokenSet},
+        typography::{
+            output::{TokenLookup, TokenQueryOutput, TypographyProperty},
+            units::format_number,
+        },
     };
     use derive_codegen::Codegen;
 
@@ -59,7 +63,16 @@ pub mod figma_export {
     #[derive(Codegen, Serialize)]
     #[codegen(tags = "figma-typography-export")]
     pub enum FigmaPluginCommandOperation {
-        UpdateTypography { text_styles: Vec<TextStyle> },
+        UpdateTypography {
+            text_styles: Vec<TextStyle>,
+        },
+        UpdateVariables {
+            collections: Vec<VariableCollection>,
+        },
+        /// Reviewed in the plugin before it's applied, see [plan_typography_for_figma]
+        ApplyTypographyPlan {
+            plan: TypographySyncPlan,
+        },
     }
 
     #[derive(Debug, Codegen, Serialize)]
@@ -76,6 +89,39 @@ pub mod figma_export {
         pub letter_spacing_px: Option<f64>,
         #[serde(skip_serializing_if = "Vec::is_empty")]
         pub variant_values: Vec<(String, String)>,
+        /// Set when [figma_config::FigmaTypographyConfig::VariablesOption] is configured.
+        /// The px values above are kept as a fallback for variables that are missing in Figma.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub bound_variables: Option<TextStyleVariables>,
+    }
+
+    /// Keys of the [Variable]s from [update_variables_for_figma] that a text style is bound to,
+    /// so changing a mode in Figma updates the text style.
+    #[derive(Debug, Codegen, Serialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub struct TextStyleVariables {
+        pub font_family: String,
+        pub font_style: String,
+        pub font_size: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub line_height: Option<String>,
+        pub letter_spacing: String,
+    }
+
+    impl TextStyleVariables {
+        /// Each bound field, like [ExistingTextStyle::bound_variables]
+        fn by_field(&self) -> BTreeMap<String, String> {
+            [
+                ("font_family", Some(&self.font_family)),
+                ("font_style", Some(&self.font_style)),
+                ("font_size", Some(&self.font_size)),
+                ("line_height", self.line_height.as_ref()),
+                ("letter_spacing", Some(&self.letter_spacing)),
+            ]
+            .into_iter()
+            .filter_map(|(field, key)| Some((field.to_string(), key?.clone())))
+            .collect()
+        }
     }
 
     impl TextStyle {
@@ -86,7 +132,13 @@ pub mod figma_export {
             key: String,
             lookup_output: TokenQueryOutput<'a>,
         ) -> Result<Self> {
-            // collected with precedents
+            for conflict in &lookup_output.conflicts {
+                eprintln!(
+                    "warning: text style ({name:?}) depends on declaration order: {conflict}"
+                );
+            }
+
+            // properties are ordered by precedence, so later ones override earlier ones
             let mut family_name_found = Option::<Cow<'a, str>>::None;
             let mut family_style_prec: BTreeMap<isize, String> = BTreeMap::new();
             let mut line_height_px = Option::<f64>::None;
@@ -101,7 +153,9 @@ pub mod figma_export {
                     }
                     TypographyProperty::LineHeight { px } => line_height_px = Some(*px),
                     TypographyProperty::FontSize { px } => font_size_px = *px,
-                    TypographyProperty::LetterSpacing { px } => letter_spacing_px = Some(*px),
+                    TypographyProperty::LetterSpacing { px, .. } => letter_spacing_px = Some(*px),
+                    // Figma text styles cannot offset their baseline
+                    TypographyProperty::BaselineShift { .. } => {}
                     TypographyProperty::FontStyle(style_scalar) => {
                         let figma_font_style_rule =
                             serde_json::from_value::<figma_scalars::FontStyleRule>(
@@ -144,6 +198,122 @@ pub mod figma_export {
                 line_height_px,
                 letter_spacing_px,
                 variant_values,
+                bound_variables: None,
+            })
+        }
+    }
+
+    /// A text style as it is in the Figma file, exported by the plugin.
+    #[derive(Debug, Clone, Codegen, Serialize, Deserialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub struct ExistingTextStyle {
+        /// Figma's style id
+        pub id: String,
+        pub name: String,
+        /// The key from the description, if the text style was generated
+        pub key: Option<String>,
+        pub family_name_and_style: (String, String),
+        pub font_size_px: f64,
+        /// `None` for an auto line height
+        pub line_height_px: Option<f64>,
+        pub letter_spacing_px: Option<f64>,
+        /// Keys of the variables bound to each field, named like the fields of
+        /// [TextStyleVariables], e.g. `{ "font_size": "key-ui:true size:sm/font-size" }`
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub bound_variables: BTreeMap<String, String>,
+    }
+
+    /// What applying the text styles would change in the Figma file.
+    #[derive(Debug, Codegen, Serialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub struct TypographySyncPlan {
+        /// Text styles with a key that isn't in Figma yet
+        pub created: Vec<TextStyle>,
+        /// Existing text styles with different values, which may also be renamed
+        pub updated: Vec<TextStyleChange>,
+        /// Existing text styles with only a different name
+        pub renamed: Vec<TextStyleChange>,
+        /// Generated text styles in Figma with a key that is no longer generated.
+        /// The plugin renames them with the `"⚠️ Unlinked / "` prefix rather than deleting them,
+        /// since they may still be in use, so text styles already marked are left out.
+        pub orphaned: Vec<ExistingTextStyle>,
+        pub unchanged_count: usize,
+    }
+
+    #[derive(Debug, Codegen, Serialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub struct TextStyleChange {
+        /// Figma's style id of the existing text style
+        pub id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub renamed_from: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub fields: Vec<FieldChange>,
+        pub text_style: TextStyle,
+    }
+
+    /// e.g. `{ field: "font_size_px", from: "12", to: "12.38" }`
+    #[derive(Debug, Codegen, Serialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub struct FieldChange {
+        pub field: String,
+        pub from: String,
+        pub to: String,
+    }
+
+    /// A Figma variable collection, created or updated in place by its key.
+    #[derive(Debug, Codegen, Serialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub struct VariableCollection {
+        /// Used to figure out which Figma variable collection to update, e.g. `"design-tokens/color"`
+        pub key: String,
+        pub name: String,
+        /// e.g. `["Light", "Dark"]`, the first is the collection's default mode
+        pub modes: Vec<String>,
+        pub variables: Vec<Variable>,
+    }
+
+    #[derive(Debug, Codegen, Serialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub struct Variable {
+        /// Used to figure out which Figma variables to replace,
+        /// e.g. `"color:primary tone:40"` or `"key-ui:true size:sm/font-size"`
+        pub key: String,
+        /// Slashes create groups in Figma, e.g. `"palette/primary/40"`
+        pub name: String,
+        /// One value for each of the collection's modes, in the same order.
+        /// All values are the same kind, which decides the variable's type
+        /// (`COLOR`, `FLOAT`, or `STRING`).
+        pub values: Vec<VariableValue>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Codegen, Serialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub enum VariableValue {
+        Color(FigmaColor),
+        Float(f64),
+        String(String),
+    }
+
+    /// Each channel from `0` to `1`, like Figma's `RGBA`
+    #[derive(Debug, Clone, PartialEq, Codegen, Serialize)]
+    #[codegen(tags = "figma-typography-export")]
+    pub struct FigmaColor {
+        pub r: f64,
+        pub g: f64,
+        pub b: f64,
+        pub a: f64,
+    }
+
+    impl FigmaColor {
+        fn from_hex(hex: &str) -> Result<Self> {
+            let [r, g, b] = crate::platforms::hex_rgb(hex)?;
+            let channel = |value: u8| value as f64 / 255.0;
+            Ok(FigmaColor {
+                r: channel(r),
+                g: channel(g),
+                b: channel(b),
+                a: 1.0,
             })
         }
     }
@@ -155,125 +325,495 @@ pub mod figma_export {
         tokens: TokenSet,
     }
 
+    impl TokenSelection {
+        fn key_string(&self) -> String {
+            self.key
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+    }
+
     pub fn update_typography_for_figma(
         all_tokens: &crate::typography::output::TypographyExport,
         extension_input: &figma_config::TypographyExtensionInput,
+        aliases: &TokenAliases,
+    ) -> Result<FigmaPluginCommand> {
+        Ok(FigmaPluginCommand {
+            figma_plugin: FigmaPluginCommandOperation::UpdateTypography {
+                text_styles: figma_text_styles(all_tokens, extension_input, aliases)?,
+            },
+        })
+    }
+
+    /// Like [update_typography_for_figma], but compares against the text styles exported
+    /// from the Figma file, so the plugin only touches the text styles that changed.
+    pub fn plan_typography_for_figma(
+        all_tokens: &crate::typography::output::TypographyExport,
+        extension_input: &figma_config::TypographyExtensionInput,
+        aliases: &TokenAliases,
+        existing: &[ExistingTextStyle],
     ) -> Result<FigmaPluginCommand> {
-        let lookup = all_tokens.as_lookup();
+        let text_styles = figma_text_styles(all_tokens, extension_input, aliases)?;
+        Ok(FigmaPluginCommand {
+            figma_plugin: FigmaPluginCommandOperation::ApplyTypographyPlan {
+                plan: plan_typography_sync(text_styles, existing),
+            },
+        })
+    }
+
+    fn figma_text_styles(
+        all_tokens: &crate::typography::output::TypographyExport,
+        extension_input: &figma_config::TypographyExtensionInput,
+        aliases: &TokenAliases,
+    ) -> Result<Vec<TextStyle>> {
+        let lookup = all_tokens.as_lookup().with_aliases(aliases);
         let mut figma_text_styles = Vec::<TextStyle>::new();
 
         for text_style in &extension_input.Figma.FigmaTextStyles {
-            let mut collected = {
-                let mut init = Vec::<TokenSelection>::new();
-                let mut starter_tokens =
-                    split_tokens(&text_style.BaseTokens).with_context(|| {
-                        format!(
-                            "while reading your Figma text style ({:?})",
-                            text_style.BaseName
-                        )
-                    })?;
-                starter_tokens.insert(0, Token::Kind("text".into()));
-                init.push(TokenSelection {
-                    name: text_style.BaseName.clone(),
-                    tokens: TokenSet::from(starter_tokens),
-                    key: TokenSet::from([key_token(&text_style.BaseKey)]),
-                });
-                init
-            };
+            let collected = text_style_selections(text_style)?;
 
-            for group in &text_style.Groups {
-                let original_text_styles = collected.clone();
-                let group_prefix = group
-                    .NamePrefix
-                    .as_ref()
-                    .map(String::as_str)
-                    .unwrap_or(" / ");
-                let group_suffix = group.NameSuffix.as_ref().map(String::as_str).unwrap_or("");
-
-                collected.clear();
-                for original in &original_text_styles {
-                    if group.IncludeEmptyOption.unwrap_or(false) {
-                        let mut empty_option = original.clone();
-                        let needs_empty_name = if let Some(ref prefix) = group.NamePrefix {
-                            // only if the prefix attempts to establish another folder
-                            // otehrwise this name will conflict with a folder.
-                            prefix.trim_end().ends_with("/")
-                        } else {
-                            true
-                        };
-
-                        if needs_empty_name {
-                            empty_option.name += group
-                                .NamePrefix
-                                .as_ref()
-                                .map(|a| a.as_str())
-                                .unwrap_or(" / ");
-                            empty_option.name += "<base>";
-                            if let Some(ref suffix) = group.NameSuffix {
-                                empty_option.name += suffix;
-                            }
-                        }
+            for selection in &collected {
+                let TokenSelection { name, key, tokens } = selection;
+                let lookup_output = lookup.query_with_set(&tokens);
 
-                        collected.push(empty_option);
-                    }
-                    for option in &group.Options {
-                        let mut new_name = format!(
-                            "{}{group_prefix}{}{group_suffix}",
-                            original.name, option.Name
-                        );
-                        let option_tokens = split_tokens(&option.Tokens).with_context(|| {
+                let key_str = selection.key_string();
+
+                let mut text_style =
+                    TextStyle::try_from_lookup(name.clone(), key_str, lookup_output).with_context(
+                        || {
                             format!(
-                                "while reading a group option ({:?}) for your Figma text style ({:?})",
-                                option.Name,
-                                text_style.BaseName
+                                "failed to create text style {name:?} ({key:?}) with query ({tokens:?})\n\n{all_tokens:#?}"
                             )
-                        })?;
-                        let mut new_key = original.key.clone();
-                        if let Some(key) = &option.Key {
-                            new_key
-                                .insert(Token::Value(format!("key-{key}").into(), "true".into()));
-                        } else {
-                            new_key.append(option_tokens.clone());
-                        }
-                        let mut new_tokens = original.tokens.clone();
-                        new_tokens.append(option_tokens);
-                        collected.push(TokenSelection {
-                            name: new_name,
-                            key: new_key,
-                            tokens: new_tokens,
-                        });
-                    }
+                        },
+                    )?;
+                if extension_input.Figma.VariablesOption.is_some() {
+                    let key = selection.key_string();
+                    text_style.bound_variables = Some(TextStyleVariables {
+                        font_family: variable_key(&key, "font-family"),
+                        font_style: variable_key(&key, "font-style"),
+                        font_size: variable_key(&key, "font-size"),
+                        line_height: text_style
+                            .line_height_px
+                            .map(|_| variable_key(&key, "line-height")),
+                        letter_spacing: variable_key(&key, "letter-spacing"),
+                    });
                 }
+                figma_text_styles.push(text_style);
             }
+        }
 
-            for TokenSelection { name, key, tokens } in &collected {
-                let lookup_output = lookup.query_with_set(&tokens);
+        Ok(figma_text_styles)
+    }
+
+    /// Prefix of the text styles the plugin no longer links to a generated text style
+    const UNLINKED_PREFIX: &str = "⚠️ Unlinked";
 
-                let key_str = key
+    /// Matches each text style to an existing one by key (or by name, for existing text
+    /// styles without a key), and lists what would change.
+    pub fn plan_typography_sync(
+        text_styles: Vec<TextStyle>,
+        existing: &[ExistingTextStyle],
+    ) -> TypographySyncPlan {
+        let mut by_key = BTreeMap::<&str, &ExistingTextStyle>::new();
+        let mut unkeyed_by_name = BTreeMap::<&str, &ExistingTextStyle>::new();
+        let mut orphaned = Vec::<ExistingTextStyle>::new();
+        for style in existing {
+            match &style.key {
+                Some(key) if by_key.contains_key(key.as_str()) => orphaned.push(style.clone()),
+                Some(key) => {
+                    by_key.insert(key, style);
+                }
+                None => {
+                    unkeyed_by_name.entry(&style.name).or_insert(style);
+                }
+            }
+        }
+
+        let mut plan = TypographySyncPlan {
+            created: Vec::new(),
+            updated: Vec::new(),
+            renamed: Vec::new(),
+            orphaned: Vec::new(),
+            unchanged_count: 0,
+        };
+        for text_style in text_styles {
+            let found = match by_key.remove(text_style.key.as_str()) {
+                Some(found) => found,
+                None => match unkeyed_by_name.remove(text_style.name.as_str()) {
+                    Some(found) => found,
+                    None => {
+                        plan.created.push(text_style);
+                        continue;
+                    }
+                },
+            };
+
+            let fields = field_changes(found, &text_style);
+            let change = TextStyleChange {
+                id: found.id.clone(),
+                renamed_from: (found.name != text_style.name).then(|| found.name.clone()),
+                fields,
+                text_style,
+            };
+            match (change.fields.is_empty(), change.renamed_from.is_some()) {
+                (false, _) => plan.updated.push(change),
+                (true, true) => plan.renamed.push(change),
+                (true, false) => plan.unchanged_count += 1,
+            }
+        }
+        plan.orphaned.extend(by_key.into_values().cloned());
+        plan.orphaned.extend(orphaned);
+        plan.orphaned
+            .retain(|style| !style.name.starts_with(UNLINKED_PREFIX));
+
+        plan
+    }
+
+    fn field_changes(existing: &ExistingTextStyle, text_style: &TextStyle) -> Vec<FieldChange> {
+        let mut fields = Vec::<FieldChange>::new();
+        let mut compare = |field: &str, from: String, to: String| {
+            if from != to {
+                fields.push(FieldChange {
+                    field: field.to_string(),
+                    from,
+                    to,
+                });
+            }
+        };
+        compare(
+            "key",
+            existing.key.clone().unwrap_or_default(),
+            text_style.key.clone(),
+        );
+        compare(
+            "font_family",
+            existing.family_name_and_style.0.clone(),
+            text_style.family_name_and_style.0.clone(),
+        );
+        compare(
+            "font_style",
+            existing.family_name_and_style.1.clone(),
+            text_style.family_name_and_style.1.clone(),
+        );
+        // Figma keeps 32 bit floats, so compare the values as they're displayed
+        compare(
+            "font_size_px",
+            px(Some(existing.font_size_px)),
+            px(Some(text_style.font_size_px)),
+        );
+        compare(
+            "line_height_px",
+            px(existing.line_height_px),
+            px(text_style.line_height_px),
+        );
+        compare(
+            "letter_spacing_px",
+            px(Some(existing.letter_spacing_px.unwrap_or(0.0))),
+            px(Some(text_style.letter_spacing_px.unwrap_or(0.0))),
+        );
+        compare(
+            "bound_variables",
+            bound_variables(&existing.bound_variables),
+            bound_variables(
+                &text_style
+                    .bound_variables
+                    .as_ref()
+                    .map(TextStyleVariables::by_field)
+                    .unwrap_or_default(),
+            ),
+        );
+        fields
+    }
+
+    /// e.g. `"font_size=key-ui:true size:sm/font-size"`, or `"none"` when nothing is bound
+    fn bound_variables(by_field: &BTreeMap<String, String>) -> String {
+        if by_field.is_empty() {
+            return "none".to_string();
+        }
+        by_field
+            .iter()
+            .map(|(field, key)| format!("{field}={key}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// e.g. `"12.38"`, or `"auto"` for no value
+    fn px(value: Option<f64>) -> String {
+        match value {
+            Some(value) => format_number((value * 100.0).round() / 100.0),
+            None => "auto".to_string(),
+        }
+    }
+
+    /// Variable collections for the colors (with `Light` and `Dark` modes) and, when
+    /// [figma_config::FigmaTypographyConfig::VariablesOption] is set, for the numbers
+    /// and fonts of each Figma text style (with a mode for each of
+    /// [figma_config::FigmaVariablesConfig::TypographyModesOption], like densities).
+    ///
+    /// Collections and variables are matched by key in the plugin, so renaming a text style
+    /// in your settings renames its variables rather than creating new ones.
+    pub fn update_variables_for_figma(
+        all_tokens: &crate::typography::output::TypographyExport,
+        colors: Option<&ColorExport>,
+        extension_input: &figma_config::TypographyExtensionInput,
+        aliases: &TokenAliases,
+    ) -> Result<FigmaPluginCommand> {
+        let mut collections = Vec::<VariableCollection>::new();
+
+        if let Some(colors) = colors {
+            let mut variables = Vec::<Variable>::new();
+            for role in colors.scheme_roles() {
+                variables.push(Variable {
+                    key: format!("color:{}", role.role),
+                    name: format!("scheme/{}", role.role),
+                    values: vec![
+                        VariableValue::Color(FigmaColor::from_hex(role.light_hex)?),
+                        VariableValue::Color(FigmaColor::from_hex(role.dark_hex)?),
+                    ],
+                });
+            }
+            // Tones and exact colors are the same in both modes
+            for color in &colors.colors {
+                if color.tokens.get("scheme").is_some() {
+                    continue;
+                }
+                let value = VariableValue::Color(FigmaColor::from_hex(&color.hex)?);
+                variables.push(Variable {
+                    key: color
+                        .tokens
+                        .iter()
+                        .map(|token| token.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    name: std::iter::once("palette".to_string())
+                        .chain(color.tokens.path())
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                    values: vec![value.clone(), value],
+                });
+            }
+            collections.push(VariableCollection {
+                key: "design-tokens/color".to_string(),
+                name: "Color".to_string(),
+                modes: vec!["Light".to_string(), "Dark".to_string()],
+                variables,
+            });
+        }
+
+        // Like the text style bindings, typography variables need `VariablesOption`
+        if let Some(variables_config) = &extension_input.Figma.VariablesOption {
+            let lookup = all_tokens.as_lookup().with_aliases(aliases);
+            let modes = match variables_config.TypographyModesOption.as_ref() {
+                Some(modes) if !modes.is_empty() => modes
                     .iter()
-                    .map(|v| v.to_string())
-                    .collect::<Vec<_>>()
-                    .join(" ");
+                    .map(|mode| {
+                        Ok((
+                            mode.Name.clone(),
+                            split_tokens(&mode.Tokens).with_context(|| {
+                                format!("while reading your Figma variables mode ({:?})", mode.Name)
+                            })?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                _ => vec![("Default".to_string(), Vec::new())],
+            };
 
-                figma_text_styles.push(
-                    TextStyle::try_from_lookup(name.clone(), key_str, lookup_output).with_context(
-                        || {
-                            format!(
-                                "failed to create text style {name:?} ({key:?}) with query ({tokens:?})\n\n{all_tokens:#?}"
+            let mut variables = Vec::<Variable>::new();
+            for text_style in &extension_input.Figma.FigmaTextStyles {
+                for selection in text_style_selections(text_style)? {
+                    let mut styles = Vec::<TextStyle>::new();
+                    for (mode_name, mode_tokens) in &modes {
+                        let mut tokens = selection.tokens.clone();
+                        tokens.append(mode_tokens.clone());
+                        styles.push(
+                            TextStyle::try_from_lookup(
+                                selection.name.clone(),
+                                selection.key_string(),
+                                lookup.query_with_set(&tokens),
                             )
-                        },
-                    )?,
-                );
+                            .with_context(|| {
+                                format!(
+                                    "failed to create variables for text style {:?} in mode {mode_name:?} with query ({tokens:?})",
+                                    selection.name
+                                )
+                            })?,
+                        );
+                    }
+                    variables.extend(text_style_variables(&selection, &styles)?);
+                }
             }
+            collections.push(VariableCollection {
+                key: "design-tokens/typography".to_string(),
+                name: "Typography".to_string(),
+                modes: modes.into_iter().map(|(name, _)| name).collect(),
+                variables,
+            });
         }
 
         Ok(FigmaPluginCommand {
-            figma_plugin: FigmaPluginCommandOperation::UpdateTypography {
-                text_styles: figma_text_styles,
-            },
+            figma_plugin: FigmaPluginCommandOperation::UpdateVariables { collections },
         })
     }
 
+    /// e.g. `"UI / Small Bold"` has `"UI/Small Bold/font-size"` with the key `"key-ui:true size:sm weight:700/font-size"`
+    fn text_style_variables(
+        selection: &TokenSelection,
+        styles_by_mode: &[TextStyle],
+    ) -> Result<Vec<Variable>> {
+        let group = selection
+            .name
+            .split('/')
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("/");
+        let key = selection.key_string();
+        let mut variables = Vec::<Variable>::new();
+        let mut push = |property: &str, values: Vec<VariableValue>| {
+            variables.push(Variable {
+                key: variable_key(&key, property),
+                name: format!("{group}/{property}"),
+                values,
+            })
+        };
+
+        let by_mode = |value: fn(&TextStyle) -> VariableValue| -> Vec<VariableValue> {
+            styles_by_mode.iter().map(value).collect()
+        };
+        push(
+            "font-family",
+            by_mode(|style| VariableValue::String(style.family_name_and_style.0.clone())),
+        );
+        push(
+            "font-style",
+            by_mode(|style| VariableValue::String(style.family_name_and_style.1.clone())),
+        );
+        push(
+            "font-size",
+            by_mode(|style| VariableValue::Float(style.font_size_px)),
+        );
+        // Figma text styles can have an auto line height, but a variable needs a value in every mode
+        match styles_by_mode
+            .iter()
+            .map(|style| style.line_height_px)
+            .collect::<Option<Vec<f64>>>()
+        {
+            Some(line_heights) => push(
+                "line-height",
+                line_heights.into_iter().map(VariableValue::Float).collect(),
+            ),
+            None if styles_by_mode
+                .iter()
+                .any(|style| style.line_height_px.is_some()) =>
+            {
+                anyhow::bail!(
+                    "text style {:?} has a line height in some modes but not others",
+                    selection.name
+                )
+            }
+            None => {}
+        }
+        push(
+            "letter-spacing",
+            by_mode(|style| VariableValue::Float(style.letter_spacing_px.unwrap_or(0.0))),
+        );
+
+        Ok(variables)
+    }
+
+    /// e.g. `"key-ui:true size:sm/font-size"`, shared by the variable and the text styles bound to it
+    fn variable_key(text_style_key: &str, property: &str) -> String {
+        format!("{text_style_key}/{property}")
+    }
+
+    /// Expands the groups of a Figma text style into every named combination of its options
+    fn text_style_selections(
+        text_style: &figma_config::FigmaTextStyle,
+    ) -> Result<Vec<TokenSelection>> {
+        let mut collected = {
+            let mut init = Vec::<TokenSelection>::new();
+            let mut starter_tokens = split_tokens(&text_style.BaseTokens).with_context(|| {
+                format!(
+                    "while reading your Figma text style ({:?})",
+                    text_style.BaseName
+                )
+            })?;
+            starter_tokens.insert(0, Token::Kind("text".into()));
+            init.push(TokenSelection {
+                name: text_style.BaseName.clone(),
+                tokens: TokenSet::from(starter_tokens),
+                key: TokenSet::from([key_token(&text_style.BaseKey)]),
+            });
+            init
+        };
+
+        for group in &text_style.Groups {
+            let original_text_styles = collected.clone();
+            let group_prefix = group
+                .NamePrefix
+                .as_ref()
+                .map(String::as_str)
+                .unwrap_or(" / ");
+            let group_suffix = group.NameSuffix.as_ref().map(String::as_str).unwrap_or("");
+
+            collected.clear();
+            for original in &original_text_styles {
+                if group.IncludeEmptyOption.unwrap_or(false) {
+                    let mut empty_option = original.clone();
+                    let needs_empty_name = if let Some(ref prefix) = group.NamePrefix {
+                        // only if the prefix attempts to establish another folder
+                        // otehrwise this name will conflict with a folder.
+                        prefix.trim_end().ends_with("/")
+                    } else {
+                        true
+                    };
+
+                    if needs_empty_name {
+                        empty_option.name += group
+                            .NamePrefix
+                            .as_ref()
+                            .map(|a| a.as_str())
+                            .unwrap_or(" / ");
+                        empty_option.name += "<base>";
+                        if let Some(ref suffix) = group.NameSuffix {
+                            empty_option.name += suffix;
+                        }
+                    }
+
+                    collected.push(empty_option);
+                }
+                for option in &group.Options {
+                    let mut new_name = format!(
+                        "{}{group_prefix}{}{group_suffix}",
+                        original.name, option.Name
+                    );
+                    let option_tokens = split_tokens(&option.Tokens).with_context(|| {
+                        format!(
+                            "while reading a group option ({:?}) for your Figma text style ({:?})",
+                            option.Name, text_style.BaseName
+                        )
+                    })?;
+                    let mut new_key = original.key.clone();
+                    if let Some(key) = &option.Key {
+                        new_key.insert(Token::Value(format!("key-{key}").into(), "true".into()));
+                    } else {
+                        new_key.append(option_tokens.clone());
+                    }
+                    let mut new_tokens = original.tokens.clone();
+                    new_tokens.append(option_tokens);
+                    collected.push(TokenSelection {
+                        name: new_name,
+                        key: new_key,
+                        tokens: new_tokens,
+                    });
+                }
+            }
+        }
+
+        Ok(collected)
+    }
+
     fn key_token(key: &str) -> Token {
         Token::Value(format!("key-{key}").into(), Cow::Borrowed("true"))
     }
@@ -300,6 +840,28 @@ pub mod figma_config {
         // e.g. we should be able to swap the font families, even if the new one has fewer weights.
         /// A sort of matrice of all possible combinations of the variants
         pub FigmaTextStyles: Vec<FigmaTextStyle>,
+        /// Figma variables for the text styles' numbers and fonts.
+        /// When set, the text styles are also bound to these variables,
+        /// so update the variables in Figma before the typography.
+        pub VariablesOption: Option<FigmaVariablesConfig>,
+    }
+
+    #[derive(Codegen, Debug, Deserialize)]
+    #[codegen(tags = "figma-typography-input")]
+    #[allow(non_snake_case)]
+    pub struct FigmaVariablesConfig {
+        /// Modes of the typography variable collection, each adding its tokens to every text style.
+        /// Defaults to a single `"Default"` mode.
+        /// e.g. `[{ Name: "Comfortable", Tokens: "density:comfortable" }, { Name: "Compact", Tokens: "density:compact" }]`
+        pub TypographyModesOption: Option<Vec<FigmaVariableMode>>,
+    }
+
+    #[derive(Codegen, Debug, Deserialize)]
+    #[codegen(tags = "figma-typography-input")]
+    #[allow(non_snake_case)]
+    pub struct FigmaVariableMode {
+        pub Name: String,
+        pub Tokens: String,
     }
 
     #[derive(Codegen, Debug, Deserialize)]
@@ -340,3 +902,203 @@ pub mod figma_config {
         pub Description: Option<String>,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::figma_export::*;
+
+    fn text_style(name: &str, key: &str, font_size_px: f64) -> TextStyle {
+        TextStyle {
+            name: name.to_string(),
+            key: key.to_string(),
+            family_name_and_style: ("Inter".to_string(), "Regular".to_string()),
+            font_size_px,
+            line_height_px: Some(20.0),
+            letter_spacing_px: None,
+            variant_values: Vec::new(),
+            bound_variables: None,
+        }
+    }
+
+    /// The text style as the plugin would export it after applying it
+    fn existing(id: &str, text_style: &TextStyle) -> ExistingTextStyle {
+        ExistingTextStyle {
+            id: id.to_string(),
+            name: text_style.name.clone(),
+            key: Some(text_style.key.clone()),
+            family_name_and_style: text_style.family_name_and_style.clone(),
+            font_size_px: text_style.font_size_px,
+            line_height_px: text_style.line_height_px,
+            letter_spacing_px: Some(0.0),
+            bound_variables: BTreeMap::new(),
+        }
+    }
+
+    fn variables(key: &str) -> TextStyleVariables {
+        TextStyleVariables {
+            font_family: format!("{key}/font-family"),
+            font_style: format!("{key}/font-style"),
+            font_size: format!("{key}/font-size"),
+            line_height: Some(format!("{key}/line-height")),
+            letter_spacing: format!("{key}/letter-spacing"),
+        }
+    }
+
+    fn field_changes(change: &TextStyleChange) -> Vec<(&str, &str, &str)> {
+        change
+            .fields
+            .iter()
+            .map(|field| (field.field.as_str(), field.from.as_str(), field.to.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn plans_creates_updates_and_renames() {
+        let unchanged = text_style("UI/Base", "text role:ui size:base", 16.0);
+        let renamed = text_style("UI/Small", "text role:ui size:sm", 14.0);
+        let updated = text_style("UI/Large", "text role:ui size:lg", 18.0);
+        let created = text_style("UI/XL", "text role:ui size:xl", 20.0);
+        // Figma keeps 32 bit floats, which round to the same displayed value
+        let mut rounded = existing("1", &unchanged);
+        rounded.font_size_px = 16.000001;
+        let existing_styles = vec![
+            rounded,
+            ExistingTextStyle {
+                name: "Small".to_string(),
+                ..existing("2", &renamed)
+            },
+            ExistingTextStyle {
+                font_size_px: 17.0,
+                line_height_px: None,
+                ..existing("3", &updated)
+            },
+        ];
+
+        let plan =
+            plan_typography_sync(vec![unchanged, renamed, updated, created], &existing_styles);
+        assert_eq!(plan.unchanged_count, 1);
+        assert_eq!(
+            plan.created
+                .iter()
+                .map(|style| style.name.as_str())
+                .collect::<Vec<_>>(),
+            ["UI/XL"]
+        );
+        assert_eq!(plan.renamed.len(), 1);
+        assert_eq!(plan.renamed[0].id, "2");
+        assert_eq!(plan.renamed[0].renamed_from.as_deref(), Some("Small"));
+        assert!(plan.renamed[0].fields.is_empty());
+        assert_eq!(plan.updated.len(), 1);
+        assert_eq!(plan.updated[0].id, "3");
+        assert_eq!(plan.updated[0].renamed_from, None);
+        assert_eq!(
+            field_changes(&plan.updated[0]),
+            [
+                ("font_size_px", "17", "18"),
+                ("line_height_px", "auto", "20")
+            ]
+        );
+        assert!(plan.orphaned.is_empty());
+    }
+
+    #[test]
+    fn links_unkeyed_styles_by_name() {
+        let style = text_style("UI/Base", "text role:ui size:base", 16.0);
+        let unkeyed = ExistingTextStyle {
+            key: None,
+            ..existing("1", &style)
+        };
+
+        let plan = plan_typography_sync(vec![style], &[unkeyed]);
+        assert_eq!(plan.updated.len(), 1);
+        assert_eq!(
+            field_changes(&plan.updated[0]),
+            [("key", "", "text role:ui size:base")]
+        );
+        assert_eq!(plan.unchanged_count, 0);
+    }
+
+    #[test]
+    fn orphans_styles_which_are_no_longer_generated() {
+        let kept = text_style("UI/Base", "text role:ui size:base", 16.0);
+        let removed = text_style("UI/Tiny", "text role:ui size:xs", 10.0);
+        let unlinked = ExistingTextStyle {
+            name: "⚠️ Unlinked / UI/Old".to_string(),
+            ..existing("3", &text_style("UI/Old", "text role:ui size:old", 8.0))
+        };
+        let existing_styles = vec![
+            existing("1", &kept),
+            existing("2", &removed),
+            unlinked,
+            // a duplicate of a generated key is orphaned, while the first one is kept
+            ExistingTextStyle {
+                name: "UI/Base copy".to_string(),
+                ..existing("4", &kept)
+            },
+            // text styles without a key were never generated, so they're left alone
+            ExistingTextStyle {
+                key: None,
+                ..existing("5", &text_style("Custom", "", 12.0))
+            },
+        ];
+
+        let plan = plan_typography_sync(vec![kept], &existing_styles);
+        assert_eq!(plan.unchanged_count, 1);
+        assert_eq!(
+            plan.orphaned
+                .iter()
+                .map(|style| style.id.as_str())
+                .collect::<Vec<_>>(),
+            ["2", "4"]
+        );
+        assert!(plan.created.is_empty() && plan.updated.is_empty() && plan.renamed.is_empty());
+    }
+
+    #[test]
+    fn binding_only_changes() {
+        let key = "text role:ui size:base";
+        let bound = TextStyle {
+            bound_variables: Some(variables(key)),
+            ..text_style("UI/Base", key, 16.0)
+        };
+        let unbound = existing("1", &bound);
+        let already_bound = ExistingTextStyle {
+            bound_variables: [
+                ("font_family", "font-family"),
+                ("font_style", "font-style"),
+                ("font_size", "font-size"),
+                ("line_height", "line-height"),
+                ("letter_spacing", "letter-spacing"),
+            ]
+            .into_iter()
+            .map(|(field, property)| (field.to_string(), format!("{key}/{property}")))
+            .collect(),
+            ..existing("1", &bound)
+        };
+
+        let plan = plan_typography_sync(vec![bound], &[unbound]);
+        assert_eq!(plan.updated.len(), 1);
+        let fields = field_changes(&plan.updated[0]);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].0, "bound_variables");
+        assert_eq!(fields[0].1, "none");
+        assert!(fields[0]
+            .2
+            .starts_with("font_family=text role:ui size:base/font-family, "));
+
+        let bound = TextStyle {
+            bound_variables: Some(variables(key)),
+            ..text_style("UI/Base", key, 16.0)
+        };
+        let plan = plan_typography_sync(vec![bound], std::slice::from_ref(&already_bound));
+        assert_eq!(plan.unchanged_count, 1);
+        assert!(plan.updated.is_empty());
+
+        // variables are unbound when they're no longer configured
+        let plan = plan_typography_sync(vec![text_style("UI/Base", key, 16.0)], &[already_bound]);
+        assert_eq!(plan.updated.len(), 1);
+        assert_eq!(field_changes(&plan.updated[0])[0].2, "none");
+    }
+}
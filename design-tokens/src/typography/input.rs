@@ -28,6 +28,9 @@ pub struct TextRole {
     pub FamilyBaseName: String,
     /// e.g. tight = `1.272` or spacious = `1.61803`
     pub LineHeightRule: FontFamilyLineHeightRule,
+    /// For example, `20.0` to keep small sizes of long-form prose readable.
+    /// Applied before aligning or snapping the line-height.
+    pub LineHeightMinPxOption: Option<f64>,
     /// Also called "letter spacing," this is the space between letters for different sizes
     pub TrackingRule: FontFamilyTrackingRule,
 }
@@ -42,6 +45,10 @@ pub struct FontFamilyInfo {
     pub CSSFontFamilyName: Option<String>,
     /// e.g. `"system-ui", "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol", "Arial", "sans-serif"`
     pub CSSFontFamilyFallbacks: Vec<String>,
+    /// Metrics for fallbacks, used to generate `@font-face` overrides which reduce layout shift.
+    /// Arial, Times New Roman and Courier New are already known.
+    /// Generic families like `system-ui` can't be adjusted, since `local()` needs a specific font.
+    pub CSSFallbackMetricsOption: Option<Vec<FontFamilyMetrics>>,
     /// Required for some systems like Figma, for it to properly select the default text style font name.
     pub DefaultRules: Vec<scalars::FontStyleRule>,
     /// Source for `weight:{value}` tokens.
@@ -72,6 +79,50 @@ pub struct FamilyWeightRule {
     pub Weight: usize,
     /// A scalar depending on the requirements of the different generators you're aiming to support
     pub FontStyleRule: scalars::FontStyleRule,
+    /// e.g. `"Inter-SemiBold"`, for platforms which load fonts by PostScript name, like SwiftUI.
+    /// Defaults to the family name without spaces and the OpenType name of the weight.
+    pub PostScriptNameOption: Option<String>,
+    /// e.g. `"Inter-SemiBoldItalic"`. Defaults like [FamilyWeightRule::PostScriptNameOption],
+    /// followed by `"Italic"` (or just `"Inter-Italic"` for `400`).
+    pub ItalicPostScriptNameOption: Option<String>,
+}
+
+impl FontFamilyInfo {
+    /// e.g. `"Inter-SemiBoldItalic"` for `600` and italic
+    pub fn post_script_name(&self, weight: usize, italic: bool) -> String {
+        let rule = self.Weights.iter().find(|rule| rule.Weight == weight);
+        let given = match italic {
+            true => rule.and_then(|rule| rule.ItalicPostScriptNameOption.clone()),
+            false => rule.and_then(|rule| rule.PostScriptNameOption.clone()),
+        };
+        given.unwrap_or_else(|| {
+            let family = self
+                .BaseName
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>();
+            match (open_type_weight_name(weight), italic) {
+                ("Regular", true) => format!("{family}-Italic"),
+                (name, true) => format!("{family}-{name}Italic"),
+                (name, false) => format!("{family}-{name}"),
+            }
+        })
+    }
+}
+
+/// e.g. `"SemiBold"` for `600`, rounding to the nearest weight class
+pub fn open_type_weight_name(weight: usize) -> &'static str {
+    match (weight + 50) / 100 {
+        0 | 1 => "Thin",
+        2 => "ExtraLight",
+        3 => "Light",
+        4 => "Regular",
+        5 => "Medium",
+        6 => "SemiBold",
+        7 => "Bold",
+        8 => "ExtraBold",
+        _ => "Black",
+    }
 }
 
 #[derive(Codegen, Debug, Deserialize)]
@@ -84,6 +135,62 @@ pub struct FontSizeScale {
     pub AlignCapHeightPxOption: Option<f64>,
     /// For example, `4.0` for aligning line-heights to 4px.
     pub AlignLineHeightPxOption: Option<f64>,
+    /// For example, `4.0` for a 4px baseline grid. Line-heights round up to a multiple of 4px,
+    /// and a `BaselineShift` moves the text down so its first baseline lands on the grid.
+    /// Uses the family's ascent and descent metrics, and cannot be combined with `AlignLineHeightPxOption`.
+    pub SnapBaselineToGridPxOption: Option<f64>,
+}
+
+impl FontSizeScale {
+    /// Applies the minimum, then either the baseline grid or the line-height alignment.
+    pub fn align_line_height_px(
+        &self,
+        line_height_px: f64,
+        min_px_opt: Option<f64>,
+    ) -> Result<f64> {
+        let target_px = match min_px_opt {
+            Some(min_px) => line_height_px.max(min_px),
+            None => line_height_px,
+        };
+
+        if let Some(grid_px) = self.SnapBaselineToGridPxOption {
+            if self.AlignLineHeightPxOption.is_some() {
+                anyhow::bail!(
+                    "SnapBaselineToGridPxOption already aligns the line-height to its grid, remove AlignLineHeightPxOption"
+                );
+            }
+            // every following baseline stays on the grid, see [FontSizeScale::baseline_shift_px]
+            return Ok((target_px / grid_px - 0.001).ceil() * grid_px);
+        }
+
+        let aligned_px = align_to(target_px, self.AlignLineHeightPxOption);
+        match (min_px_opt, self.AlignLineHeightPxOption) {
+            // rounding to the nearest alignment must not undercut the minimum
+            (Some(min_px), Some(align_px)) if aligned_px < min_px => Ok(aligned_px + align_px),
+            _ => Ok(aligned_px),
+        }
+    }
+
+    /// How far to move the text down so its first baseline lands on the `SnapBaselineToGridPxOption`
+    /// grid, from `0.0` up to (but not including) the grid size.
+    pub fn baseline_shift_px(
+        &self,
+        line_height_px: f64,
+        font_size_px: f64,
+        metrics: &FontFamilyMetrics,
+    ) -> Option<f64> {
+        let grid_px = self.SnapBaselineToGridPxOption?;
+        // Browsers center the content area (ascent + descent) in the line box,
+        // so the first baseline sits at `(line_height - content) / 2 + ascent`.
+        let ascent_px = metrics.ascent / metrics.unitsPerEm * font_size_px;
+        let descent_px = metrics.descent.abs() / metrics.unitsPerEm * font_size_px;
+        let baseline_px = (line_height_px - ascent_px - descent_px) / 2.0 + ascent_px;
+        let shift_px = grid_px - baseline_px.rem_euclid(grid_px);
+        if shift_px > grid_px - 0.001 {
+            return Some(0.0);
+        }
+        Some(shift_px)
+    }
 }
 
 #[derive(Codegen, Debug, Deserialize)]
@@ -95,6 +202,21 @@ pub struct FontSizeRel {
     pub Token: String,
     /// e.g. `-2`, `-1`, `0`, `1`, etc.
     pub Rel: f64,
+    /// Defaults to aligning the cap height with `AlignCapHeightPxOption`,
+    /// or to no rounding for [FontSizeEquation::SizeTablePx].
+    pub RoundingOption: Option<FontSizeRounding>,
+}
+
+#[derive(Codegen, Clone, Debug, Deserialize)]
+#[codegen(tags = "typography-input")]
+#[allow(non_snake_case)]
+pub enum FontSizeRounding {
+    /// For example, `1.0` for aligning the cap height to 1px.
+    CapHeightPx(f64),
+    /// For example, `1.0` for whole pixel font sizes.
+    FontSizePx(f64),
+    /// Use the computed size as is.
+    Exact,
 }
 
 /// WIP: Based on @capsizecss/metrics
@@ -120,13 +242,90 @@ pub struct FontFamilyMetrics {
 pub enum FontFamilyTrackingRule {
     /// Determine your tracking goals via a page like https://rsms.me/inter/dynmetrics/
     DynMetrics { a: f64, b: f64, c: f64 },
+    /// The same tracking for every size, e.g. `0.0` for monospace families like Roboto Mono.
+    ConstantEm { em: f64 },
+    /// Explicit tracking for each `size:{}` token, e.g. from a foundry provided table.
+    /// Every size in the [FontSizeScale] must have an entry.
+    SizeTableEm { sizes: Vec<SizeTokenValue> },
+    /// Linearly interpolate tracking between anchor font sizes.
+    /// Font sizes outside of the anchors use the tracking of the nearest anchor.
+    InterpolateEm { anchors: Vec<TrackingAnchor> },
+}
+
+/// A value for a specific `size:{}` token, e.g. `{ Size: "xs", Value: 0.02 }`.
+#[derive(Codegen, Debug, Deserialize)]
+#[codegen(tags = "typography-input")]
+#[allow(non_snake_case)]
+pub struct SizeTokenValue {
+    /// e.g. `"xs"` for `size:xs`
+    pub Size: String,
+    pub Value: f64,
+}
+
+#[derive(Codegen, Debug, Deserialize)]
+#[codegen(tags = "typography-input")]
+#[allow(non_snake_case)]
+pub struct TrackingAnchor {
+    pub FontSizePx: f64,
+    pub TrackingEm: f64,
+}
+
+impl SizeTokenValue {
+    pub fn find<'a>(values: &'a [SizeTokenValue], size_token: &str) -> Option<&'a SizeTokenValue> {
+        values.iter().find(|v| v.Size == size_token)
+    }
 }
 
 impl FontFamilyTrackingRule {
-    pub fn compute_font_tracking_px(&self, font_size_px: f64) -> f64 {
+    pub fn compute_font_tracking_px(&self, size_token: &str, font_size_px: f64) -> Result<f64> {
         match self {
             // reference https://rsms.me/inter/dynmetrics/
-            FontFamilyTrackingRule::DynMetrics { a, b, c } => a + b * E.powf(c * font_size_px),
+            FontFamilyTrackingRule::DynMetrics { a, b, c } => Ok(a + b * E.powf(c * font_size_px)),
+            FontFamilyTrackingRule::ConstantEm { .. }
+            | FontFamilyTrackingRule::SizeTableEm { .. }
+            | FontFamilyTrackingRule::InterpolateEm { .. } => {
+                Ok(self.compute_font_tracking_em(size_token, font_size_px)? * font_size_px)
+            }
+        }
+    }
+
+    pub fn compute_font_tracking_em(&self, size_token: &str, font_size_px: f64) -> Result<f64> {
+        match self {
+            FontFamilyTrackingRule::DynMetrics { .. } => {
+                Ok(self.compute_font_tracking_px(size_token, font_size_px)? / font_size_px)
+            }
+            FontFamilyTrackingRule::ConstantEm { em } => Ok(*em),
+            FontFamilyTrackingRule::SizeTableEm { sizes } => {
+                SizeTokenValue::find(sizes, size_token)
+                    .map(|found| found.Value)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Tracking table is missing an entry for size ({size_token:?})"
+                        )
+                    })
+            }
+            FontFamilyTrackingRule::InterpolateEm { anchors } => {
+                let mut sorted = anchors.iter().collect::<Vec<_>>();
+                sorted.sort_by(|a, b| a.FontSizePx.total_cmp(&b.FontSizePx));
+                let (first, last) = match (sorted.first(), sorted.last()) {
+                    (Some(first), Some(last)) => (first, last),
+                    _ => anyhow::bail!("Tracking interpolation requires at least one anchor"),
+                };
+                if font_size_px <= first.FontSizePx {
+                    return Ok(first.TrackingEm);
+                }
+                if font_size_px >= last.FontSizePx {
+                    return Ok(last.TrackingEm);
+                }
+                for pair in sorted.windows(2) {
+                    let (lo, hi) = (pair[0], pair[1]);
+                    if font_size_px <= hi.FontSizePx {
+                        let t = (font_size_px - lo.FontSizePx) / (hi.FontSizePx - lo.FontSizePx);
+                        return Ok(lo.TrackingEm + t * (hi.TrackingEm - lo.TrackingEm));
+                    }
+                }
+                Ok(last.TrackingEm)
+            }
         }
     }
 }
@@ -137,15 +336,41 @@ impl FontFamilyTrackingRule {
 pub enum FontFamilyLineHeightRule {
     /// Determine your tracking goals via a page like https://rsms.me/inter/dynmetrics/
     FontSizePxMultipler { multiplier: f64 },
+    /// Explicit line-height for each `size:{}` token.
+    /// Every size in the [FontSizeScale] must have an entry.
+    SizeTablePx { sizes: Vec<SizeTokenValue> },
+    /// e.g. `2.5` times the cap height for dense UI
+    CapHeightMultiplier { multiplier: f64 },
+    /// e.g. `8.0` for a `16px` font size to have a `24px` line-height
+    FontSizePlusPx { px: f64 },
 }
 
 impl FontFamilyLineHeightRule {
-    pub fn compute_line_height_px(&self, font_size_px: f64, align_px_opt: Option<f64>) -> f64 {
+    /// Unaligned line-height, see [FontSizeScale::align_line_height_px].
+    pub fn compute_line_height_px(
+        &self,
+        size_token: &str,
+        font_size_px: f64,
+        cap_height_px: f64,
+    ) -> Result<f64> {
         match self {
             // reference https://rsms.me/inter/dynmetrics/
             FontFamilyLineHeightRule::FontSizePxMultipler { multiplier } => {
-                align_to(multiplier * font_size_px, align_px_opt)
+                Ok(multiplier * font_size_px)
+            }
+            FontFamilyLineHeightRule::SizeTablePx { sizes } => {
+                SizeTokenValue::find(sizes, size_token)
+                    .map(|found| found.Value)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Line-height table is missing an entry for size ({size_token:?})"
+                        )
+                    })
             }
+            FontFamilyLineHeightRule::CapHeightMultiplier { multiplier } => {
+                Ok(multiplier * cap_height_px)
+            }
+            FontFamilyLineHeightRule::FontSizePlusPx { px } => Ok(font_size_px + px),
         }
     }
 }
@@ -162,15 +387,426 @@ pub enum FontSizeEquation {
         /// of the font size base number.
         multiplier: f64,
     },
+    /// Same as `Multiplier`, but with a well known ratio.
+    ModularScale { base_px: f64, scale: ModularScale },
+    /// Use a different multiplier for sizes above and below the base,
+    /// e.g. subtle steps for small text and dramatic steps for headings.
+    TwoRatio {
+        base_px: f64,
+        /// Applied for each `Rel` above `0`
+        multiplier_above: f64,
+        /// Applied for each `Rel` below `0`
+        multiplier_below: f64,
+    },
+    /// Explicit font sizes (not cap heights) for each `size:{}` token,
+    /// e.g. for when `lg` should be exactly `20px`.
+    /// Every size in the [FontSizeScale] must have an entry.
+    SizeTablePx { sizes: Vec<SizeTokenValue> },
+}
+
+/// See https://www.modularscale.com/ for a comparison of these scales.
+#[derive(Codegen, Debug, Deserialize)]
+#[codegen(tags = "typography-input")]
+pub enum ModularScale {
+    /// `16:15`
+    MinorSecond,
+    /// `9:8`
+    MajorSecond,
+    /// `6:5`
+    MinorThird,
+    /// `5:4`
+    MajorThird,
+    /// `4:3`
+    PerfectFourth,
+    /// `√2`
+    AugmentedFourth,
+    /// `3:2`
+    PerfectFifth,
+    /// `1.618:1`
+    GoldenRatio,
+}
+
+impl ModularScale {
+    pub fn ratio(&self) -> f64 {
+        match self {
+            ModularScale::MinorSecond => 16.0 / 15.0,
+            ModularScale::MajorSecond => 9.0 / 8.0,
+            ModularScale::MinorThird => 6.0 / 5.0,
+            ModularScale::MajorThird => 5.0 / 4.0,
+            ModularScale::PerfectFourth => 4.0 / 3.0,
+            ModularScale::AugmentedFourth => std::f64::consts::SQRT_2,
+            ModularScale::PerfectFifth => 3.0 / 2.0,
+            ModularScale::GoldenRatio => 1.61803398875,
+        }
+    }
 }
 
 impl FontSizeEquation {
-    pub fn compute_cap_height_px(&self, rel: f64, align_px_opt: Option<f64>) -> f64 {
+    /// Unrounded font size, see [FontSizeScale::compute_font_size_px].
+    ///
+    /// `cap_height_em` is the family's cap height relative to its font size,
+    /// since the equations are defined in terms of cap height.
+    pub fn compute_font_size_px(&self, size: &FontSizeRel, cap_height_em: f64) -> Result<f64> {
         match self {
             FontSizeEquation::Multiplier {
                 base_px,
                 multiplier,
-            } => align_to(base_px * (multiplier.powf(rel)), align_px_opt),
+            } => Ok(base_px * (multiplier.powf(size.Rel)) / cap_height_em),
+            FontSizeEquation::ModularScale { base_px, scale } => {
+                Ok(base_px * (scale.ratio().powf(size.Rel)) / cap_height_em)
+            }
+            FontSizeEquation::TwoRatio {
+                base_px,
+                multiplier_above,
+                multiplier_below,
+            } => {
+                let multiplier = if size.Rel < 0.0 {
+                    multiplier_below
+                } else {
+                    multiplier_above
+                };
+                Ok(base_px * (multiplier.powf(size.Rel)) / cap_height_em)
+            }
+            FontSizeEquation::SizeTablePx { sizes } => SizeTokenValue::find(sizes, &size.Token)
+                .map(|found| found.Value)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Font size table is missing an entry for size ({:?})",
+                        size.Token
+                    )
+                }),
+        }
+    }
+}
+
+impl FontSizeScale {
+    /// Returns the rounded `(cap_height_px, font_size_px)` for the size.
+    pub fn compute_font_size_px(
+        &self,
+        size: &FontSizeRel,
+        metrics: &FontFamilyMetrics,
+    ) -> Result<(f64, f64)> {
+        let cap_height_em = metrics.capHeight / metrics.unitsPerEm;
+        let font_size_px = self
+            .Equation
+            .compute_font_size_px(size, cap_height_em)
+            .with_context(|| format!("computing font size for size ({:?})", size.Token))?;
+
+        let rounding = match &size.RoundingOption {
+            Some(rounding) => rounding.clone(),
+            None => match (&self.Equation, self.AlignCapHeightPxOption) {
+                (FontSizeEquation::SizeTablePx { .. }, _) | (_, None) => FontSizeRounding::Exact,
+                (_, Some(align_px)) => FontSizeRounding::CapHeightPx(align_px),
+            },
+        };
+
+        Ok(match rounding {
+            FontSizeRounding::CapHeightPx(align_px) => {
+                let cap_height_px = align_to(font_size_px * cap_height_em, Some(align_px));
+                (cap_height_px, cap_height_px / cap_height_em)
+            }
+            FontSizeRounding::FontSizePx(align_px) => {
+                let font_size_px = align_to(font_size_px, Some(align_px));
+                (font_size_px * cap_height_em, font_size_px)
+            }
+            FontSizeRounding::Exact => (font_size_px * cap_height_em, font_size_px),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, but found {actual}"
+        );
+    }
+
+    fn size_values(values: &[(&str, f64)]) -> Vec<SizeTokenValue> {
+        values
+            .iter()
+            .map(|(size, value)| SizeTokenValue {
+                Size: size.to_string(),
+                Value: *value,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tracking_dyn_metrics() {
+        let rule = FontFamilyTrackingRule::DynMetrics {
+            a: -0.02,
+            b: 0.2,
+            c: -0.1,
+        };
+        let px = rule.compute_font_tracking_px("base", 10.0).unwrap();
+        assert_close(px, -0.02 + 0.2 * E.powf(-1.0));
+        assert_close(
+            rule.compute_font_tracking_em("base", 10.0).unwrap(),
+            px / 10.0,
+        );
+    }
+
+    #[test]
+    fn tracking_constant_em() {
+        let rule = FontFamilyTrackingRule::ConstantEm { em: 0.01 };
+        assert_close(rule.compute_font_tracking_em("sm", 12.0).unwrap(), 0.01);
+        assert_close(rule.compute_font_tracking_px("lg", 20.0).unwrap(), 0.2);
+    }
+
+    #[test]
+    fn tracking_size_table_em() {
+        let rule = FontFamilyTrackingRule::SizeTableEm {
+            sizes: size_values(&[("xs", 0.02), ("lg", -0.01)]),
+        };
+        assert_close(rule.compute_font_tracking_em("xs", 12.0).unwrap(), 0.02);
+        assert_close(rule.compute_font_tracking_px("lg", 20.0).unwrap(), -0.2);
+        let err = rule.compute_font_tracking_em("sm", 14.0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Tracking table is missing an entry for size (\"sm\")"
+        );
+    }
+
+    #[test]
+    fn tracking_interpolate_em() {
+        // anchors are sorted before interpolating
+        let rule = FontFamilyTrackingRule::InterpolateEm {
+            anchors: vec![
+                TrackingAnchor {
+                    FontSizePx: 32.0,
+                    TrackingEm: -0.02,
+                },
+                TrackingAnchor {
+                    FontSizePx: 12.0,
+                    TrackingEm: 0.02,
+                },
+                TrackingAnchor {
+                    FontSizePx: 16.0,
+                    TrackingEm: 0.0,
+                },
+            ],
+        };
+        assert_close(rule.compute_font_tracking_em("", 14.0).unwrap(), 0.01);
+        assert_close(rule.compute_font_tracking_em("", 24.0).unwrap(), -0.01);
+        assert_close(rule.compute_font_tracking_px("", 24.0).unwrap(), -0.24);
+        // sizes outside of the anchors use the nearest anchor
+        assert_close(rule.compute_font_tracking_em("", 8.0).unwrap(), 0.02);
+        assert_close(rule.compute_font_tracking_em("", 64.0).unwrap(), -0.02);
+
+        let empty = FontFamilyTrackingRule::InterpolateEm { anchors: vec![] };
+        assert!(empty.compute_font_tracking_em("", 16.0).is_err());
+    }
+
+    /// 800 ascent and 200 descent, so a 20px font size has a 16px ascent and a 4px descent
+    fn metrics() -> FontFamilyMetrics {
+        FontFamilyMetrics {
+            familyName: "Test".to_string(),
+            category: "sans-serif".to_string(),
+            capHeight: 700.0,
+            ascent: 800.0,
+            descent: -200.0,
+            lineGap: 0.0,
+            unitsPerEm: 1000.0,
+            xHeight: 500.0,
+            xWidthAvg: 500.0,
         }
     }
+
+    fn scale(align_px: Option<f64>, snap_px: Option<f64>) -> FontSizeScale {
+        FontSizeScale {
+            FontSizes: vec![],
+            Equation: FontSizeEquation::Multiplier {
+                base_px: 7.0,
+                multiplier: 1.2,
+            },
+            AlignCapHeightPxOption: None,
+            AlignLineHeightPxOption: align_px,
+            SnapBaselineToGridPxOption: snap_px,
+        }
+    }
+
+    #[test]
+    fn line_height_rules() {
+        let multiplier = FontFamilyLineHeightRule::FontSizePxMultipler { multiplier: 1.5 };
+        assert_close(
+            multiplier.compute_line_height_px("", 16.0, 11.2).unwrap(),
+            24.0,
+        );
+        let cap_height = FontFamilyLineHeightRule::CapHeightMultiplier { multiplier: 2.5 };
+        assert_close(
+            cap_height.compute_line_height_px("", 16.0, 11.2).unwrap(),
+            28.0,
+        );
+        let plus = FontFamilyLineHeightRule::FontSizePlusPx { px: 8.0 };
+        assert_close(plus.compute_line_height_px("", 16.0, 11.2).unwrap(), 24.0);
+        let table = FontFamilyLineHeightRule::SizeTablePx {
+            sizes: size_values(&[("sm", 20.0)]),
+        };
+        assert_close(table.compute_line_height_px("sm", 14.0, 9.8).unwrap(), 20.0);
+        let err = table.compute_line_height_px("lg", 20.0, 14.0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Line-height table is missing an entry for size (\"lg\")"
+        );
+    }
+
+    #[test]
+    fn line_height_alignment_and_minimum() {
+        let unaligned = scale(None, None);
+        assert_close(unaligned.align_line_height_px(21.3, None).unwrap(), 21.3);
+        assert_close(
+            unaligned.align_line_height_px(17.0, Some(20.0)).unwrap(),
+            20.0,
+        );
+
+        let aligned = scale(Some(4.0), None);
+        assert_close(aligned.align_line_height_px(21.3, None).unwrap(), 20.0);
+        assert_close(aligned.align_line_height_px(22.1, None).unwrap(), 24.0);
+        // rounding down to 20px would undercut the 21px minimum
+        assert_close(
+            aligned.align_line_height_px(17.0, Some(21.0)).unwrap(),
+            24.0,
+        );
+    }
+
+    #[test]
+    fn baseline_grid_snapping() {
+        let snapped = scale(None, Some(4.0));
+        let metrics = metrics();
+
+        // 20px content area centered in a 24px line box puts the baseline at 2px + 16px
+        let line_height_px = snapped.align_line_height_px(22.5, None).unwrap();
+        assert_close(line_height_px, 24.0);
+        let shift_px = snapped
+            .baseline_shift_px(line_height_px, 20.0, &metrics)
+            .unwrap();
+        assert_close(shift_px, 2.0);
+
+        // in a 28px line box the baseline is already at 4px + 16px
+        let line_height_px = snapped.align_line_height_px(25.0, None).unwrap();
+        assert_close(line_height_px, 28.0);
+        let shift_px = snapped
+            .baseline_shift_px(line_height_px, 20.0, &metrics)
+            .unwrap();
+        assert_close(shift_px, 0.0);
+
+        // 18px: 14.4px ascent and 3.6px descent, so the baseline is at 3px + 14.4px
+        let shift_px = snapped.baseline_shift_px(24.0, 18.0, &metrics).unwrap();
+        assert_close(shift_px, 2.6);
+        assert_close((24.0 - 18.0) / 2.0 + 14.4 + shift_px, 20.0);
+
+        assert_eq!(
+            scale(Some(4.0), None).baseline_shift_px(24.0, 20.0, &metrics),
+            None
+        );
+        assert!(scale(Some(4.0), Some(4.0))
+            .align_line_height_px(24.0, None)
+            .is_err());
+    }
+
+    fn size(token: &str, rel: f64, rounding: Option<FontSizeRounding>) -> FontSizeRel {
+        FontSizeRel {
+            Token: token.to_string(),
+            Rel: rel,
+            RoundingOption: rounding,
+        }
+    }
+
+    #[test]
+    fn font_size_equations() {
+        // the equations scale the cap height, which is 0.7em for these metrics
+        let cap_height_em = 0.7;
+        let multiplier = FontSizeEquation::Multiplier {
+            base_px: 7.0,
+            multiplier: 1.2,
+        };
+        let lg = size("lg", 1.0, None);
+        assert_close(
+            multiplier.compute_font_size_px(&lg, cap_height_em).unwrap(),
+            12.0,
+        );
+
+        let modular = FontSizeEquation::ModularScale {
+            base_px: 7.0,
+            scale: ModularScale::PerfectFourth,
+        };
+        let xl = size("xl", 2.0, None);
+        assert_close(
+            modular.compute_font_size_px(&xl, cap_height_em).unwrap(),
+            10.0 * 16.0 / 9.0,
+        );
+        assert_close(ModularScale::MinorThird.ratio(), 1.2);
+        assert_close(ModularScale::GoldenRatio.ratio(), 1.61803398875);
+
+        let two_ratio = FontSizeEquation::TwoRatio {
+            base_px: 7.0,
+            multiplier_above: 1.5,
+            multiplier_below: 1.1,
+        };
+        let sm = size("sm", -1.0, None);
+        assert_close(
+            two_ratio.compute_font_size_px(&lg, cap_height_em).unwrap(),
+            15.0,
+        );
+        assert_close(
+            two_ratio.compute_font_size_px(&sm, cap_height_em).unwrap(),
+            10.0 / 1.1,
+        );
+
+        // table sizes are font sizes, so the cap height does not factor in
+        let table = FontSizeEquation::SizeTablePx {
+            sizes: size_values(&[("lg", 20.0)]),
+        };
+        assert_close(
+            table.compute_font_size_px(&lg, cap_height_em).unwrap(),
+            20.0,
+        );
+        let err = table.compute_font_size_px(&sm, cap_height_em).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Font size table is missing an entry for size (\"sm\")"
+        );
+    }
+
+    #[test]
+    fn font_size_rounding() {
+        let metrics = metrics();
+        let mut scale = scale(None, None);
+        scale.AlignCapHeightPxOption = Some(1.0);
+
+        // 14.4px font size with a 10.08px cap height
+        let (cap_height_px, font_size_px) = scale
+            .compute_font_size_px(&size("xl", 2.0, None), &metrics)
+            .unwrap();
+        assert_close(cap_height_px, 10.0);
+        assert_close(font_size_px, 10.0 / 0.7);
+
+        let (cap_height_px, font_size_px) = scale
+            .compute_font_size_px(
+                &size("xl", 2.0, Some(FontSizeRounding::FontSizePx(1.0))),
+                &metrics,
+            )
+            .unwrap();
+        assert_close(cap_height_px, 9.8);
+        assert_close(font_size_px, 14.0);
+
+        let (cap_height_px, font_size_px) = scale
+            .compute_font_size_px(&size("xl", 2.0, Some(FontSizeRounding::Exact)), &metrics)
+            .unwrap();
+        assert_close(cap_height_px, 10.08);
+        assert_close(font_size_px, 14.4);
+
+        // explicit table sizes are not rounded by default
+        scale.Equation = FontSizeEquation::SizeTablePx {
+            sizes: size_values(&[("lg", 20.5)]),
+        };
+        let (_, font_size_px) = scale
+            .compute_font_size_px(&size("lg", 1.0, None), &metrics)
+            .unwrap();
+        assert_close(font_size_px, 20.5);
+    }
 }
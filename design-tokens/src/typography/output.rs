@@ -3,7 +3,11 @@ use std::collections::{BTreeMap, BTreeSet, HashMap};
 use crate::{
     prelude::*,
     token,
-    tokens::{Token, TokenSet},
+    tokens::{
+        aliases::TokenAliases,
+        query::{Query, TokenMatcher, ValuePattern},
+        MatchErr, Token, TokenSet,
+    },
 };
 
 use super::{input, scalars};
@@ -21,12 +25,28 @@ impl TypographyExport {
     pub fn as_lookup(&self) -> TokenLookup {
         self.into()
     }
+
+    /// Each set of required tokens with the properties it applies.
+    pub fn iter_rules(&self) -> impl Iterator<Item = (&TokenSet, Vec<&TypographyProperty>)> {
+        self.tokens.iter().map(|(reqs, prop_idxs)| {
+            (
+                reqs,
+                prop_idxs.iter().map(|idx| &self.properties[*idx]).collect(),
+            )
+        })
+    }
 }
 
 pub struct TokenLookup<'a> {
     export: &'a TypographyExport,
-    // useful?
+    /// Which rules require each token.
     tokens_map: HashMap<Token, Vec<usize>>,
+    /// Each rule listed under only its least common required token, so a query
+    /// only visits rules which have a good chance of matching.
+    candidates_map: HashMap<Token, Vec<usize>>,
+    /// Rules which require no tokens, and so match every query.
+    unconditional: Vec<usize>,
+    aliases: Option<&'a TokenAliases>,
 }
 
 impl<'a> From<&'a TypographyExport> for TokenLookup<'a> {
@@ -37,43 +57,309 @@ impl<'a> From<&'a TypographyExport> for TokenLookup<'a> {
                 tokens_map.entry(req.clone()).or_default().push(i);
             }
         }
+
+        let mut candidates_map: HashMap<Token, Vec<usize>> = HashMap::new();
+        let mut unconditional = Vec::new();
+        for (i, (reqs, _)) in value.tokens.iter().enumerate() {
+            match reqs.iter().min_by_key(|req| tokens_map[req].len()) {
+                Some(rarest) => candidates_map.entry(rarest).or_default().push(i),
+                None => unconditional.push(i),
+            }
+        }
+
         TokenLookup {
             export: value,
             tokens_map,
+            candidates_map,
+            unconditional,
+            aliases: None,
         }
     }
 }
 
 pub struct TokenQueryOutput<'a> {
+    /// Ordered from lowest to highest precedence, so a later property overrides
+    /// an earlier one of the same [TypographyProperty::overrides_key].
     pub properties: Vec<&'a TypographyProperty>,
     /// Used to construct a `"key"` for figuring out which Figma TextStyles to replace.
     pub tokens_required: TokenSet,
+    /// Equally specific rules which set the same property to different values,
+    /// where only declaration order decides the winner. Ties which a more specific
+    /// rule overrides aren't conflicts.
+    /// Exporters warn about these, since reordering the input would change the result.
+    pub conflicts: Vec<PropertyConflict<'a>>,
+}
+
+#[derive(Debug)]
+pub struct PropertyConflict<'a> {
+    pub property: &'static str,
+    /// Number of tokens required by each of the conflicting rules
+    pub specificity: usize,
+    /// In declaration order, so the last one wins.
+    pub rules: Vec<(&'a TokenSet, &'a TypographyProperty)>,
+}
+
+impl std::fmt::Display for PropertyConflict<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is set by {} rules with specificity {}:",
+            self.property,
+            self.rules.len(),
+            self.specificity
+        )?;
+        for (reqs, prop) in &self.rules {
+            write!(f, "\n  {reqs:?} => {prop:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// See [TokenLookup::explain].
+pub struct QueryExplanation<'a> {
+    /// The queried tokens after resolving aliases
+    pub resolved: TokenSet,
+    /// In the order each property was first set
+    pub properties: Vec<ExplainedProperty<'a>>,
+    pub conflicts: Vec<PropertyConflict<'a>>,
+    /// Rules which missed matching by a single token, usually the surprising ones.
+    pub near_misses: Vec<(&'a TokenSet, MatchErr)>,
+}
+
+pub struct ExplainedProperty<'a> {
+    pub value: &'a TypographyProperty,
+    /// The rule with the highest precedence which set this property
+    pub source: &'a TokenSet,
+    /// Rules overridden by `source`, from highest to lowest precedence
+    pub shadowed: Vec<(&'a TokenSet, &'a TypographyProperty)>,
+}
+
+impl std::fmt::Display for QueryExplanation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "resolved {:?}", self.resolved)?;
+        for explained in &self.properties {
+            writeln!(f, "{:?}", explained.value)?;
+            writeln!(f, "  from {:?}", explained.source)?;
+            for (reqs, value) in &explained.shadowed {
+                writeln!(f, "  shadows {reqs:?} => {value:?}")?;
+            }
+        }
+        for conflict in &self.conflicts {
+            writeln!(f, "conflict: {conflict}")?;
+        }
+        if !self.near_misses.is_empty() {
+            writeln!(f, "near misses:")?;
+            for (reqs, mismatch) in &self.near_misses {
+                writeln!(f, "  {reqs:?} ({mismatch})")?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<'a> TokenLookup<'a> {
+    /// Queries will resolve alias tokens like `role:heading` before matching rules.
+    pub fn with_aliases(mut self, aliases: &'a TokenAliases) -> Self {
+        self.aliases = Some(aliases);
+        self
+    }
+
+    fn resolve_aliases<'t>(&self, token_set: &'t TokenSet) -> Cow<'t, TokenSet> {
+        match self.aliases {
+            Some(aliases) => Cow::Owned(aliases.resolve(token_set)),
+            None => Cow::Borrowed(token_set),
+        }
+    }
+
     pub fn query_with_set(&self, token_set: &TokenSet) -> TokenQueryOutput<'a> {
-        let mut found: Vec<(&Vec<usize>, &TokenSet)> = Vec::new();
-        for (reqs, prop_idxs) in &self.export.tokens {
-            if token_set.contains_all_of(reqs) {
-                found.push((prop_idxs, reqs));
+        let token_set = self.resolve_aliases(token_set);
+        self.collect_output(self.matching_rules(&token_set))
+    }
+
+    fn matching_rules<'s>(&'s self, token_set: &'s TokenSet) -> impl Iterator<Item = usize> + 's {
+        token_set
+            .iter()
+            .filter_map(|token| self.candidates_map.get(&token))
+            .flatten()
+            .copied()
+            .filter(|rule_idx| token_set.contains_all_of(&self.export.tokens[*rule_idx].0))
+            .chain(self.unconditional.iter().copied())
+    }
+
+    /// Like [TokenLookup::query_with_set], but traces where each property came from,
+    /// for `design-tokens explain "text role:ui size:sm"`.
+    pub fn explain(&self, token_set: &TokenSet) -> QueryExplanation<'a> {
+        let token_set = &*self.resolve_aliases(token_set);
+        let mut properties = Vec::<ExplainedProperty<'a>>::new();
+        for rule_idx in self.in_precedence_order(self.matching_rules(token_set)) {
+            let (reqs, prop_idxs) = &self.export.tokens[rule_idx];
+            for idx in prop_idxs {
+                let value = &self.export.properties[*idx];
+                let overridden = value.overrides_key().and_then(|key| {
+                    properties
+                        .iter_mut()
+                        .find(|explained| explained.value.overrides_key() == Some(key))
+                });
+                match overridden {
+                    Some(explained) => {
+                        let previous = (explained.source, explained.value);
+                        explained.shadowed.insert(0, previous);
+                        explained.source = reqs;
+                        explained.value = value;
+                    }
+                    None => properties.push(ExplainedProperty {
+                        value,
+                        source: reqs,
+                        shadowed: Vec::new(),
+                    }),
+                }
             }
         }
 
-        found.sort_unstable_by_key(|&(prec, _)| prec);
+        let near_misses = self
+            .export
+            .tokens
+            .iter()
+            .filter_map(|(reqs, _)| {
+                let mut mismatches = token_set.mismatches(reqs);
+                if mismatches.len() == 1 {
+                    Some((reqs, mismatches.remove(0)))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        QueryExplanation {
+            resolved: token_set.clone(),
+            properties,
+            conflicts: self.query_with_set(token_set).conflicts,
+            near_misses,
+        }
+    }
+
+    /// Checks every rule, which is what [TokenLookup::query_with_set] did before indexing.
+    /// Kept as the oracle for the indexed lookup in tests.
+    #[cfg(test)]
+    fn query_with_set_linear(&self, token_set: &TokenSet) -> TokenQueryOutput<'a> {
+        let matched = self
+            .export
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, (reqs, _))| token_set.contains_all_of(reqs))
+            .map(|(rule_idx, _)| rule_idx);
+
+        self.collect_output(matched)
+    }
+
+    /// More specific rules (requiring more tokens) win, then rules declared later win,
+    /// since [TypographyExport] keeps its rules in declaration order.
+    fn in_precedence_order(&self, matched: impl Iterator<Item = usize>) -> Vec<usize> {
+        let mut found: Vec<usize> = matched.collect();
+        found.sort_unstable_by_key(|&rule_idx| (self.export.tokens[rule_idx].0.len(), rule_idx));
+        found
+    }
+
+    fn collect_output(&self, matched: impl Iterator<Item = usize>) -> TokenQueryOutput<'a> {
+        let found = self.in_precedence_order(matched);
 
         let mut all_reqs = TokenSet::new();
         let mut all_props: Vec<&TypographyProperty> = Vec::new();
-        for (idxs, reqs) in found {
+        // property => (specificity, rules setting it), keeping only the most specific tier,
+        // since a tie in a less specific tier is always overridden
+        let mut setters =
+            BTreeMap::<&'static str, (usize, Vec<(&'a TokenSet, &'a TypographyProperty)>)>::new();
+        for rule_idx in found {
+            let (reqs, prop_idxs) = &self.export.tokens[rule_idx];
             all_reqs.append(reqs.iter());
-            for idx in idxs {
-                all_props.push(&self.export.properties[*idx]);
+            for idx in prop_idxs {
+                let prop = &self.export.properties[*idx];
+                all_props.push(prop);
+                if let Some(key) = prop.overrides_key() {
+                    let (specificity, rules) = setters
+                        .entry(key)
+                        .or_insert_with(|| (reqs.len(), Vec::new()));
+                    // `found` is in precedence order, so specificity only increases
+                    if *specificity < reqs.len() {
+                        *specificity = reqs.len();
+                        rules.clear();
+                    }
+                    rules.push((reqs, prop));
+                }
             }
         }
 
+        let conflicts = setters
+            .into_iter()
+            .filter(|(_, (_, rules))| rules.iter().any(|(_, prop)| *prop != rules[0].1))
+            .map(|(property, (specificity, rules))| PropertyConflict {
+                property,
+                specificity,
+                rules,
+            })
+            .collect();
+
         TokenQueryOutput {
             properties: all_props,
             tokens_required: all_reqs,
+            conflicts,
+        }
+    }
+
+    /// Every combination of known tokens which satisfies the query,
+    /// e.g. `text role:ui size:* !size:xs` results in a token set for each size except `xs`.
+    pub fn expand_query(&self, query: &Query) -> Vec<TokenSet> {
+        let mut known_values = BTreeMap::<&Cowstr, BTreeSet<&Cowstr>>::new();
+        for token in self.tokens_map.keys() {
+            if let Token::Value(key, value) = token {
+                known_values.entry(key).or_default().insert(value);
+            }
+        }
+        for (alias, _) in self.aliases.into_iter().flat_map(TokenAliases::iter) {
+            if let Token::Value(key, value) = alias {
+                known_values.entry(key).or_default().insert(value);
+            }
+        }
+
+        let mut expanded = vec![TokenSet::new()];
+        for term in query.terms.iter().filter(|term| !term.negated) {
+            let options: Vec<Token> = match &term.matcher {
+                TokenMatcher::Kind(kind) => vec![Token::Kind(kind.clone())],
+                TokenMatcher::Value {
+                    key,
+                    value: ValuePattern::Any,
+                } => known_values
+                    .get(key)
+                    .into_iter()
+                    .flatten()
+                    .map(|value| Token::Value(key.clone(), (*value).clone()))
+                    .collect(),
+                TokenMatcher::Value {
+                    key,
+                    value: ValuePattern::OneOf(values),
+                } => values
+                    .iter()
+                    .map(|value| Token::Value(key.clone(), value.clone()))
+                    .collect(),
+            };
+            expanded = expanded
+                .iter()
+                .flat_map(|token_set| {
+                    options.iter().map(move |option| {
+                        let mut token_set = token_set.clone();
+                        token_set.insert(option.clone());
+                        token_set
+                    })
+                })
+                .collect();
         }
+
+        // negated terms and repeated keys are checked after expanding
+        expanded.retain(|token_set| query.matches(token_set));
+        expanded.sort();
+        expanded.dedup();
+        expanded
     }
     pub fn query(&self, tokens: &[Token]) -> TokenQueryOutput<'a> {
         return self.query_with_set(&TokenSet::from(tokens.iter().cloned()));
@@ -118,8 +404,9 @@ impl<'a> TokenLookup<'a> {
 #[serde(transparent)]
 pub struct TypographyExtensionExport(BTreeMap<String, serde_json::Value>);
 
+/// Rules by their required tokens, with the position each was first declared at.
 #[derive(Default)]
-pub struct TypographyTokensCollector(BTreeMap<TokenSet, Vec<TypographyProperty>>);
+pub struct TypographyTokensCollector(BTreeMap<TokenSet, (usize, Vec<TypographyProperty>)>);
 
 impl From<TypographyTokensCollector> for TypographyExport {
     fn from(value: TypographyTokensCollector) -> Self {
@@ -129,7 +416,9 @@ impl From<TypographyTokensCollector> for TypographyExport {
             extensions: TypographyExtensionExport(BTreeMap::new()),
         };
 
-        for (tokens, values) in value.0.into_iter() {
+        let mut rules = value.0.into_iter().collect::<Vec<_>>();
+        rules.sort_by_key(|(_, (declared_idx, _))| *declared_idx);
+        for (tokens, (_, values)) in rules {
             let value_idxs = values.into_iter().map(|value| {
                 let found_idx_opt = result
                     .properties
@@ -159,12 +448,29 @@ pub enum TypographyProperty {
     FontFamily { family_name: Cow<'static, str> },
     LineHeight { px: f64 },
     FontSize { px: f64 },
-    LetterSpacing { px: f64 },
+    LetterSpacing { px: f64, em: f64 },
     FontStyle(scalars::FontStyleRule),
+    // moves the text down within its line box, see `FontSizeScale::baseline_shift_px`
+    BaselineShift { px: f64 },
     // /// Hmm
     // Variable { key: String, value: f64 },
 }
 
+impl TypographyProperty {
+    /// Properties with the same key override each other by precedence.
+    /// `FontStyle` rules accumulate instead (e.g. a weight suffix and an italic suffix).
+    pub fn overrides_key(&self) -> Option<&'static str> {
+        match self {
+            TypographyProperty::FontFamily { .. } => Some("FontFamily"),
+            TypographyProperty::LineHeight { .. } => Some("LineHeight"),
+            TypographyProperty::FontSize { .. } => Some("FontSize"),
+            TypographyProperty::LetterSpacing { .. } => Some("LetterSpacing"),
+            TypographyProperty::FontStyle(_) => None,
+            TypographyProperty::BaselineShift { .. } => Some("BaselineShift"),
+        }
+    }
+}
+
 impl TypographyTokensCollector {
     fn push(
         &mut self,
@@ -178,14 +484,16 @@ impl TypographyTokensCollector {
         // });
         Ok(())
     }
-    fn push_all(
+    pub(crate) fn push_all(
         &mut self,
         filter: impl IntoIterator<Item = Token>,
         values: impl IntoIterator<Item = TypographyProperty>,
     ) -> Result<()> {
+        let declared_idx = self.0.len();
         self.0
             .entry(TokenSet::from(filter))
-            .or_default()
+            .or_insert_with(|| (declared_idx, Vec::new()))
+            .1
             .extend(values);
         // for value in values {
         //     self.push(filter, value);
@@ -229,23 +537,38 @@ pub fn generate_typography_all_tokens(
             )?;
         }
 
-        let recip = family_info.Metrics.unitsPerEm / family_info.Metrics.capHeight;
-
         for size in input.FontSizeScale.FontSizes.iter() {
-            let cap_height_px = input
+            let (cap_height_px, font_size_px) = input
                 .FontSizeScale
-                .Equation
-                .compute_cap_height_px(size.Rel, input.FontSizeScale.AlignCapHeightPxOption);
-
-            let font_size_px = recip * cap_height_px;
+                .compute_font_size_px(size, &family_info.Metrics)
+                .with_context(|| {
+                    format!("computing font size for text role ({:?})", text_role.Token)
+                })?;
 
             let tracking_px = text_role
                 .TrackingRule
-                .compute_font_tracking_px(font_size_px);
+                .compute_font_tracking_px(&size.Token, font_size_px)
+                .with_context(|| {
+                    format!("computing tracking for text role ({:?})", text_role.Token)
+                })?;
 
             let line_height_px = text_role
                 .LineHeightRule
-                .compute_line_height_px(font_size_px, input.FontSizeScale.AlignLineHeightPxOption);
+                .compute_line_height_px(&size.Token, font_size_px, cap_height_px)
+                .with_context(|| {
+                    format!(
+                        "computing line-height for text role ({:?})",
+                        text_role.Token
+                    )
+                })?;
+            let line_height_px = input
+                .FontSizeScale
+                .align_line_height_px(line_height_px, text_role.LineHeightMinPxOption)?;
+            let baseline_shift_px = input.FontSizeScale.baseline_shift_px(
+                line_height_px,
+                font_size_px,
+                &family_info.Metrics,
+            );
 
             all_tokens.push_all(
                 rules
@@ -253,9 +576,14 @@ pub fn generate_typography_all_tokens(
                     .chain(std::iter::once(Token::of_value("size", size.Token.clone()))),
                 [
                     TypographyProperty::FontSize { px: font_size_px },
-                    TypographyProperty::LetterSpacing { px: tracking_px },
+                    TypographyProperty::LetterSpacing {
+                        px: tracking_px,
+                        em: tracking_px / font_size_px,
+                    },
                     TypographyProperty::LineHeight { px: line_height_px },
-                ],
+                ]
+                .into_iter()
+                .chain(baseline_shift_px.map(|px| TypographyProperty::BaselineShift { px })),
             )?;
         }
     }
@@ -286,3 +614,134 @@ pub fn generate_typography_all_tokens(
 
     Ok(all_tokens)
 }
+
+/// Compares the indexed [TokenLookup::query_with_set] against the linear scan
+/// it replaced, with an export shaped like a large design system.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn large_export() -> Result<(TypographyExport, Vec<TokenSet>)> {
+        let mut collector = TypographyTokensCollector::default();
+        let sizes = ["xs", "sm", "base", "lg", "xl", "2xl", "3xl", "4xl"];
+        let weights = [100, 200, 300, 400, 500, 600, 700, 800, 900];
+        for role in 0..4 {
+            let role_token = Token::of_value_display("role", format!("role{role}"));
+            collector.push(
+                [Token::of_kind("text"), role_token.clone()],
+                TypographyProperty::FontFamily {
+                    family_name: Cow::Owned(format!("Family {}", role % 3)),
+                },
+            )?;
+            for (i, size) in sizes.iter().enumerate() {
+                collector.push(
+                    [
+                        Token::of_kind("text"),
+                        role_token.clone(),
+                        Token::of_value("size", *size),
+                    ],
+                    TypographyProperty::FontSize {
+                        px: 10.0 + i as f64 * 2.0,
+                    },
+                )?;
+            }
+            for weight in weights {
+                collector.push(
+                    [
+                        role_token.clone(),
+                        Token::of_value_display("weight", weight),
+                    ],
+                    TypographyProperty::LineHeight { px: weight as f64 },
+                )?;
+            }
+        }
+        // colors and spacing share the export, but never match text queries
+        for color in 0..20 {
+            for tone in (0..=100).step_by(10) {
+                collector.push(
+                    [
+                        Token::of_kind("color"),
+                        Token::of_value_display("color", format!("color{color}")),
+                        Token::of_value_display("tone", tone),
+                    ],
+                    TypographyProperty::LetterSpacing {
+                        px: tone as f64,
+                        em: 0.0,
+                    },
+                )?;
+            }
+        }
+        for space in 0..50 {
+            collector.push(
+                [
+                    Token::of_kind("space"),
+                    Token::of_value_display("space", space),
+                ],
+                TypographyProperty::LineHeight { px: space as f64 },
+            )?;
+        }
+
+        let export = TypographyExport::from(collector);
+
+        let mut queries = Vec::new();
+        for role in 0..4 {
+            for size in sizes {
+                for weight in weights {
+                    queries.push(TokenSet::from([
+                        Token::of_kind("text"),
+                        Token::of_value_display("role", format!("role{role}")),
+                        Token::of_value("size", size),
+                        Token::of_value_display("weight", weight),
+                    ]));
+                }
+            }
+        }
+        Ok((export, queries))
+    }
+
+    #[test]
+    fn indexed_lookup_matches_linear_scan() {
+        let (export, queries) = large_export().unwrap();
+        let lookup = export.as_lookup();
+        for query in &queries {
+            assert_eq!(
+                lookup.query_with_set(query).properties,
+                lookup.query_with_set_linear(query).properties,
+                "{query:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn conflicts_only_in_the_winning_tier() {
+        let font_size = |px| TypographyProperty::FontSize { px };
+        let text = || Token::of_kind("text");
+        let role = || Token::of_value("role", "ui");
+        let size = || Token::of_value("size", "sm");
+        // `role:ui` and `size:sm` tie, unless a rule requiring both overrides them
+        let export = |specific: bool| {
+            let mut collector = TypographyTokensCollector::default();
+            collector.push([text(), role()], font_size(12.0)).unwrap();
+            collector.push([text(), size()], font_size(14.0)).unwrap();
+            if specific {
+                collector
+                    .push([text(), role(), size()], font_size(16.0))
+                    .unwrap();
+            }
+            TypographyExport::from(collector)
+        };
+        let query = TokenSet::from([text(), role(), size()]);
+
+        let tied = export(false);
+        let conflicts = tied.as_lookup().query_with_set(&query).conflicts;
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].property, "FontSize");
+        assert_eq!(conflicts[0].specificity, 2);
+        assert_eq!(conflicts[0].rules.len(), 2);
+
+        let resolved = export(true);
+        let output = resolved.as_lookup().query_with_set(&query);
+        assert!(output.conflicts.is_empty(), "{:?}", output.conflicts);
+        assert_eq!(output.properties.last(), Some(&&font_size(16.0)));
+    }
+}
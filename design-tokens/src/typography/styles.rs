@@ -0,0 +1,105 @@
+//! Materialized text styles are each combination of known tokens matching a query
+//! (by default every role and size) with the final value of each property resolved.
+//! Exporters for other tools start from these rather than the rules in [TypographyExport].
+use crate::{
+    prelude::*,
+    tokens::{aliases::TokenAliases, query::Query, TokenSet},
+};
+
+use super::{
+    css::css_scalars,
+    output::{TypographyExport, TypographyProperty},
+};
+
+/// e.g. `text role:ui size:sm` and `text role:content size:lg`
+pub const DEFAULT_TEXT_STYLES_QUERY: &str = "text role:* size:*";
+
+#[derive(Clone, Debug)]
+pub struct MaterializedTextStyle {
+    /// e.g. `text role:ui size:sm`, before resolving aliases
+    pub tokens: TokenSet,
+    pub family_name: String,
+    pub font_size_px: f64,
+    pub line_height_px: Option<f64>,
+    pub letter_spacing_px: Option<f64>,
+    /// Defaults to `400`
+    pub font_weight: usize,
+    pub italic: bool,
+    /// e.g. `"'wght' 450"`
+    pub font_variation_settings: Vec<String>,
+}
+
+pub fn materialize_text_styles(
+    all_tokens: &TypographyExport,
+    aliases: &TokenAliases,
+    query: &Query,
+) -> Result<Vec<MaterializedTextStyle>> {
+    let lookup = all_tokens.as_lookup().with_aliases(aliases);
+    lookup
+        .expand_query(query)
+        .into_iter()
+        .map(|tokens| {
+            let output = lookup.query_with_set(&tokens);
+            for conflict in &output.conflicts {
+                eprintln!(
+                    "warning: text style ({tokens:?}) depends on declaration order: {conflict}"
+                );
+            }
+
+            let mut family_name = Option::<String>::None;
+            let mut font_size_px = Option::<f64>::None;
+            let mut line_height_px = Option::<f64>::None;
+            let mut letter_spacing_px = Option::<f64>::None;
+            let mut font_weight = 400;
+            let mut italic = false;
+            let mut font_variation_settings = Vec::new();
+            // properties are ordered by precedence, so later ones override earlier ones
+            for prop in output.properties {
+                match prop {
+                    TypographyProperty::FontFamily { family_name: name } => {
+                        family_name = Some(name.to_string())
+                    }
+                    TypographyProperty::FontSize { px } => font_size_px = Some(*px),
+                    TypographyProperty::LineHeight { px } => line_height_px = Some(*px),
+                    TypographyProperty::LetterSpacing { px, .. } => letter_spacing_px = Some(*px),
+                    // only the CSS exporter positions text within its line box
+                    TypographyProperty::BaselineShift { .. } => {}
+                    TypographyProperty::FontStyle(style_scalar) => {
+                        let css_rules = serde_json::from_value::<css_scalars::FontStyleRule>(
+                            style_scalar.0.clone(),
+                        )
+                        .context("expecting TypographyProperty::FontStyle to support CSS")?
+                        .CSS;
+                        for css_rule in css_rules {
+                            match css_rule {
+                                css_scalars::CSSFontStyleRule::FontStyleItalics => italic = true,
+                                css_scalars::CSSFontStyleRule::FontWeightBold => font_weight = 700,
+                                css_scalars::CSSFontStyleRule::FontWeight(weight) => {
+                                    font_weight = weight
+                                }
+                                css_scalars::CSSFontStyleRule::FontVariationSetting(setting) => {
+                                    font_variation_settings.push(setting)
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            Ok(MaterializedTextStyle {
+                family_name: family_name.ok_or_else(|| {
+                    anyhow::anyhow!("no family name found for text style ({tokens:?})")
+                })?,
+                font_size_px: font_size_px.ok_or_else(|| {
+                    anyhow::anyhow!("no font size found for text style ({tokens:?})")
+                })?,
+                line_height_px,
+                letter_spacing_px,
+                font_weight,
+                italic,
+                font_variation_settings,
+                tokens,
+            })
+        })
+        .collect()
+}
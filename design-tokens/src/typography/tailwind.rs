@@ -1,43 +1,183 @@
-use super::{input, output, scalars};
-use crate::prelude::*;
+//! Values for Tailwind's `theme.extend`, with a `fontSize` for each materialized text style,
+//! converted to the same units as the CSS exporter, e.g. `rem` font sizes.
+use std::collections::BTreeMap;
 
-#[derive(Codegen, Debug)]
+use super::{
+    css::css_export::css_font_family, input::FontFamilyInfo, styles::MaterializedTextStyle,
+    units::TypographyUnits,
+};
+use crate::{
+    platforms::{kebab_case, words},
+    prelude::*,
+};
+
+#[derive(Codegen, Debug, Serialize)]
 #[codegen(tags = "tailwind-typography-export")]
 #[allow(non_snake_case)]
 pub struct TailwindTypographyExport {
-    // hmmm
+    /// e.g. `{ "inter": "hnsans, system-ui, sans-serif" }` for the `font-inter` class
+    pub fontFamily: BTreeMap<String, String>,
+    /// e.g. `{ "ui-sm": ["0.875rem", { "lineHeight": "1.25rem" }] }` for the `text-ui-sm` class.
+    /// Tailwind can't set a family or italics with the font size, so those stay separate
+    /// classes, e.g. `font-inter italic`.
+    pub fontSize: BTreeMap<String, (String, TailwindFontSizeOptions)>,
+}
+
+#[derive(Codegen, Debug, Serialize)]
+#[codegen(tags = "tailwind-typography-export")]
+#[allow(non_snake_case)]
+pub struct TailwindFontSizeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lineHeight: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub letterSpacing: Option<String>,
+    /// e.g. `"700"`
+    pub fontWeight: String,
 }
 
 pub mod tailwind_config {
-    use crate::prelude::*;
+    use crate::{prelude::*, typography::units::TypographyUnits};
 
     // Must be named `TypographyExtensionInput` to ensure it merges with other typography extensions
     /// Depends on `css-typography-scalars`
-    #[derive(Debug, Codegen)]
+    #[derive(Debug, Codegen, Deserialize)]
     #[codegen(tags = "tailwind-typography-input")]
     #[codegen(ts_interface_merge)]
     #[allow(non_snake_case)]
     pub struct TypographyExtensionInput {
+        /// Defaults to `px` for all units.
         #[serde(alias = "tailwind")]
-        Tailwind: TailwindTypographyConfig,
+        pub Tailwind: Option<TailwindTypographyConfig>,
     }
 
-    #[derive(Codegen, Debug)]
+    #[derive(Codegen, Debug, Default, Deserialize)]
     #[codegen(tags = "tailwind-typography-input")]
     #[allow(non_snake_case)]
     pub struct TailwindTypographyConfig {
         // TODO: Some kind of narrowing / selections for creating types / lints for the design system
         // e.g. we should be able to swap the font families, even if the new one has fewer weights.
-        /// A sort of matrice of all possible combinations of the variants
-        pub TailwindTextClasses: (),
+        /// e.g. `{ FontSizeOption: "Rem", LetterSpacingOption: "Em", LineHeightOption: "Unitless" }`
+        pub UnitsOption: Option<TypographyUnits>,
     }
 }
 
+/// Text styles are named after their values in this order, then any other keys
+const NAME_KEYS: [&str; 4] = ["role", "size", "weight", "italic"];
+
+/// Each text style is named after its token values, e.g. `text role:ui size:sm italic:true`
+/// is `ui-sm-italic`.
 pub fn generate_typography_for_tailwind(
-    all_tokens: &output::TypographyExport,
+    text_styles: &[MaterializedTextStyle],
+    families: &[FontFamilyInfo],
     tailwind_settings: &tailwind_config::TailwindTypographyConfig,
 ) -> Result<TailwindTypographyExport> {
-    Ok(TailwindTypographyExport {
-        // core_styles: todo!("use all tokens to create core styles: {all_tokens:#?}"),
-    })
+    let default_units = TypographyUnits::default();
+    let units = tailwind_settings
+        .UnitsOption
+        .as_ref()
+        .unwrap_or(&default_units);
+
+    let name_keys = NAME_KEYS.map(String::from);
+    let mut export = TailwindTypographyExport {
+        fontFamily: BTreeMap::new(),
+        fontSize: BTreeMap::new(),
+    };
+    for style in text_styles {
+        let family_key = kebab_case(&words([&style.family_name]));
+        let font_family = css_font_family(&style.family_name, families)
+            .with_context(|| format!("creating Tailwind font family for ({:?})", style.tokens))?;
+        export.fontFamily.insert(family_key, font_family);
+
+        let name = kebab_case(&words(
+            style
+                .tokens
+                .values_ordered_by(&name_keys)
+                .into_iter()
+                .map(|(key, value)| match value.as_ref() {
+                    "true" => key.as_ref(),
+                    value => value,
+                }),
+        ));
+        let options = TailwindFontSizeOptions {
+            lineHeight: style
+                .line_height_px
+                .map(|px| units.line_height(px, style.font_size_px).to_string()),
+            letterSpacing: style
+                .letter_spacing_px
+                .map(|px| units.letter_spacing(px, style.font_size_px).to_string()),
+            fontWeight: style.font_weight.to_string(),
+        };
+        let font_size = units.font_size(style.font_size_px).to_string();
+        if export
+            .fontSize
+            .insert(name.clone(), (font_size, options))
+            .is_some()
+        {
+            anyhow::bail!(
+                "more than one Tailwind font size is named ({name:?}), including ({:?})",
+                style.tokens
+            );
+        }
+    }
+
+    Ok(export)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tokens::{split_tokens, TokenSet},
+        typography::units::{FontSizeUnit, LetterSpacingUnit, LineHeightUnit},
+    };
+
+    #[test]
+    fn font_sizes_in_configured_units() {
+        let style = MaterializedTextStyle {
+            tokens: TokenSet::from(split_tokens("text role:ui size:sm italic:true").unwrap()),
+            family_name: "Inter".to_string(),
+            font_size_px: 14.0,
+            line_height_px: Some(21.0),
+            letter_spacing_px: Some(-0.14),
+            font_weight: 500,
+            italic: true,
+            font_variation_settings: vec![],
+        };
+        let families = serde_json::from_value::<Vec<FontFamilyInfo>>(serde_json::json!([{
+            "BaseName": "Inter",
+            "CSSFontFamilyName": "hnsans",
+            "CSSFontFamilyFallbacks": ["Segoe UI", "sans-serif"],
+            "DefaultRules": [],
+            "Weights": [],
+            "Metrics": {
+                "familyName": "Inter", "category": "sans-serif", "capHeight": 1490.0,
+                "ascent": 1984.0, "descent": -494.0, "lineGap": 0.0, "unitsPerEm": 2048.0,
+                "xHeight": 1118.0, "xWidthAvg": 1083.0
+            }
+        }]))
+        .unwrap();
+        let config = tailwind_config::TailwindTypographyConfig {
+            UnitsOption: Some(TypographyUnits {
+                RootFontSizePxOption: None,
+                FontSizeOption: Some(FontSizeUnit::Rem),
+                LetterSpacingOption: Some(LetterSpacingUnit::Em),
+                LineHeightOption: Some(LineHeightUnit::Unitless),
+            }),
+        };
+
+        let export = generate_typography_for_tailwind(&[style], &families, &config).unwrap();
+        assert_eq!(
+            serde_json::to_value(&export).unwrap(),
+            serde_json::json!({
+                "fontFamily": { "inter": "hnsans, \"Segoe UI\", sans-serif" },
+                "fontSize": {
+                    "ui-sm-italic": ["0.875rem", {
+                        "lineHeight": "1.5",
+                        "letterSpacing": "-0.01em",
+                        "fontWeight": "500"
+                    }]
+                }
+            })
+        );
+    }
 }
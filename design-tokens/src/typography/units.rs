@@ -0,0 +1,165 @@
+//! Converts the `px` values of [super::output::TypographyProperty] into the units
+//! an environment expects, e.g. `rem` font sizes for CSS, while Figma keeps `px`.
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+#[derive(Codegen, Debug, Default, Deserialize)]
+#[codegen(tags = "typography-units-input")]
+#[allow(non_snake_case)]
+pub struct TypographyUnits {
+    /// Defaults to `16.0`, which is the default root font size of browsers.
+    pub RootFontSizePxOption: Option<f64>,
+    /// Defaults to `Px`. Use `Rem` to respect the user's font size settings.
+    pub FontSizeOption: Option<FontSizeUnit>,
+    /// Defaults to `Px`.
+    pub LetterSpacingOption: Option<LetterSpacingUnit>,
+    /// Defaults to `Px`.
+    pub LineHeightOption: Option<LineHeightUnit>,
+}
+
+#[derive(Codegen, Clone, Copy, Debug, Deserialize)]
+#[codegen(tags = "typography-units-input")]
+pub enum FontSizeUnit {
+    Px,
+    /// Relative to the root font size
+    Rem,
+}
+
+#[derive(Codegen, Clone, Copy, Debug, Deserialize)]
+#[codegen(tags = "typography-units-input")]
+pub enum LetterSpacingUnit {
+    Px,
+    /// Relative to the font size, so it scales along with `rem` font sizes
+    Em,
+}
+
+#[derive(Codegen, Clone, Copy, Debug, Deserialize)]
+#[codegen(tags = "typography-units-input")]
+pub enum LineHeightUnit {
+    Px,
+    /// Relative to the root font size
+    Rem,
+    /// Relative to the font size, e.g. `1.5`
+    Unitless,
+}
+
+/// A length ready to be written out, see [Display].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Length {
+    Px(f64),
+    Rem(f64),
+    Em(f64),
+    Unitless(f64),
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Length::Px(value) => write!(f, "{}px", format_number(*value)),
+            Length::Rem(value) => write!(f, "{}rem", format_number(*value)),
+            Length::Em(value) => write!(f, "{}em", format_number(*value)),
+            Length::Unitless(value) => write!(f, "{}", format_number(*value)),
+        }
+    }
+}
+
+/// Rounds to 4 decimal places and drops trailing zeros, e.g. `0.8125` or `12`.
+pub fn format_number(value: f64) -> String {
+    let rounded = format!("{:.4}", value);
+    let trimmed = rounded.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" | "" => "0".to_string(),
+        other => other.to_string(),
+    }
+}
+
+impl TypographyUnits {
+    pub fn root_font_size_px(&self) -> f64 {
+        self.RootFontSizePxOption.unwrap_or(16.0)
+    }
+
+    pub fn font_size(&self, font_size_px: f64) -> Length {
+        match self.FontSizeOption.unwrap_or(FontSizeUnit::Px) {
+            FontSizeUnit::Px => Length::Px(font_size_px),
+            FontSizeUnit::Rem => Length::Rem(font_size_px / self.root_font_size_px()),
+        }
+    }
+
+    pub fn letter_spacing(&self, letter_spacing_px: f64, font_size_px: f64) -> Length {
+        match self.LetterSpacingOption.unwrap_or(LetterSpacingUnit::Px) {
+            LetterSpacingUnit::Px => Length::Px(letter_spacing_px),
+            LetterSpacingUnit::Em => Length::Em(letter_spacing_px / font_size_px),
+        }
+    }
+
+    pub fn line_height(&self, line_height_px: f64, font_size_px: f64) -> Length {
+        match self.LineHeightOption.unwrap_or(LineHeightUnit::Px) {
+            LineHeightUnit::Px => Length::Px(line_height_px),
+            LineHeightUnit::Rem => Length::Rem(line_height_px / self.root_font_size_px()),
+            LineHeightUnit::Unitless => Length::Unitless(line_height_px / font_size_px),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn units(
+        font_size: FontSizeUnit,
+        letter_spacing: LetterSpacingUnit,
+        line_height: LineHeightUnit,
+    ) -> TypographyUnits {
+        TypographyUnits {
+            RootFontSizePxOption: None,
+            FontSizeOption: Some(font_size),
+            LetterSpacingOption: Some(letter_spacing),
+            LineHeightOption: Some(line_height),
+        }
+    }
+
+    #[test]
+    fn px_by_default() {
+        let units = TypographyUnits::default();
+        assert_eq!(units.font_size(14.0).to_string(), "14px");
+        assert_eq!(units.letter_spacing(-0.14, 14.0).to_string(), "-0.14px");
+        assert_eq!(units.line_height(20.0, 14.0).to_string(), "20px");
+    }
+
+    #[test]
+    fn relative_units() {
+        let relative = units(
+            FontSizeUnit::Rem,
+            LetterSpacingUnit::Em,
+            LineHeightUnit::Unitless,
+        );
+        assert_eq!(relative.font_size(14.0), Length::Rem(0.875));
+        assert_eq!(relative.letter_spacing(-0.28, 14.0), Length::Em(-0.02));
+        assert_eq!(relative.line_height(21.0, 14.0), Length::Unitless(1.5));
+        assert_eq!(relative.line_height(21.0, 14.0).to_string(), "1.5");
+
+        let rem_line_height = TypographyUnits {
+            RootFontSizePxOption: Some(10.0),
+            ..units(
+                FontSizeUnit::Rem,
+                LetterSpacingUnit::Px,
+                LineHeightUnit::Rem,
+            )
+        };
+        assert_eq!(rem_line_height.font_size(14.0).to_string(), "1.4rem");
+        assert_eq!(
+            rem_line_height.line_height(21.0, 14.0).to_string(),
+            "2.1rem"
+        );
+    }
+
+    #[test]
+    fn number_formatting() {
+        assert_eq!(format_number(12.0), "12");
+        assert_eq!(format_number(0.8125), "0.8125");
+        assert_eq!(format_number(1.0 / 3.0), "0.3333");
+        assert_eq!(format_number(-0.00001), "0");
+        assert_eq!(Length::Em(-0.0125).to_string(), "-0.0125em");
+    }
+}
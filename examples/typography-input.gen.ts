@@ -95,6 +95,38 @@ export type CSSFontStyleRule =
   | CSSFontStyleRule.FontWeightBold
   | CSSFontStyleRule.FontWeight
   | CSSFontStyleRule.FontVariationSetting
+/**
+ * Depends on `css-typography-scalars`
+ *
+ * `#[codegen(ts_interface_merge, tags = "css-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/css.rs:36`](../../design-tokens/src/typography/css.rs)
+ */
+export interface TypographyExtensionInput {
+  /**
+   * Defaults to `px` for all units.
+   *
+   * `#[serde(alias = "css")]`
+   */
+  CSS?: CSSTypographyConfig | undefined | null | null | undefined;
+}
+/**
+ * `#[codegen(tags = "css-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/css.rs:46`](../../design-tokens/src/typography/css.rs)
+ */
+export type CSSTypographyConfig = {
+  /** e.g. `{ FontSizeOption: "Rem", LetterSpacingOption: "Em", LineHeightOption: "Unitless" }` */
+  UnitsOption?: TypographyUnits | undefined | null | null | undefined;
+};
+/**
+ * `#[codegen(tags = "css-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/css.rs:46`](../../design-tokens/src/typography/css.rs)
+ */
+export function CSSTypographyConfig(inner: CSSTypographyConfig): CSSTypographyConfig {
+  return inner;
+}
 /**
  * FontStyleRule is whatever your source configuration is using to match the environment's
  * font styles to the desired weights and such.
@@ -205,25 +237,29 @@ export type FigmaFontStyleRule =
  *
  * `#[codegen(ts_interface_merge, tags = "tailwind-typography-input")]`
  *
- * [Source `design-tokens/src/typography/tailwind.rs:16`](../../design-tokens/src/typography/tailwind.rs)
+ * [Source `design-tokens/src/typography/tailwind.rs:43`](../../design-tokens/src/typography/tailwind.rs)
  */
 export interface TypographyExtensionInput {
-  /** `#[serde(alias = "tailwind")]` */
-  Tailwind: TailwindTypographyConfig;
+  /**
+   * Defaults to `px` for all units.
+   *
+   * `#[serde(alias = "tailwind")]`
+   */
+  Tailwind?: TailwindTypographyConfig | undefined | null | null | undefined;
 }
 /**
  * `#[codegen(tags = "tailwind-typography-input")]`
  *
- * [Source `design-tokens/src/typography/tailwind.rs:25`](../../design-tokens/src/typography/tailwind.rs)
+ * [Source `design-tokens/src/typography/tailwind.rs:53`](../../design-tokens/src/typography/tailwind.rs)
  */
 export type TailwindTypographyConfig = {
-  /** A sort of matrice of all possible combinations of the variants */
-  TailwindTextClasses: [];
+  /** e.g. `{ FontSizeOption: "Rem", LetterSpacingOption: "Em", LineHeightOption: "Unitless" }` */
+  UnitsOption?: TypographyUnits | undefined | null | null | undefined;
 };
 /**
  * `#[codegen(tags = "tailwind-typography-input")]`
  *
- * [Source `design-tokens/src/typography/tailwind.rs:25`](../../design-tokens/src/typography/tailwind.rs)
+ * [Source `design-tokens/src/typography/tailwind.rs:53`](../../design-tokens/src/typography/tailwind.rs)
  */
 export function TailwindTypographyConfig(inner: TailwindTypographyConfig): TailwindTypographyConfig {
   return inner;
@@ -231,7 +267,7 @@ export function TailwindTypographyConfig(inner: TailwindTypographyConfig): Tailw
 /**
  * `#[codegen(ts_interface_merge, tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:286`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:780`](../../design-tokens/src/typography/figma.rs)
  */
 export interface TypographyExtensionInput {
   /** `#[serde(alias = "figma")]` */
@@ -240,16 +276,22 @@ export interface TypographyExtensionInput {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:295`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:789`](../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaTypographyConfig = {
   /** A sort of matrice of all possible combinations of the variants */
   FigmaTextStyles: Array<FigmaTextStyle>;
+  /**
+   * Figma variables for the text styles' numbers and fonts.
+   * When set, the text styles are also bound to these variables,
+   * so update the variables in Figma before the typography.
+   */
+  VariablesOption?: FigmaVariablesConfig | undefined | null | null | undefined;
 };
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:295`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:789`](../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaTypographyConfig(inner: FigmaTypographyConfig): FigmaTypographyConfig {
   return inner;
@@ -257,7 +299,45 @@ export function FigmaTypographyConfig(inner: FigmaTypographyConfig): FigmaTypogr
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:305`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:803`](../../design-tokens/src/typography/figma.rs)
+ */
+export type FigmaVariablesConfig = {
+  /**
+   * Modes of the typography variable collection, each adding its tokens to every text style.
+   * Defaults to a single `"Default"` mode.
+   * e.g. `[{ Name: "Comfortable", Tokens: "density:comfortable" }, { Name: "Compact", Tokens: "density:compact" }]`
+   */
+  TypographyModesOption?: Array<FigmaVariableMode> | undefined | null | null | undefined;
+};
+/**
+ * `#[codegen(tags = "figma-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:803`](../../design-tokens/src/typography/figma.rs)
+ */
+export function FigmaVariablesConfig(inner: FigmaVariablesConfig): FigmaVariablesConfig {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "figma-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:813`](../../design-tokens/src/typography/figma.rs)
+ */
+export type FigmaVariableMode = {
+  Name: string;
+  Tokens: string;
+};
+/**
+ * `#[codegen(tags = "figma-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:813`](../../design-tokens/src/typography/figma.rs)
+ */
+export function FigmaVariableMode(inner: FigmaVariableMode): FigmaVariableMode {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "figma-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:821`](../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaTextStyle = {
   BaseName: string;
@@ -269,7 +349,7 @@ export type FigmaTextStyle = {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:305`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:821`](../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaTextStyle(inner: FigmaTextStyle): FigmaTextStyle {
   return inner;
@@ -277,7 +357,7 @@ export function FigmaTextStyle(inner: FigmaTextStyle): FigmaTextStyle {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:316`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:832`](../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaTextStyleMatrixGroup = {
   /**
@@ -295,7 +375,7 @@ export type FigmaTextStyleMatrixGroup = {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:316`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:832`](../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaTextStyleMatrixGroup(inner: FigmaTextStyleMatrixGroup): FigmaTextStyleMatrixGroup {
   return inner;
@@ -303,7 +383,7 @@ export function FigmaTextStyleMatrixGroup(inner: FigmaTextStyleMatrixGroup): Fig
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:331`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:847`](../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaTextStyleMatrixOption = {
   Name: string;
@@ -318,7 +398,7 @@ export type FigmaTextStyleMatrixOption = {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:331`](../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:847`](../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaTextStyleMatrixOption(inner: FigmaTextStyleMatrixOption): FigmaTextStyleMatrixOption {
   return inner;
@@ -359,6 +439,11 @@ export type TextRole = {
   FamilyBaseName: string;
   /** e.g. tight = `1.272` or spacious = `1.61803` */
   LineHeightRule: FontFamilyLineHeightRule;
+  /**
+   * For example, `20.0` to keep small sizes of long-form prose readable.
+   * Applied before aligning or snapping the line-height.
+   */
+  LineHeightMinPxOption?: number | undefined | null | null | undefined;
   /** Also called "letter spacing," this is the space between letters for different sizes */
   TrackingRule: FontFamilyTrackingRule;
 };
@@ -373,7 +458,7 @@ export function TextRole(inner: TextRole): TextRole {
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:35`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:38`](../../design-tokens/src/typography/input.rs)
  */
 export type FontFamilyInfo = {
   /** e.g. `"Inter"` or `"Merriweather"` */
@@ -382,6 +467,12 @@ export type FontFamilyInfo = {
   CSSFontFamilyName?: string | undefined | null | null | undefined;
   /** e.g. `"system-ui", "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol", "Arial", "sans-serif"` */
   CSSFontFamilyFallbacks: Array<string>;
+  /**
+   * Metrics for fallbacks, used to generate `@font-face` overrides which reduce layout shift.
+   * Arial, Times New Roman and Courier New are already known.
+   * Generic families like `system-ui` can't be adjusted, since `local()` needs a specific font.
+   */
+  CSSFallbackMetricsOption?: Array<FontFamilyMetrics> | undefined | null | null | undefined;
   /** Required for some systems like Figma, for it to properly select the default text style font name. */
   DefaultRules: Array<FontStyleRule>;
   /** Source for `weight:{value}` tokens. */
@@ -397,7 +488,7 @@ export type FontFamilyInfo = {
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:35`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:38`](../../design-tokens/src/typography/input.rs)
  */
 export function FontFamilyInfo(inner: FontFamilyInfo): FontFamilyInfo {
   return inner;
@@ -405,7 +496,7 @@ export function FontFamilyInfo(inner: FontFamilyInfo): FontFamilyInfo {
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:57`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:64`](../../design-tokens/src/typography/input.rs)
  */
 export type FamilyWeightRule = {
   /**
@@ -424,11 +515,21 @@ export type FamilyWeightRule = {
   Weight: number;
   /** A scalar depending on the requirements of the different generators you're aiming to support */
   FontStyleRule: FontStyleRule;
+  /**
+   * e.g. `"Inter-SemiBold"`, for platforms which load fonts by PostScript name, like SwiftUI.
+   * Defaults to the family name without spaces and the OpenType name of the weight.
+   */
+  PostScriptNameOption?: string | undefined | null | null | undefined;
+  /**
+   * e.g. `"Inter-SemiBoldItalic"`. Defaults like [FamilyWeightRule::PostScriptNameOption],
+   * followed by `"Italic"` (or just `"Inter-Italic"` for `400`).
+   */
+  ItalicPostScriptNameOption?: string | undefined | null | null | undefined;
 };
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:57`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:64`](../../design-tokens/src/typography/input.rs)
  */
 export function FamilyWeightRule(inner: FamilyWeightRule): FamilyWeightRule {
   return inner;
@@ -436,7 +537,7 @@ export function FamilyWeightRule(inner: FamilyWeightRule): FamilyWeightRule {
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:77`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:128`](../../design-tokens/src/typography/input.rs)
  */
 export type FontSizeScale = {
   FontSizes: Array<FontSizeRel>;
@@ -445,11 +546,17 @@ export type FontSizeScale = {
   AlignCapHeightPxOption?: number | undefined | null | null | undefined;
   /** For example, `4.0` for aligning line-heights to 4px. */
   AlignLineHeightPxOption?: number | undefined | null | null | undefined;
+  /**
+   * For example, `4.0` for a 4px baseline grid. Line-heights round up to a multiple of 4px,
+   * and a `BaselineShift` moves the text down so its first baseline lands on the grid.
+   * Uses the family's ascent and descent metrics, and cannot be combined with `AlignLineHeightPxOption`.
+   */
+  SnapBaselineToGridPxOption?: number | undefined | null | null | undefined;
 };
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:77`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:128`](../../design-tokens/src/typography/input.rs)
  */
 export function FontSizeScale(inner: FontSizeScale): FontSizeScale {
   return inner;
@@ -457,7 +564,7 @@ export function FontSizeScale(inner: FontSizeScale): FontSizeScale {
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:89`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:196`](../../design-tokens/src/typography/input.rs)
  */
 export type FontSizeRel = {
   /**
@@ -467,21 +574,98 @@ export type FontSizeRel = {
   Token: string;
   /** e.g. `-2`, `-1`, `0`, `1`, etc. */
   Rel: number;
+  /**
+   * Defaults to aligning the cap height with `AlignCapHeightPxOption`,
+   * or to no rounding for [FontSizeEquation::SizeTablePx].
+   */
+  RoundingOption?: FontSizeRounding | undefined | null | null | undefined;
 };
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:89`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:196`](../../design-tokens/src/typography/input.rs)
  */
 export function FontSizeRel(inner: FontSizeRel): FontSizeRel {
   return inner;
 }
+/**
+ * `#[codegen(tags = "typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/input.rs:210`](../../design-tokens/src/typography/input.rs)
+ */
+// deno-lint-ignore no-namespace
+export namespace FontSizeRounding {
+  export type ApplyFns<R> = {
+    // callbacks
+    /** For example, `1.0` for aligning the cap height to 1px. */
+    CapHeightPx(inner: CapHeightPx["CapHeightPx"]): R;
+    /** For example, `1.0` for whole pixel font sizes. */
+    FontSizePx(inner: FontSizePx["FontSizePx"]): R;
+    /** Use the computed size as is. */
+    Exact(): R,
+  }
+  /** Match helper for {@link FontSizeRounding} */
+  export function apply<R>(
+    to: ApplyFns<R>,
+  ): (input: FontSizeRounding) => R {
+    return function _match(input): R {
+      // if-else strings
+      if (input === "Exact") return to.Exact();
+      // if-else objects
+      if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
+      if ("CapHeightPx" in input) return to.CapHeightPx(input["CapHeightPx"]);
+      if ("FontSizePx" in input) return to.FontSizePx(input["FontSizePx"]);
+      const _exhaust: never = input;
+      throw new TypeError("Unknown object when expected FontSizeRounding");
+    }
+  }
+  /** Match helper for {@link FontSizeRounding} */
+  export function match<R>(
+    input: FontSizeRounding,
+    to: ApplyFns<R>,
+  ): R {
+    return apply(to)(input)
+  }
+  /** For example, `1.0` for aligning the cap height to 1px. */
+  export type CapHeightPx = {
+    /** For example, `1.0` for aligning the cap height to 1px. */
+    CapHeightPx: number
+  };
+  /** For example, `1.0` for aligning the cap height to 1px. */
+  export function CapHeightPx(value: number): CapHeightPx {
+    return { CapHeightPx: value };
+  }
+  /** For example, `1.0` for whole pixel font sizes. */
+  export type FontSizePx = {
+    /** For example, `1.0` for whole pixel font sizes. */
+    FontSizePx: number
+  };
+  /** For example, `1.0` for whole pixel font sizes. */
+  export function FontSizePx(value: number): FontSizePx {
+    return { FontSizePx: value };
+  }
+  /** Use the computed size as is. */
+  export type Exact = "Exact"
+  /** Use the computed size as is. */
+  export function Exact(): Exact {
+    return "Exact";
+  }
+}
+/**
+ * `#[codegen(tags = "typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/input.rs:210`](../../design-tokens/src/typography/input.rs)
+ */
+export type FontSizeRounding =
+  | FontSizeRounding.CapHeightPx
+  | FontSizeRounding.FontSizePx
+  | FontSizeRounding.Exact
 /**
  * WIP: Based on @capsizecss/metrics
  *
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:101`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:223`](../../design-tokens/src/typography/input.rs)
  */
 export type FontFamilyMetrics = {
   familyName: string;
@@ -499,7 +683,7 @@ export type FontFamilyMetrics = {
  *
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:101`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:223`](../../design-tokens/src/typography/input.rs)
  */
 export function FontFamilyMetrics(inner: FontFamilyMetrics): FontFamilyMetrics {
   return inner;
@@ -509,7 +693,7 @@ export function FontFamilyMetrics(inner: FontFamilyMetrics): FontFamilyMetrics {
  *
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:117`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:239`](../../design-tokens/src/typography/input.rs)
  */
 // deno-lint-ignore no-namespace
 export namespace FontFamilyTrackingRule {
@@ -517,6 +701,18 @@ export namespace FontFamilyTrackingRule {
     // callbacks
     /** Determine your tracking goals via a page like https://rsms.me/inter/dynmetrics/ */
     DynMetrics(inner: DynMetrics["DynMetrics"]): R,
+    /** The same tracking for every size, e.g. `0.0` for monospace families like Roboto Mono. */
+    ConstantEm(inner: ConstantEm["ConstantEm"]): R,
+    /**
+     * Explicit tracking for each `size:{}` token, e.g. from a foundry provided table.
+     * Every size in the [FontSizeScale] must have an entry.
+     */
+    SizeTableEm(inner: SizeTableEm["SizeTableEm"]): R,
+    /**
+     * Linearly interpolate tracking between anchor font sizes.
+     * Font sizes outside of the anchors use the tracking of the nearest anchor.
+     */
+    InterpolateEm(inner: InterpolateEm["InterpolateEm"]): R,
   }
   /** Match helper for {@link FontFamilyTrackingRule} */
   export function apply<R>(
@@ -527,6 +723,9 @@ export namespace FontFamilyTrackingRule {
       // if-else objects
       if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
       if ("DynMetrics" in input) return to.DynMetrics(input["DynMetrics"]);
+      if ("ConstantEm" in input) return to.ConstantEm(input["ConstantEm"]);
+      if ("SizeTableEm" in input) return to.SizeTableEm(input["SizeTableEm"]);
+      if ("InterpolateEm" in input) return to.InterpolateEm(input["InterpolateEm"]);
       const _exhaust: never = input;
       throw new TypeError("Unknown object when expected FontFamilyTrackingRule");
     }
@@ -551,20 +750,113 @@ export namespace FontFamilyTrackingRule {
   export function DynMetrics(value: DynMetrics["DynMetrics"]): DynMetrics {
     return { DynMetrics: value }
   }
+  /** The same tracking for every size, e.g. `0.0` for monospace families like Roboto Mono. */
+  export type ConstantEm = {
+    /** The same tracking for every size, e.g. `0.0` for monospace families like Roboto Mono. */
+    ConstantEm: {
+      em: number;
+    };
+  };
+  /** The same tracking for every size, e.g. `0.0` for monospace families like Roboto Mono. */
+  export function ConstantEm(value: ConstantEm["ConstantEm"]): ConstantEm {
+    return { ConstantEm: value }
+  }
+  /**
+   * Explicit tracking for each `size:{}` token, e.g. from a foundry provided table.
+   * Every size in the [FontSizeScale] must have an entry.
+   */
+  export type SizeTableEm = {
+    /**
+     * Explicit tracking for each `size:{}` token, e.g. from a foundry provided table.
+     * Every size in the [FontSizeScale] must have an entry.
+     */
+    SizeTableEm: {
+      sizes: Array<SizeTokenValue>;
+    };
+  };
+  /**
+   * Explicit tracking for each `size:{}` token, e.g. from a foundry provided table.
+   * Every size in the [FontSizeScale] must have an entry.
+   */
+  export function SizeTableEm(value: SizeTableEm["SizeTableEm"]): SizeTableEm {
+    return { SizeTableEm: value }
+  }
+  /**
+   * Linearly interpolate tracking between anchor font sizes.
+   * Font sizes outside of the anchors use the tracking of the nearest anchor.
+   */
+  export type InterpolateEm = {
+    /**
+     * Linearly interpolate tracking between anchor font sizes.
+     * Font sizes outside of the anchors use the tracking of the nearest anchor.
+     */
+    InterpolateEm: {
+      anchors: Array<TrackingAnchor>;
+    };
+  };
+  /**
+   * Linearly interpolate tracking between anchor font sizes.
+   * Font sizes outside of the anchors use the tracking of the nearest anchor.
+   */
+  export function InterpolateEm(value: InterpolateEm["InterpolateEm"]): InterpolateEm {
+    return { InterpolateEm: value }
+  }
 }
 /**
  * WIP: Based on @capsizecss/metrics
  *
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:117`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:239`](../../design-tokens/src/typography/input.rs)
  */
 export type FontFamilyTrackingRule =
   | FontFamilyTrackingRule.DynMetrics
+  | FontFamilyTrackingRule.ConstantEm
+  | FontFamilyTrackingRule.SizeTableEm
+  | FontFamilyTrackingRule.InterpolateEm
 /**
+ * A value for a specific `size:{}` token, e.g. `{ Size: "xs", Value: 0.02 }`.
+ *
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:134`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:256`](../../design-tokens/src/typography/input.rs)
+ */
+export type SizeTokenValue = {
+  /** e.g. `"xs"` for `size:xs` */
+  Size: string;
+  Value: number;
+};
+/**
+ * A value for a specific `size:{}` token, e.g. `{ Size: "xs", Value: 0.02 }`.
+ *
+ * `#[codegen(tags = "typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/input.rs:256`](../../design-tokens/src/typography/input.rs)
+ */
+export function SizeTokenValue(inner: SizeTokenValue): SizeTokenValue {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/input.rs:265`](../../design-tokens/src/typography/input.rs)
+ */
+export type TrackingAnchor = {
+  FontSizePx: number;
+  TrackingEm: number;
+};
+/**
+ * `#[codegen(tags = "typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/input.rs:265`](../../design-tokens/src/typography/input.rs)
+ */
+export function TrackingAnchor(inner: TrackingAnchor): TrackingAnchor {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/input.rs:333`](../../design-tokens/src/typography/input.rs)
  */
 // deno-lint-ignore no-namespace
 export namespace FontFamilyLineHeightRule {
@@ -572,6 +864,15 @@ export namespace FontFamilyLineHeightRule {
     // callbacks
     /** Determine your tracking goals via a page like https://rsms.me/inter/dynmetrics/ */
     FontSizePxMultipler(inner: FontSizePxMultipler["FontSizePxMultipler"]): R,
+    /**
+     * Explicit line-height for each `size:{}` token.
+     * Every size in the [FontSizeScale] must have an entry.
+     */
+    SizeTablePx(inner: SizeTablePx["SizeTablePx"]): R,
+    /** e.g. `2.5` times the cap height for dense UI */
+    CapHeightMultiplier(inner: CapHeightMultiplier["CapHeightMultiplier"]): R,
+    /** e.g. `8.0` for a `16px` font size to have a `24px` line-height */
+    FontSizePlusPx(inner: FontSizePlusPx["FontSizePlusPx"]): R,
   }
   /** Match helper for {@link FontFamilyLineHeightRule} */
   export function apply<R>(
@@ -582,6 +883,9 @@ export namespace FontFamilyLineHeightRule {
       // if-else objects
       if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
       if ("FontSizePxMultipler" in input) return to.FontSizePxMultipler(input["FontSizePxMultipler"]);
+      if ("SizeTablePx" in input) return to.SizeTablePx(input["SizeTablePx"]);
+      if ("CapHeightMultiplier" in input) return to.CapHeightMultiplier(input["CapHeightMultiplier"]);
+      if ("FontSizePlusPx" in input) return to.FontSizePlusPx(input["FontSizePlusPx"]);
       const _exhaust: never = input;
       throw new TypeError("Unknown object when expected FontFamilyLineHeightRule");
     }
@@ -604,26 +908,84 @@ export namespace FontFamilyLineHeightRule {
   export function FontSizePxMultipler(value: FontSizePxMultipler["FontSizePxMultipler"]): FontSizePxMultipler {
     return { FontSizePxMultipler: value }
   }
+  /**
+   * Explicit line-height for each `size:{}` token.
+   * Every size in the [FontSizeScale] must have an entry.
+   */
+  export type SizeTablePx = {
+    /**
+     * Explicit line-height for each `size:{}` token.
+     * Every size in the [FontSizeScale] must have an entry.
+     */
+    SizeTablePx: {
+      sizes: Array<SizeTokenValue>;
+    };
+  };
+  /**
+   * Explicit line-height for each `size:{}` token.
+   * Every size in the [FontSizeScale] must have an entry.
+   */
+  export function SizeTablePx(value: SizeTablePx["SizeTablePx"]): SizeTablePx {
+    return { SizeTablePx: value }
+  }
+  /** e.g. `2.5` times the cap height for dense UI */
+  export type CapHeightMultiplier = {
+    /** e.g. `2.5` times the cap height for dense UI */
+    CapHeightMultiplier: {
+      multiplier: number;
+    };
+  };
+  /** e.g. `2.5` times the cap height for dense UI */
+  export function CapHeightMultiplier(value: CapHeightMultiplier["CapHeightMultiplier"]): CapHeightMultiplier {
+    return { CapHeightMultiplier: value }
+  }
+  /** e.g. `8.0` for a `16px` font size to have a `24px` line-height */
+  export type FontSizePlusPx = {
+    /** e.g. `8.0` for a `16px` font size to have a `24px` line-height */
+    FontSizePlusPx: {
+      px: number;
+    };
+  };
+  /** e.g. `8.0` for a `16px` font size to have a `24px` line-height */
+  export function FontSizePlusPx(value: FontSizePlusPx["FontSizePlusPx"]): FontSizePlusPx {
+    return { FontSizePlusPx: value }
+  }
 }
 /**
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:134`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:333`](../../design-tokens/src/typography/input.rs)
  */
 export type FontFamilyLineHeightRule =
   | FontFamilyLineHeightRule.FontSizePxMultipler
+  | FontFamilyLineHeightRule.SizeTablePx
+  | FontFamilyLineHeightRule.CapHeightMultiplier
+  | FontFamilyLineHeightRule.FontSizePlusPx
 /**
  * WIP: Based on ratioInterval
  *
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:154`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:379`](../../design-tokens/src/typography/input.rs)
  */
 // deno-lint-ignore no-namespace
 export namespace FontSizeEquation {
   export type ApplyFns<R> = {
     // callbacks
     Multiplier(inner: Multiplier["Multiplier"]): R,
+    /** Same as `Multiplier`, but with a well known ratio. */
+    ModularScale(inner: ModularScale["ModularScale"]): R,
+    /**
+     * Use a different multiplier for sizes above and below the base,
+     * e.g. subtle steps for small text and dramatic steps for headings.
+     */
+    TwoRatio(inner: TwoRatio["TwoRatio"]): R,
+    /**
+     * Explicit font sizes (not cap heights) for each `size:{}` token,
+     * e.g. for when `lg` should be exactly `20px`.
+     * Every size in the [FontSizeScale] must have an entry.
+     */
+    SizeTablePx(inner: SizeTablePx["SizeTablePx"]): R,
   }
   /** Match helper for {@link FontSizeEquation} */
   export function apply<R>(
@@ -634,6 +996,9 @@ export namespace FontSizeEquation {
       // if-else objects
       if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
       if ("Multiplier" in input) return to.Multiplier(input["Multiplier"]);
+      if ("ModularScale" in input) return to.ModularScale(input["ModularScale"]);
+      if ("TwoRatio" in input) return to.TwoRatio(input["TwoRatio"]);
+      if ("SizeTablePx" in input) return to.SizeTablePx(input["SizeTablePx"]);
       const _exhaust: never = input;
       throw new TypeError("Unknown object when expected FontSizeEquation");
     }
@@ -659,13 +1024,742 @@ export namespace FontSizeEquation {
   export function Multiplier(value: Multiplier["Multiplier"]): Multiplier {
     return { Multiplier: value }
   }
+  /** Same as `Multiplier`, but with a well known ratio. */
+  export type ModularScale = {
+    /** Same as `Multiplier`, but with a well known ratio. */
+    ModularScale: {
+      base_px: number;
+      scale: ModularScale;
+    };
+  };
+  /** Same as `Multiplier`, but with a well known ratio. */
+  export function ModularScale(value: ModularScale["ModularScale"]): ModularScale {
+    return { ModularScale: value }
+  }
+  /**
+   * Use a different multiplier for sizes above and below the base,
+   * e.g. subtle steps for small text and dramatic steps for headings.
+   */
+  export type TwoRatio = {
+    /**
+     * Use a different multiplier for sizes above and below the base,
+     * e.g. subtle steps for small text and dramatic steps for headings.
+     */
+    TwoRatio: {
+      base_px: number;
+      /** Applied for each `Rel` above `0` */
+      multiplier_above: number;
+      /** Applied for each `Rel` below `0` */
+      multiplier_below: number;
+    };
+  };
+  /**
+   * Use a different multiplier for sizes above and below the base,
+   * e.g. subtle steps for small text and dramatic steps for headings.
+   */
+  export function TwoRatio(value: TwoRatio["TwoRatio"]): TwoRatio {
+    return { TwoRatio: value }
+  }
+  /**
+   * Explicit font sizes (not cap heights) for each `size:{}` token,
+   * e.g. for when `lg` should be exactly `20px`.
+   * Every size in the [FontSizeScale] must have an entry.
+   */
+  export type SizeTablePx = {
+    /**
+     * Explicit font sizes (not cap heights) for each `size:{}` token,
+     * e.g. for when `lg` should be exactly `20px`.
+     * Every size in the [FontSizeScale] must have an entry.
+     */
+    SizeTablePx: {
+      sizes: Array<SizeTokenValue>;
+    };
+  };
+  /**
+   * Explicit font sizes (not cap heights) for each `size:{}` token,
+   * e.g. for when `lg` should be exactly `20px`.
+   * Every size in the [FontSizeScale] must have an entry.
+   */
+  export function SizeTablePx(value: SizeTablePx["SizeTablePx"]): SizeTablePx {
+    return { SizeTablePx: value }
+  }
 }
 /**
  * WIP: Based on ratioInterval
  *
  * `#[codegen(tags = "typography-input")]`
  *
- * [Source `design-tokens/src/typography/input.rs:154`](../../design-tokens/src/typography/input.rs)
+ * [Source `design-tokens/src/typography/input.rs:379`](../../design-tokens/src/typography/input.rs)
  */
 export type FontSizeEquation =
-  | FontSizeEquation.Multiplier
\ No newline at end of file
+  | FontSizeEquation.Multiplier
+  | FontSizeEquation.ModularScale
+  | FontSizeEquation.TwoRatio
+  | FontSizeEquation.SizeTablePx
+/**
+ * See https://www.modularscale.com/ for a comparison of these scales.
+ *
+ * `#[codegen(tags = "typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/input.rs:408`](../../design-tokens/src/typography/input.rs)
+ */
+// deno-lint-ignore no-namespace
+export namespace ModularScale {
+  export type ApplyFns<R> = {
+    // callbacks
+    /** `16:15` */
+    MinorSecond(): R,
+    /** `9:8` */
+    MajorSecond(): R,
+    /** `6:5` */
+    MinorThird(): R,
+    /** `5:4` */
+    MajorThird(): R,
+    /** `4:3` */
+    PerfectFourth(): R,
+    /** `√2` */
+    AugmentedFourth(): R,
+    /** `3:2` */
+    PerfectFifth(): R,
+    /** `1.618:1` */
+    GoldenRatio(): R,
+  }
+  /** Match helper for {@link ModularScale} */
+  export function apply<R>(
+    to: ApplyFns<R>,
+  ): (input: ModularScale) => R {
+    return function _match(input): R {
+      // if-else strings
+      if (input === "MinorSecond") return to.MinorSecond();
+      if (input === "MajorSecond") return to.MajorSecond();
+      if (input === "MinorThird") return to.MinorThird();
+      if (input === "MajorThird") return to.MajorThird();
+      if (input === "PerfectFourth") return to.PerfectFourth();
+      if (input === "AugmentedFourth") return to.AugmentedFourth();
+      if (input === "PerfectFifth") return to.PerfectFifth();
+      if (input === "GoldenRatio") return to.GoldenRatio();
+      // if-else objects
+      if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
+      const _exhaust: never = input;
+      throw new TypeError("Unknown object when expected ModularScale");
+    }
+  }
+  /** Match helper for {@link ModularScale} */
+  export function match<R>(
+    input: ModularScale,
+    to: ApplyFns<R>,
+  ): R {
+    return apply(to)(input)
+  }
+  /** `16:15` */
+  export type MinorSecond = "MinorSecond"
+  /** `16:15` */
+  export function MinorSecond(): MinorSecond {
+    return "MinorSecond";
+  }
+  /** `9:8` */
+  export type MajorSecond = "MajorSecond"
+  /** `9:8` */
+  export function MajorSecond(): MajorSecond {
+    return "MajorSecond";
+  }
+  /** `6:5` */
+  export type MinorThird = "MinorThird"
+  /** `6:5` */
+  export function MinorThird(): MinorThird {
+    return "MinorThird";
+  }
+  /** `5:4` */
+  export type MajorThird = "MajorThird"
+  /** `5:4` */
+  export function MajorThird(): MajorThird {
+    return "MajorThird";
+  }
+  /** `4:3` */
+  export type PerfectFourth = "PerfectFourth"
+  /** `4:3` */
+  export function PerfectFourth(): PerfectFourth {
+    return "PerfectFourth";
+  }
+  /** `√2` */
+  export type AugmentedFourth = "AugmentedFourth"
+  /** `√2` */
+  export function AugmentedFourth(): AugmentedFourth {
+    return "AugmentedFourth";
+  }
+  /** `3:2` */
+  export type PerfectFifth = "PerfectFifth"
+  /** `3:2` */
+  export function PerfectFifth(): PerfectFifth {
+    return "PerfectFifth";
+  }
+  /** `1.618:1` */
+  export type GoldenRatio = "GoldenRatio"
+  /** `1.618:1` */
+  export function GoldenRatio(): GoldenRatio {
+    return "GoldenRatio";
+  }
+}
+/**
+ * See https://www.modularscale.com/ for a comparison of these scales.
+ *
+ * `#[codegen(tags = "typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/input.rs:408`](../../design-tokens/src/typography/input.rs)
+ */
+export type ModularScale =
+  | ModularScale.MinorSecond
+  | ModularScale.MajorSecond
+  | ModularScale.MinorThird
+  | ModularScale.MajorThird
+  | ModularScale.PerfectFourth
+  | ModularScale.AugmentedFourth
+  | ModularScale.PerfectFifth
+  | ModularScale.GoldenRatio
+/**
+ * `#[codegen(tags = "typography-units-input")]`
+ *
+ * [Source `design-tokens/src/typography/units.rs:7`](../../design-tokens/src/typography/units.rs)
+ */
+export type TypographyUnits = {
+  /** Defaults to `16.0`, which is the default root font size of browsers. */
+  RootFontSizePxOption?: number | undefined | null | null | undefined;
+  /** Defaults to `Px`. Use `Rem` to respect the user's font size settings. */
+  FontSizeOption?: FontSizeUnit | undefined | null | null | undefined;
+  /** Defaults to `Px`. */
+  LetterSpacingOption?: LetterSpacingUnit | undefined | null | null | undefined;
+  /** Defaults to `Px`. */
+  LineHeightOption?: LineHeightUnit | undefined | null | null | undefined;
+};
+/**
+ * `#[codegen(tags = "typography-units-input")]`
+ *
+ * [Source `design-tokens/src/typography/units.rs:7`](../../design-tokens/src/typography/units.rs)
+ */
+export function TypographyUnits(inner: TypographyUnits): TypographyUnits {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "typography-units-input")]`
+ *
+ * [Source `design-tokens/src/typography/units.rs:21`](../../design-tokens/src/typography/units.rs)
+ */
+// deno-lint-ignore no-namespace
+export namespace FontSizeUnit {
+  export type ApplyFns<R> = {
+    // callbacks
+    Px(): R,
+    /** Relative to the root font size */
+    Rem(): R,
+  }
+  /** Match helper for {@link FontSizeUnit} */
+  export function apply<R>(
+    to: ApplyFns<R>,
+  ): (input: FontSizeUnit) => R {
+    return function _match(input): R {
+      // if-else strings
+      if (input === "Px") return to.Px();
+      if (input === "Rem") return to.Rem();
+      // if-else objects
+      if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
+      const _exhaust: never = input;
+      throw new TypeError("Unknown object when expected FontSizeUnit");
+    }
+  }
+  /** Match helper for {@link FontSizeUnit} */
+  export function match<R>(
+    input: FontSizeUnit,
+    to: ApplyFns<R>,
+  ): R {
+    return apply(to)(input)
+  }
+  export type Px = "Px"
+  export function Px(): Px {
+    return "Px";
+  }
+  /** Relative to the root font size */
+  export type Rem = "Rem"
+  /** Relative to the root font size */
+  export function Rem(): Rem {
+    return "Rem";
+  }
+}
+/**
+ * `#[codegen(tags = "typography-units-input")]`
+ *
+ * [Source `design-tokens/src/typography/units.rs:21`](../../design-tokens/src/typography/units.rs)
+ */
+export type FontSizeUnit =
+  | FontSizeUnit.Px
+  | FontSizeUnit.Rem
+/**
+ * `#[codegen(tags = "typography-units-input")]`
+ *
+ * [Source `design-tokens/src/typography/units.rs:29`](../../design-tokens/src/typography/units.rs)
+ */
+// deno-lint-ignore no-namespace
+export namespace LetterSpacingUnit {
+  export type ApplyFns<R> = {
+    // callbacks
+    Px(): R,
+    /** Relative to the font size, so it scales along with `rem` font sizes */
+    Em(): R,
+  }
+  /** Match helper for {@link LetterSpacingUnit} */
+  export function apply<R>(
+    to: ApplyFns<R>,
+  ): (input: LetterSpacingUnit) => R {
+    return function _match(input): R {
+      // if-else strings
+      if (input === "Px") return to.Px();
+      if (input === "Em") return to.Em();
+      // if-else objects
+      if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
+      const _exhaust: never = input;
+      throw new TypeError("Unknown object when expected LetterSpacingUnit");
+    }
+  }
+  /** Match helper for {@link LetterSpacingUnit} */
+  export function match<R>(
+    input: LetterSpacingUnit,
+    to: ApplyFns<R>,
+  ): R {
+    return apply(to)(input)
+  }
+  export type Px = "Px"
+  export function Px(): Px {
+    return "Px";
+  }
+  /** Relative to the font size, so it scales along with `rem` font sizes */
+  export type Em = "Em"
+  /** Relative to the font size, so it scales along with `rem` font sizes */
+  export function Em(): Em {
+    return "Em";
+  }
+}
+/**
+ * `#[codegen(tags = "typography-units-input")]`
+ *
+ * [Source `design-tokens/src/typography/units.rs:29`](../../design-tokens/src/typography/units.rs)
+ */
+export type LetterSpacingUnit =
+  | LetterSpacingUnit.Px
+  | LetterSpacingUnit.Em
+/**
+ * `#[codegen(tags = "typography-units-input")]`
+ *
+ * [Source `design-tokens/src/typography/units.rs:37`](../../design-tokens/src/typography/units.rs)
+ */
+// deno-lint-ignore no-namespace
+export namespace LineHeightUnit {
+  export type ApplyFns<R> = {
+    // callbacks
+    Px(): R,
+    /** Relative to the root font size */
+    Rem(): R,
+    /** Relative to the font size, e.g. `1.5` */
+    Unitless(): R,
+  }
+  /** Match helper for {@link LineHeightUnit} */
+  export function apply<R>(
+    to: ApplyFns<R>,
+  ): (input: LineHeightUnit) => R {
+    return function _match(input): R {
+      // if-else strings
+      if (input === "Px") return to.Px();
+      if (input === "Rem") return to.Rem();
+      if (input === "Unitless") return to.Unitless();
+      // if-else objects
+      if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
+      const _exhaust: never = input;
+      throw new TypeError("Unknown object when expected LineHeightUnit");
+    }
+  }
+  /** Match helper for {@link LineHeightUnit} */
+  export function match<R>(
+    input: LineHeightUnit,
+    to: ApplyFns<R>,
+  ): R {
+    return apply(to)(input)
+  }
+  export type Px = "Px"
+  export function Px(): Px {
+    return "Px";
+  }
+  /** Relative to the root font size */
+  export type Rem = "Rem"
+  /** Relative to the root font size */
+  export function Rem(): Rem {
+    return "Rem";
+  }
+  /** Relative to the font size, e.g. `1.5` */
+  export type Unitless = "Unitless"
+  /** Relative to the font size, e.g. `1.5` */
+  export function Unitless(): Unitless {
+    return "Unitless";
+  }
+}
+/**
+ * `#[codegen(tags = "typography-units-input")]`
+ *
+ * [Source `design-tokens/src/typography/units.rs:37`](../../design-tokens/src/typography/units.rs)
+ */
+export type LineHeightUnit =
+  | LineHeightUnit.Px
+  | LineHeightUnit.Rem
+  | LineHeightUnit.Unitless
+/**
+ * `#[codegen(tags = "input")]`
+ *
+ * [Source `design-tokens/src/lib.rs:23`](../../design-tokens/src/lib.rs)
+ */
+export type SystemInput = {
+  color_palette?: ColorPalette | undefined | null | null | undefined;
+  /** e.g. `role:heading` referencing `role:ui weight:600` */
+  aliases?: Array<TokenAliasInput> | undefined | null | null | undefined;
+  typography: BaseTypographyInput;
+};
+/**
+ * `#[codegen(tags = "input")]`
+ *
+ * [Source `design-tokens/src/lib.rs:23`](../../design-tokens/src/lib.rs)
+ */
+export function SystemInput(inner: SystemInput): SystemInput {
+  return inner;
+}
+/**
+ * TODO: adjust API/Configuration to be more accomodating
+ * of other color generation strategies than just Material You.
+ *
+ * `#[codegen(tags = "input,color")]`
+ *
+ * [Source `design-tokens/src/color/input.rs:5`](../../design-tokens/src/color/input.rs)
+ */
+export type ColorPalette = {
+  Primary: InputColor;
+  Extensions: Array<ColorExtension>;
+};
+/**
+ * TODO: adjust API/Configuration to be more accomodating
+ * of other color generation strategies than just Material You.
+ *
+ * `#[codegen(tags = "input,color")]`
+ *
+ * [Source `design-tokens/src/color/input.rs:5`](../../design-tokens/src/color/input.rs)
+ */
+export function ColorPalette(inner: ColorPalette): ColorPalette {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "input,color")]`
+ *
+ * [Source `design-tokens/src/color/input.rs:13`](../../design-tokens/src/color/input.rs)
+ */
+export type ColorExtension = {
+  /** e.g. `"blue"` */
+  Token: string;
+  Source: SourceColor;
+};
+/**
+ * `#[codegen(tags = "input,color")]`
+ *
+ * [Source `design-tokens/src/color/input.rs:13`](../../design-tokens/src/color/input.rs)
+ */
+export function ColorExtension(inner: ColorExtension): ColorExtension {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "input,color")]`
+ *
+ * [Source `design-tokens/src/color/input.rs:22`](../../design-tokens/src/color/input.rs)
+ */
+// deno-lint-ignore no-namespace
+export namespace SourceColor {
+  export type ApplyFns<R> = {
+    // callbacks
+    /**
+     * Harmonized with the primary color, shifting its hue up to 15° toward the primary
+     * like Material's custom colors, so it fits in with the rest of the palette.
+     */
+    SimilarTo(inner: SimilarTo["SimilarTo"]): R;
+    /** Used as is, and also available as a color like `color:blue`. */
+    Exactly(inner: Exactly["Exactly"]): R;
+  }
+  /** Match helper for {@link SourceColor} */
+  export function apply<R>(
+    to: ApplyFns<R>,
+  ): (input: SourceColor) => R {
+    return function _match(input): R {
+      // if-else strings
+      // if-else objects
+      if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
+      if ("SimilarTo" in input) return to.SimilarTo(input["SimilarTo"]);
+      if ("Exactly" in input) return to.Exactly(input["Exactly"]);
+      const _exhaust: never = input;
+      throw new TypeError("Unknown object when expected SourceColor");
+    }
+  }
+  /** Match helper for {@link SourceColor} */
+  export function match<R>(
+    input: SourceColor,
+    to: ApplyFns<R>,
+  ): R {
+    return apply(to)(input)
+  }
+  /**
+   * Harmonized with the primary color, shifting its hue up to 15° toward the primary
+   * like Material's custom colors, so it fits in with the rest of the palette.
+   */
+  export type SimilarTo = {
+    /**
+     * Harmonized with the primary color, shifting its hue up to 15° toward the primary
+     * like Material's custom colors, so it fits in with the rest of the palette.
+     */
+    SimilarTo: InputColor
+  };
+  /**
+   * Harmonized with the primary color, shifting its hue up to 15° toward the primary
+   * like Material's custom colors, so it fits in with the rest of the palette.
+   */
+  export function SimilarTo(value: InputColor): SimilarTo {
+    return { SimilarTo: value };
+  }
+  /** Used as is, and also available as a color like `color:blue`. */
+  export type Exactly = {
+    /** Used as is, and also available as a color like `color:blue`. */
+    Exactly: InputColor
+  };
+  /** Used as is, and also available as a color like `color:blue`. */
+  export function Exactly(value: InputColor): Exactly {
+    return { Exactly: value };
+  }
+}
+/**
+ * `#[codegen(tags = "input,color")]`
+ *
+ * [Source `design-tokens/src/color/input.rs:22`](../../design-tokens/src/color/input.rs)
+ */
+export type SourceColor =
+  | SourceColor.SimilarTo
+  | SourceColor.Exactly
+/**
+ * `#[codegen(tags = "input,color")]`
+ *
+ * [Source `design-tokens/src/color/input.rs:32`](../../design-tokens/src/color/input.rs)
+ */
+// deno-lint-ignore no-namespace
+export namespace InputColor {
+  export type ApplyFns<R> = {
+    // callbacks
+    Hex(inner: Hex["Hex"]): R;
+  }
+  /** Match helper for {@link InputColor} */
+  export function apply<R>(
+    to: ApplyFns<R>,
+  ): (input: InputColor) => R {
+    return function _match(input): R {
+      // if-else strings
+      // if-else objects
+      if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
+      if ("Hex" in input) return to.Hex(input["Hex"]);
+      const _exhaust: never = input;
+      throw new TypeError("Unknown object when expected InputColor");
+    }
+  }
+  /** Match helper for {@link InputColor} */
+  export function match<R>(
+    input: InputColor,
+    to: ApplyFns<R>,
+  ): R {
+    return apply(to)(input)
+  }
+  export type Hex = {
+    Hex: string
+  };
+  export function Hex(value: string): Hex {
+    return { Hex: value };
+  }
+}
+/**
+ * `#[codegen(tags = "input,color")]`
+ *
+ * [Source `design-tokens/src/color/input.rs:32`](../../design-tokens/src/color/input.rs)
+ */
+export type InputColor =
+  | InputColor.Hex
+/**
+ * `#[codegen(tags = "input")]`
+ *
+ * [Source `design-tokens/src/tokens/aliases.rs:9`](../../design-tokens/src/tokens/aliases.rs)
+ */
+export type TokenAliasInput = {
+  /** e.g. `"role:heading"` */
+  token: string;
+  /** e.g. `"role:ui weight:600"`, which may include other aliases. */
+  references: string;
+};
+/**
+ * `#[codegen(tags = "input")]`
+ *
+ * [Source `design-tokens/src/tokens/aliases.rs:9`](../../design-tokens/src/tokens/aliases.rs)
+ */
+export function TokenAliasInput(inner: TokenAliasInput): TokenAliasInput {
+  return inner;
+}
+/**
+ * `#[codegen(ts_interface_merge, tags = "style-dictionary-input")]`
+ *
+ * [Source `design-tokens/src/style_dictionary.rs:11`](../../design-tokens/src/style_dictionary.rs)
+ */
+export interface TypographyExtensionInput {
+  /** `#[serde(alias = "styleDictionary")]` */
+  StyleDictionary?: StyleDictionaryConfig | undefined | null | null | undefined;
+}
+/**
+ * `#[codegen(tags = "style-dictionary-input")]`
+ *
+ * [Source `design-tokens/src/style_dictionary.rs:20`](../../design-tokens/src/style_dictionary.rs)
+ */
+export type StyleDictionaryConfig = {
+  /**
+   * Order of token keys in each name path, e.g. `["size", "role"]` names
+   * `text role:ui size:sm italic:true` as `text.size/sm.role/ui.italic`.
+   * Other keys follow in alphabetical order.
+   */
+  PathKeysOption?: Array<string> | undefined | null | null | undefined;
+};
+/**
+ * `#[codegen(tags = "style-dictionary-input")]`
+ *
+ * [Source `design-tokens/src/style_dictionary.rs:20`](../../design-tokens/src/style_dictionary.rs)
+ */
+export function StyleDictionaryConfig(inner: StyleDictionaryConfig): StyleDictionaryConfig {
+  return inner;
+}
+/**
+ * `#[codegen(ts_interface_merge, tags = "compose-input")]`
+ *
+ * [Source `design-tokens/src/platforms/compose.rs:19`](../../design-tokens/src/platforms/compose.rs)
+ */
+export interface TypographyExtensionInput {
+  /** `#[serde(alias = "compose")]` */
+  Compose?: ComposeConfig | undefined | null | null | undefined;
+}
+/**
+ * `#[codegen(tags = "compose-input")]`
+ *
+ * [Source `design-tokens/src/platforms/compose.rs:28`](../../design-tokens/src/platforms/compose.rs)
+ */
+export type ComposeConfig = {
+  /** e.g. `"com.example.designsystem"`. Defaults to `"designtokens"`. */
+  PackageOption?: string | undefined | null | null | undefined;
+  /**
+   * Which text style fills each Material type role, e.g. `bodyLarge` from `text role:content size:lg`.
+   * Roles left out keep the Material defaults.
+   */
+  MaterialTypographyOption?: Array<ComposeMaterialTypeRole> | undefined | null | null | undefined;
+};
+/**
+ * `#[codegen(tags = "compose-input")]`
+ *
+ * [Source `design-tokens/src/platforms/compose.rs:28`](../../design-tokens/src/platforms/compose.rs)
+ */
+export function ComposeConfig(inner: ComposeConfig): ComposeConfig {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "compose-input")]`
+ *
+ * [Source `design-tokens/src/platforms/compose.rs:39`](../../design-tokens/src/platforms/compose.rs)
+ */
+export type ComposeMaterialTypeRole = {
+  /** e.g. `"bodyLarge"` or `"labelSmall"` */
+  Role: string;
+  /** e.g. `"text role:content size:lg"` */
+  Tokens: string;
+};
+/**
+ * `#[codegen(tags = "compose-input")]`
+ *
+ * [Source `design-tokens/src/platforms/compose.rs:39`](../../design-tokens/src/platforms/compose.rs)
+ */
+export function ComposeMaterialTypeRole(inner: ComposeMaterialTypeRole): ComposeMaterialTypeRole {
+  return inner;
+}
+/**
+ * `#[codegen(ts_interface_merge, tags = "flutter-input")]`
+ *
+ * [Source `design-tokens/src/platforms/flutter.rs:19`](../../design-tokens/src/platforms/flutter.rs)
+ */
+export interface TypographyExtensionInput {
+  /** `#[serde(alias = "flutter")]` */
+  Flutter?: FlutterConfig | undefined | null | null | undefined;
+}
+/**
+ * `#[codegen(tags = "flutter-input")]`
+ *
+ * [Source `design-tokens/src/platforms/flutter.rs:28`](../../design-tokens/src/platforms/flutter.rs)
+ */
+export type FlutterConfig = {
+  /**
+   * Which text style fills each `TextTheme` role, e.g. `bodyLarge` from `text role:content size:lg`.
+   * Roles left out keep the Material defaults.
+   */
+  TextThemeOption?: Array<FlutterTextThemeRole> | undefined | null | null | undefined;
+};
+/**
+ * `#[codegen(tags = "flutter-input")]`
+ *
+ * [Source `design-tokens/src/platforms/flutter.rs:28`](../../design-tokens/src/platforms/flutter.rs)
+ */
+export function FlutterConfig(inner: FlutterConfig): FlutterConfig {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "flutter-input")]`
+ *
+ * [Source `design-tokens/src/platforms/flutter.rs:37`](../../design-tokens/src/platforms/flutter.rs)
+ */
+export type FlutterTextThemeRole = {
+  /** e.g. `"bodyLarge"` or `"labelSmall"` */
+  Role: string;
+  /** e.g. `"text role:content size:lg"` */
+  Tokens: string;
+};
+/**
+ * `#[codegen(tags = "flutter-input")]`
+ *
+ * [Source `design-tokens/src/platforms/flutter.rs:37`](../../design-tokens/src/platforms/flutter.rs)
+ */
+export function FlutterTextThemeRole(inner: FlutterTextThemeRole): FlutterTextThemeRole {
+  return inner;
+}
+/**
+ * `#[codegen(ts_interface_merge, tags = "rust-input")]`
+ *
+ * [Source `design-tokens/src/platforms/rust.rs:30`](../../design-tokens/src/platforms/rust.rs)
+ */
+export interface TypographyExtensionInput {
+  /** `#[serde(alias = "rust")]` */
+  Rust?: RustConfig | undefined | null | null | undefined;
+}
+/**
+ * `#[codegen(tags = "rust-input")]`
+ *
+ * [Source `design-tokens/src/platforms/rust.rs:39`](../../design-tokens/src/platforms/rust.rs)
+ */
+export type RustConfig = {
+  /**
+   * Conversions to generate, each behind the app's feature of the same name,
+   * e.g. `["egui", "bevy"]`. Supports `"egui"`, `"iced"`, and `"bevy"`.
+   */
+  FrameworksOption?: Array<string> | undefined | null | null | undefined;
+};
+/**
+ * `#[codegen(tags = "rust-input")]`
+ *
+ * [Source `design-tokens/src/platforms/rust.rs:39`](../../design-tokens/src/platforms/rust.rs)
+ */
+export function RustConfig(inner: RustConfig): RustConfig {
+  return inner;
+}
\ No newline at end of file
@@ -160,7 +160,7 @@ export function FontStyleRule(value: FontStyleRule): FontStyleRule {
 /**
  * `#[codegen(tags = "figma-typography-export")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:53`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:57`](../../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaPluginCommand = {
   figma_plugin: FigmaPluginCommandOperation;
@@ -168,7 +168,7 @@ export type FigmaPluginCommand = {
 /**
  * `#[codegen(tags = "figma-typography-export")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:53`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:57`](../../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaPluginCommand(inner: FigmaPluginCommand): FigmaPluginCommand {
   return inner;
@@ -176,13 +176,16 @@ export function FigmaPluginCommand(inner: FigmaPluginCommand): FigmaPluginComman
 /**
  * `#[codegen(tags = "figma-typography-export")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:59`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:63`](../../../design-tokens/src/typography/figma.rs)
  */
 // deno-lint-ignore no-namespace
 export namespace FigmaPluginCommandOperation {
   export type ApplyFns<R> = {
     // callbacks
     UpdateTypography(inner: UpdateTypography["UpdateTypography"]): R,
+    UpdateVariables(inner: UpdateVariables["UpdateVariables"]): R,
+    /** Reviewed in the plugin before it's applied, see [plan_typography_for_figma] */
+    ApplyTypographyPlan(inner: ApplyTypographyPlan["ApplyTypographyPlan"]): R,
   }
   /** Match helper for {@link FigmaPluginCommandOperation} */
   export function apply<R>(
@@ -193,6 +196,8 @@ export namespace FigmaPluginCommandOperation {
       // if-else objects
       if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
       if ("UpdateTypography" in input) return to.UpdateTypography(input["UpdateTypography"]);
+      if ("UpdateVariables" in input) return to.UpdateVariables(input["UpdateVariables"]);
+      if ("ApplyTypographyPlan" in input) return to.ApplyTypographyPlan(input["ApplyTypographyPlan"]);
       const _exhaust: never = input;
       throw new TypeError("Unknown object when expected FigmaPluginCommandOperation");
     }
@@ -212,18 +217,37 @@ export namespace FigmaPluginCommandOperation {
   export function UpdateTypography(value: UpdateTypography["UpdateTypography"]): UpdateTypography {
     return { UpdateTypography: value }
   }
+  export type UpdateVariables = {
+    UpdateVariables: {
+      collections: Array<VariableCollection>;
+    };
+  };
+  export function UpdateVariables(value: UpdateVariables["UpdateVariables"]): UpdateVariables {
+    return { UpdateVariables: value }
+  }
+  export type ApplyTypographyPlan = {
+    ApplyTypographyPlan: {
+      plan: TypographySyncPlan;
+    };
+  };
+  /** Reviewed in the plugin before it's applied, see [plan_typography_for_figma] */
+  export function ApplyTypographyPlan(value: ApplyTypographyPlan["ApplyTypographyPlan"]): ApplyTypographyPlan {
+    return { ApplyTypographyPlan: value }
+  }
 }
 /**
  * `#[codegen(tags = "figma-typography-export")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:59`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:63`](../../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaPluginCommandOperation =
   | FigmaPluginCommandOperation.UpdateTypography
+  | FigmaPluginCommandOperation.UpdateVariables
+  | FigmaPluginCommandOperation.ApplyTypographyPlan
 /**
  * `#[codegen(tags = "figma-typography-export")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:65`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:78`](../../../design-tokens/src/typography/figma.rs)
  */
 export type TextStyle = {
   name: string;
@@ -237,12 +261,297 @@ export type TextStyle = {
   letter_spacing_px?: number | undefined | null | null | undefined;
   /** `#[serde(skip_serializing_if = "Vec::is_empty")]` */
   variant_values: Array<[string, string]>;
+  /**
+   * Set when [figma_config::FigmaTypographyConfig::VariablesOption] is configured.
+   * The px values above are kept as a fallback for variables that are missing in Figma.
+   *
+   * `#[serde(skip_serializing_if = "Option::is_none")]`
+   */
+  bound_variables?: TextStyleVariables | undefined | null | null | undefined;
 };
 /**
  * `#[codegen(tags = "figma-typography-export")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:65`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:78`](../../../design-tokens/src/typography/figma.rs)
  */
 export function TextStyle(inner: TextStyle): TextStyle {
   return inner;
+}
+/**
+ * Keys of the [Variable]s from [update_variables_for_figma] that a text style is bound to,
+ * so changing a mode in Figma updates the text style.
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:100`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type TextStyleVariables = {
+  font_family: string;
+  font_style: string;
+  font_size: string;
+  /** `#[serde(skip_serializing_if = "Option::is_none")]` */
+  line_height?: string | undefined | null | null | undefined;
+  letter_spacing: string;
+};
+/**
+ * Keys of the [Variable]s from [update_variables_for_figma] that a text style is bound to,
+ * so changing a mode in Figma updates the text style.
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:100`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function TextStyleVariables(inner: TextStyleVariables): TextStyleVariables {
+  return inner;
+}
+/**
+ * A text style as it is in the Figma file, exported by the plugin.
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:207`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type ExistingTextStyle = {
+  /** Figma's style id */
+  id: string;
+  name: string;
+  /** The key from the description, if the text style was generated */
+  key?: string | undefined | null | null | undefined;
+  family_name_and_style: [string, string];
+  font_size_px: number;
+  /** `None` for an auto line height */
+  line_height_px?: number | undefined | null | null | undefined;
+  letter_spacing_px?: number | undefined | null | null | undefined;
+  /**
+   * Keys of the variables bound to each field, named like the fields of
+   * [TextStyleVariables], e.g. `{ "font_size": "key-ui:true size:sm/font-size" }`
+   *
+   * `#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]`
+   */
+  bound_variables: Record<string, string>;
+};
+/**
+ * A text style as it is in the Figma file, exported by the plugin.
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:207`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function ExistingTextStyle(inner: ExistingTextStyle): ExistingTextStyle {
+  return inner;
+}
+/**
+ * What applying the text styles would change in the Figma file.
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:227`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type TypographySyncPlan = {
+  /** Text styles with a key that isn't in Figma yet */
+  created: Array<TextStyle>;
+  /** Existing text styles with different values, which may also be renamed */
+  updated: Array<TextStyleChange>;
+  /** Existing text styles with only a different name */
+  renamed: Array<TextStyleChange>;
+  /**
+   * Generated text styles in Figma with a key that is no longer generated.
+   * The plugin renames them with the `"⚠️ Unlinked / "` prefix rather than deleting them,
+   * since they may still be in use, so text styles already marked are left out.
+   */
+  orphaned: Array<ExistingTextStyle>;
+  unchanged_count: number;
+};
+/**
+ * What applying the text styles would change in the Figma file.
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:227`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function TypographySyncPlan(inner: TypographySyncPlan): TypographySyncPlan {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:243`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type TextStyleChange = {
+  /** Figma's style id of the existing text style */
+  id: string;
+  /** `#[serde(skip_serializing_if = "Option::is_none")]` */
+  renamed_from?: string | undefined | null | null | undefined;
+  /** `#[serde(skip_serializing_if = "Vec::is_empty")]` */
+  fields: Array<FieldChange>;
+  text_style: TextStyle;
+};
+/**
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:243`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function TextStyleChange(inner: TextStyleChange): TextStyleChange {
+  return inner;
+}
+/**
+ * e.g. `{ field: "font_size_px", from: "12", to: "12.38" }`
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:256`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type FieldChange = {
+  field: string;
+  from: string;
+  to: string;
+};
+/**
+ * e.g. `{ field: "font_size_px", from: "12", to: "12.38" }`
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:256`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function FieldChange(inner: FieldChange): FieldChange {
+  return inner;
+}
+/**
+ * A Figma variable collection, created or updated in place by its key.
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:265`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type VariableCollection = {
+  /** Used to figure out which Figma variable collection to update, e.g. `"design-tokens/color"` */
+  key: string;
+  name: string;
+  /** e.g. `["Light", "Dark"]`, the first is the collection's default mode */
+  modes: Array<string>;
+  variables: Array<Variable>;
+};
+/**
+ * A Figma variable collection, created or updated in place by its key.
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:265`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function VariableCollection(inner: VariableCollection): VariableCollection {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:276`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type Variable = {
+  /**
+   * Used to figure out which Figma variables to replace,
+   * e.g. `"color:primary tone:40"` or `"key-ui:true size:sm/font-size"`
+   */
+  key: string;
+  /** Slashes create groups in Figma, e.g. `"palette/primary/40"` */
+  name: string;
+  /**
+   * One value for each of the collection's modes, in the same order.
+   * All values are the same kind, which decides the variable's type
+   * (`COLOR`, `FLOAT`, or `STRING`).
+   */
+  values: Array<VariableValue>;
+};
+/**
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:276`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function Variable(inner: Variable): Variable {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:290`](../../../design-tokens/src/typography/figma.rs)
+ */
+// deno-lint-ignore no-namespace
+export namespace VariableValue {
+  export type ApplyFns<R> = {
+    // callbacks
+    Color(inner: Color["Color"]): R,
+    Float(inner: Float["Float"]): R,
+    String(inner: String["String"]): R;
+  }
+  /** Match helper for {@link VariableValue} */
+  export function apply<R>(
+    to: ApplyFns<R>,
+  ): (input: VariableValue) => R {
+    return function _match(input): R {
+      // if-else strings
+      // if-else objects
+      if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
+      if ("Color" in input) return to.Color(input["Color"]);
+      if ("Float" in input) return to.Float(input["Float"]);
+      if ("String" in input) return to.String(input["String"]);
+      const _exhaust: never = input;
+      throw new TypeError("Unknown object when expected VariableValue");
+    }
+  }
+  /** Match helper for {@link VariableValue} */
+  export function match<R>(
+    input: VariableValue,
+    to: ApplyFns<R>,
+  ): R {
+    return apply(to)(input)
+  }
+  export type Color = {
+    Color: FigmaColor
+  };
+  export function Color(value: FigmaColor): Color {
+    return { Color: value };
+  }
+  export type Float = {
+    Float: number
+  };
+  export function Float(value: number): Float {
+    return { Float: value };
+  }
+  export type String = {
+    String: string
+  };
+  export function String(value: string): String {
+    return { String: value };
+  }
+}
+/**
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:290`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type VariableValue =
+  | VariableValue.Color
+  | VariableValue.Float
+  | VariableValue.String
+/**
+ * Each channel from `0` to `1`, like Figma's `RGBA`
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:299`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type FigmaColor = {
+  r: number;
+  g: number;
+  b: number;
+  a: number;
+};
+/**
+ * Each channel from `0` to `1`, like Figma's `RGBA`
+ *
+ * `#[codegen(tags = "figma-typography-export")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:299`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function FigmaColor(inner: FigmaColor): FigmaColor {
+  return inner;
 }
\ No newline at end of file
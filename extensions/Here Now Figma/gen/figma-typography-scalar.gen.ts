@@ -91,7 +91,7 @@ export type FigmaFontStyleRule =
 /**
  * `#[codegen(ts_interface_merge, tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:286`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:780`](../../../design-tokens/src/typography/figma.rs)
  */
 export interface TypographyExtensionInput {
   /** `#[serde(alias = "figma")]` */
@@ -100,16 +100,22 @@ export interface TypographyExtensionInput {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:295`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:789`](../../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaTypographyConfig = {
   /** A sort of matrice of all possible combinations of the variants */
   FigmaTextStyles: Array<FigmaTextStyle>;
+  /**
+   * Figma variables for the text styles' numbers and fonts.
+   * When set, the text styles are also bound to these variables,
+   * so update the variables in Figma before the typography.
+   */
+  VariablesOption?: FigmaVariablesConfig | undefined | null | null | undefined;
 };
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:295`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:789`](../../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaTypographyConfig(inner: FigmaTypographyConfig): FigmaTypographyConfig {
   return inner;
@@ -117,7 +123,45 @@ export function FigmaTypographyConfig(inner: FigmaTypographyConfig): FigmaTypogr
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:305`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:803`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type FigmaVariablesConfig = {
+  /**
+   * Modes of the typography variable collection, each adding its tokens to every text style.
+   * Defaults to a single `"Default"` mode.
+   * e.g. `[{ Name: "Comfortable", Tokens: "density:comfortable" }, { Name: "Compact", Tokens: "density:compact" }]`
+   */
+  TypographyModesOption?: Array<FigmaVariableMode> | undefined | null | null | undefined;
+};
+/**
+ * `#[codegen(tags = "figma-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:803`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function FigmaVariablesConfig(inner: FigmaVariablesConfig): FigmaVariablesConfig {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "figma-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:813`](../../../design-tokens/src/typography/figma.rs)
+ */
+export type FigmaVariableMode = {
+  Name: string;
+  Tokens: string;
+};
+/**
+ * `#[codegen(tags = "figma-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:813`](../../../design-tokens/src/typography/figma.rs)
+ */
+export function FigmaVariableMode(inner: FigmaVariableMode): FigmaVariableMode {
+  return inner;
+}
+/**
+ * `#[codegen(tags = "figma-typography-input")]`
+ *
+ * [Source `design-tokens/src/typography/figma.rs:821`](../../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaTextStyle = {
   BaseName: string;
@@ -129,7 +173,7 @@ export type FigmaTextStyle = {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:305`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:821`](../../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaTextStyle(inner: FigmaTextStyle): FigmaTextStyle {
   return inner;
@@ -137,7 +181,7 @@ export function FigmaTextStyle(inner: FigmaTextStyle): FigmaTextStyle {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:316`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:832`](../../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaTextStyleMatrixGroup = {
   /**
@@ -155,7 +199,7 @@ export type FigmaTextStyleMatrixGroup = {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:316`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:832`](../../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaTextStyleMatrixGroup(inner: FigmaTextStyleMatrixGroup): FigmaTextStyleMatrixGroup {
   return inner;
@@ -163,7 +207,7 @@ export function FigmaTextStyleMatrixGroup(inner: FigmaTextStyleMatrixGroup): Fig
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:331`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:847`](../../../design-tokens/src/typography/figma.rs)
  */
 export type FigmaTextStyleMatrixOption = {
   Name: string;
@@ -178,7 +222,7 @@ export type FigmaTextStyleMatrixOption = {
 /**
  * `#[codegen(tags = "figma-typography-input")]`
  *
- * [Source `design-tokens/src/typography/figma.rs:331`](../../../design-tokens/src/typography/figma.rs)
+ * [Source `design-tokens/src/typography/figma.rs:847`](../../../design-tokens/src/typography/figma.rs)
  */
 export function FigmaTextStyleMatrixOption(inner: FigmaTextStyleMatrixOption): FigmaTextStyleMatrixOption {
   return inner;
@@ -4,6 +4,8 @@ export namespace MessageToUI {
   export type ApplyFns<R> = {
     // callbacks
     LoadCollections(inner: LoadCollections["LoadCollections"]): R,
+    /** Reply to [MessageToPlugin::ExportExistingTextStyles], to pass to `--figma-text-styles` */
+    ExistingTextStyles(inner: ExistingTextStyles["ExistingTextStyles"]): R,
   }
   /** Match helper for {@link MessageToUI} */
   export function apply<R>(
@@ -14,6 +16,7 @@ export namespace MessageToUI {
       // if-else objects
       if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
       if ("LoadCollections" in input) return to.LoadCollections(input["LoadCollections"]);
+      if ("ExistingTextStyles" in input) return to.ExistingTextStyles(input["ExistingTextStyles"]);
       const _exhaust: never = input;
       throw new TypeError("Unknown object when expected MessageToUI");
     }
@@ -33,10 +36,21 @@ export namespace MessageToUI {
   export function LoadCollections(value: LoadCollections["LoadCollections"]): LoadCollections {
     return { LoadCollections: value }
   }
+  export type ExistingTextStyles = {
+    ExistingTextStyles: {
+      /** `#[codegen(ts_as = "Array<import(\"./figma-typography-export.gen.js\").ExistingTextStyle>")]` */
+      text_styles: Array<import("./figma-typography-export.gen.js").ExistingTextStyle>;
+    };
+  }
+  /** Reply to [MessageToPlugin::ExportExistingTextStyles], to pass to `--figma-text-styles` */
+  export function ExistingTextStyles(value: ExistingTextStyles["ExistingTextStyles"]): ExistingTextStyles {
+    return { ExistingTextStyles: value }
+  }
 }
 /** `#[codegen(tags = "hn-figma-types")]` */
 export type MessageToUI =
   | MessageToUI.LoadCollections
+  | MessageToUI.ExistingTextStyles
 /** `#[codegen(tags = "hn-figma-types")]` */
 // deno-lint-ignore no-namespace
 export namespace MessageToPlugin {
@@ -44,6 +58,7 @@ export namespace MessageToPlugin {
     // callbacks
     ImportJSONFileToVariables(inner: ImportJSONFileToVariables["ImportJSONFileToVariables"]): R,
     Command(inner: Command["Command"]): R,
+    ExportExistingTextStyles(): R,
   }
   /** Match helper for {@link MessageToPlugin} */
   export function apply<R>(
@@ -51,6 +66,7 @@ export namespace MessageToPlugin {
   ): (input: MessageToPlugin) => R {
     return function _match(input): R {
       // if-else strings
+      if (input === "ExportExistingTextStyles") return to.ExportExistingTextStyles();
       // if-else objects
       if (typeof input !== "object" || input == null) throw new TypeError("Unexpected non-object for input");
       if ("ImportJSONFileToVariables" in input) return to.ImportJSONFileToVariables(input["ImportJSONFileToVariables"]);
@@ -86,11 +102,16 @@ export namespace MessageToPlugin {
   export function Command(value: Command["Command"]): Command {
     return { Command: value }
   }
+  export type ExportExistingTextStyles = "ExportExistingTextStyles"
+  export function ExportExistingTextStyles(): ExportExistingTextStyles {
+    return "ExportExistingTextStyles";
+  }
 }
 /** `#[codegen(tags = "hn-figma-types")]` */
 export type MessageToPlugin =
   | MessageToPlugin.ImportJSONFileToVariables
   | MessageToPlugin.Command
+  | MessageToPlugin.ExportExistingTextStyles
 /** `#[codegen(tags = "hn-figma-types")]` */
 // deno-lint-ignore no-namespace
 export namespace IDOrNew {
@@ -1,5 +1,12 @@
 import { gen, protocol } from "~gen";
 import { descriptionKey, descriptionInsertKey } from "./descriptionKey";
+import {
+  bindTextStyleVariables,
+  findVariablesByKey,
+  textStyleVariableKeys,
+  unbindTextStyleVariables,
+  updateVariables,
+} from "./variables";
 
 let existingStyles = figma.getLocalTextStyles();
 const updated: typeof existingStyles = [];
@@ -74,10 +81,19 @@ figma.ui.onmessage = (e) => {
       gen.FigmaPluginCommandOperation.match(inner.command.figma_plugin, {
         async UpdateTypography(typography) {
           await updateTextStyles(typography.text_styles);
+          return "Successfully updated typography";
+        },
+        async UpdateVariables(variables) {
+          await updateVariables(variables.collections);
+          return "Successfully updated variables";
+        },
+        async ApplyTypographyPlan(inner) {
+          await applyTypographyPlan(inner.plan);
+          return "Successfully applied typography plan";
         },
       })
-        .then(() => {
-          figma.closePlugin("Successfully updated typography");
+        .then((message) => {
+          figma.closePlugin(message);
         })
         .catch((err) => {
           figma.notify("Failed to execute command");
@@ -91,8 +107,72 @@ figma.ui.onmessage = (e) => {
     ImportJSONFileToVariables(inner) {
       console.warn("TODO: importing JSON file to variables", inner);
     },
+    ExportExistingTextStyles() {
+      sendToUI(
+        protocol.MessageToUI.ExistingTextStyles({
+          text_styles: existingStyles.map(toExistingTextStyle),
+        })
+      );
+    },
   });
 };
+
+function toExistingTextStyle(style: TextStyle): gen.ExistingTextStyle {
+  const toPx = (value: LineHeight | LetterSpacing) =>
+    value.unit === "PIXELS"
+      ? value.value
+      : value.unit === "PERCENT"
+      ? (value.value * style.fontSize) / 100
+      : null;
+  return {
+    id: style.id,
+    name: style.name,
+    key: descriptionKey(style),
+    family_name_and_style: [style.fontName.family, style.fontName.style],
+    font_size_px: style.fontSize,
+    line_height_px: toPx(style.lineHeight),
+    letter_spacing_px: toPx(style.letterSpacing),
+    bound_variables: textStyleVariableKeys(style),
+  };
+}
+
+/** Only touches the text styles in the plan, which was reviewed in the UI */
+async function applyTypographyPlan(plan: gen.TypographySyncPlan) {
+  const variablesByKey = await findVariablesByKey();
+  const total =
+    plan.created.length +
+    plan.updated.length +
+    plan.renamed.length +
+    plan.orphaned.length;
+  figma.notify(`Applying ${total} text style changes`);
+  for (const textStyle of plan.created) {
+    const newStyle = figma.createTextStyle();
+    await updateStyle(newStyle, textStyle, variablesByKey).catch(
+      withMessage`Failed to create text style ${textStyle.name}`
+    );
+    existingStyles.push(newStyle);
+  }
+  for (const change of [...plan.updated, ...plan.renamed]) {
+    const style = figma.getStyleById(change.id);
+    if (style == null || style.type !== "TEXT") {
+      console.warn("Text style in plan no longer exists", change);
+      continue;
+    }
+    await updateStyle(
+      style as TextStyle,
+      change.text_style,
+      variablesByKey
+    ).catch(withMessage`Failed to update text style ${change.text_style.name}`);
+    const unbound =
+      change.text_style.bound_variables == null &&
+      change.fields.some((field) => field.field === "bound_variables");
+    if (unbound) unbindTextStyleVariables(style as TextStyle);
+  }
+  for (const orphan of plan.orphaned) {
+    const style = figma.getStyleById(orphan.id);
+    if (style) markUnlinked(style);
+  }
+}
 async function updateTextStyles(textStyles: gen.TextStyle[]) {
   const timer = new Timer();
   timer.start("update typography");
@@ -101,13 +181,17 @@ async function updateTextStyles(textStyles: gen.TextStyle[]) {
   figma.notify(`Updating ${total} text styles`);
   let waitToNotifyAt = Date.now() + 1000;
   const unlinkedKeys = new Set(seenKeys.keys());
+  const variablesByKey = await findVariablesByKey();
   const lastTextStylePerFolder = new Map<string, TextStyle>();
   let lastFolderName: string | null = null;
   for (const batch of splitIntoBatches(textStyles, 10)) {
     const styles = await Promise.all(
       batch.map(async (typeStyle) => {
         unlinkedKeys.delete(typeStyle.key);
-        const { timer, found } = await importTypeStyle(typeStyle).catch(
+        const { timer, found } = await importTypeStyle(
+          typeStyle,
+          variablesByKey
+        ).catch(
           withMessage`Failed to import text style #${i} for ${typeStyle.name}`
         );
         i++;
@@ -155,21 +239,28 @@ async function updateTextStyles(textStyles: gen.TextStyle[]) {
   timer.start("marking unlinked keys");
   for (const key of Array.from(unlinkedKeys.keys())) {
     const unlinkedStyle = seenKeys.get(key);
-    if (unlinkedStyle && !unlinkedStyle.name.startsWith("⚠️ Unlinked")) {
-      unlinkedStyle.name =
-        "⚠️ Unlinked / " + removeMarkingFolderFromName(unlinkedStyle.name);
-    }
+    if (unlinkedStyle) markUnlinked(unlinkedStyle);
   }
   timer.end();
 
   console.log("Finished updating typography", timer.timings);
 }
 
+/** Keeps a text style which is no longer generated, since it may still be in use */
+function markUnlinked(style: BaseStyle) {
+  if (!style.name.startsWith("⚠️ Unlinked")) {
+    style.name = "⚠️ Unlinked / " + removeMarkingFolderFromName(style.name);
+  }
+}
+
 function removeMarkingFolderFromName(name: string): string {
   return name.replace(/^\s*⚠️[\w\s]+\/\s*/, "");
 }
 
-async function importTypeStyle(style: gen.TextStyle) {
+async function importTypeStyle(
+  style: gen.TextStyle,
+  variablesByKey: Map<string, Variable>
+) {
   const timer = new Timer();
   timer.start("find existing type style");
   let found = seenKeys.get(style.key);
@@ -179,11 +270,11 @@ async function importTypeStyle(style: gen.TextStyle) {
 
   if (found != null) {
     timer.start("update style existing");
-    timer.append(await updateStyle(found, style));
+    timer.append(await updateStyle(found, style, variablesByKey));
   } else {
     timer.start("create style");
     const newStyle = figma.createTextStyle();
-    timer.append(await updateStyle(newStyle, style));
+    timer.append(await updateStyle(newStyle, style, variablesByKey));
     existingStyles.push(newStyle);
     found = newStyle;
   }
@@ -269,7 +360,11 @@ class Timer {
   }
 }
 
-async function updateStyle(style: TextStyle, update: gen.TextStyle) {
+async function updateStyle(
+  style: TextStyle,
+  update: gen.TextStyle,
+  variablesByKey: Map<string, Variable>
+) {
   const timings = new Timer();
   const fontName: FontName = {
     family: update.family_name_and_style[0],
@@ -290,6 +385,10 @@ async function updateStyle(style: TextStyle, update: gen.TextStyle) {
   style.lineHeight = update.line_height_px
     ? { unit: "PIXELS", value: update.line_height_px }
     : { unit: "AUTO" };
+  if (update.bound_variables) {
+    timings.start(`bind variables`);
+    bindTextStyleVariables(style, update.bound_variables, variablesByKey);
+  }
   descriptionInsertKey(style, update.key);
   timings.end();
   return timings;
@@ -0,0 +1,176 @@
+import { gen } from "~gen";
+
+/** Plugin data key holding the design tokens key of collections and variables */
+export const VARIABLE_KEY = "design-tokens-key";
+
+const UNLINKED_PREFIX = "⚠️ Unlinked / ";
+
+export async function updateVariables(
+  collections: gen.VariableCollection[]
+): Promise<void> {
+  const existingCollections =
+    await figma.variables.getLocalVariableCollectionsAsync();
+  const existingVariables = await figma.variables.getLocalVariablesAsync();
+  for (const update of collections) {
+    let collection = existingCollections.find(
+      (found) => found.getPluginData(VARIABLE_KEY) === update.key
+    );
+    if (collection == null) {
+      collection = figma.variables.createVariableCollection(update.name);
+      collection.setPluginData(VARIABLE_KEY, update.key);
+    }
+    collection.name = update.name;
+    const modeIds = updateModes(collection, update.modes);
+
+    const collectionId = collection.id;
+    const byKey = new Map<string, Variable>();
+    for (const variable of existingVariables) {
+      const key = variable.getPluginData(VARIABLE_KEY);
+      if (variable.variableCollectionId === collectionId && key) {
+        byKey.set(key, variable);
+      }
+    }
+    const unlinkedKeys = new Set(byKey.keys());
+
+    for (const variableUpdate of update.variables) {
+      unlinkedKeys.delete(variableUpdate.key);
+      if (variableUpdate.values.length === 0) continue;
+      const resolvedType = resolvedTypeOf(variableUpdate.values[0]);
+      let variable = byKey.get(variableUpdate.key);
+      if (variable != null && variable.resolvedType !== resolvedType) {
+        // the type of a variable cannot be changed
+        variable.remove();
+        variable = undefined;
+      }
+      if (variable == null) {
+        variable = figma.variables.createVariable(
+          variableUpdate.name,
+          collection,
+          resolvedType
+        );
+        variable.setPluginData(VARIABLE_KEY, variableUpdate.key);
+      }
+      variable.name = variableUpdate.name;
+      for (let i = 0; i < variableUpdate.values.length; i++) {
+        variable.setValueForMode(
+          modeIds[i],
+          gen.VariableValue.match<VariableValue>(variableUpdate.values[i], {
+            Color: (color) => color,
+            Float: (value) => value,
+            String: (value) => value,
+          })
+        );
+      }
+    }
+
+    for (const key of Array.from(unlinkedKeys)) {
+      const unlinked = byKey.get(key);
+      if (unlinked && !unlinked.name.startsWith(UNLINKED_PREFIX)) {
+        unlinked.name = UNLINKED_PREFIX + unlinked.name;
+      }
+    }
+  }
+}
+
+/** Every local variable created by {@link updateVariables}, by its key */
+export async function findVariablesByKey(): Promise<Map<string, Variable>> {
+  const byKey = new Map<string, Variable>();
+  for (const variable of await figma.variables.getLocalVariablesAsync()) {
+    const key = variable.getPluginData(VARIABLE_KEY);
+    if (key) byKey.set(key, variable);
+  }
+  return byKey;
+}
+
+/** Binds each field to its variable, keeping the raw value when the variable is missing */
+export function bindTextStyleVariables(
+  style: TextStyle,
+  bound: gen.TextStyleVariables,
+  variablesByKey: Map<string, Variable>
+) {
+  const fields: [VariableBindableTextField, string | null | undefined][] = [
+    ["fontFamily", bound.font_family],
+    ["fontStyle", bound.font_style],
+    ["fontSize", bound.font_size],
+    ["lineHeight", bound.line_height],
+    ["letterSpacing", bound.letter_spacing],
+  ];
+  for (const [field, key] of fields) {
+    if (key == null) {
+      style.setBoundVariable(field, null);
+      continue;
+    }
+    const variable = variablesByKey.get(key);
+    if (variable == null) {
+      console.warn(`No variable for ${key}, update variables first`, {
+        style: style.name,
+      });
+      continue;
+    }
+    style.setBoundVariable(field, variable);
+  }
+}
+
+/** Fields of text styles which can be bound, named like {@link gen.TextStyleVariables} */
+const TEXT_STYLE_FIELDS: [
+  VariableBindableTextField,
+  keyof gen.TextStyleVariables
+][] = [
+  ["fontFamily", "font_family"],
+  ["fontStyle", "font_style"],
+  ["fontSize", "font_size"],
+  ["lineHeight", "line_height"],
+  ["letterSpacing", "letter_spacing"],
+];
+
+/**
+ * The keys of the bound variables, falling back to the name
+ * for variables which weren't created by {@link updateVariables}
+ */
+export function textStyleVariableKeys(
+  style: TextStyle
+): Record<string, string> {
+  const keys: Record<string, string> = {};
+  for (const [field, name] of TEXT_STYLE_FIELDS) {
+    const alias = style.boundVariables?.[field];
+    if (alias == null) continue;
+    const variable = figma.variables.getVariableById(alias.id);
+    if (variable == null) continue;
+    keys[name] = variable.getPluginData(VARIABLE_KEY) || variable.name;
+  }
+  return keys;
+}
+
+/** Keeps the raw values of the text style, for text styles no longer configured with variables */
+export function unbindTextStyleVariables(style: TextStyle) {
+  for (const [field] of TEXT_STYLE_FIELDS) {
+    style.setBoundVariable(field, null);
+  }
+}
+
+/** Finds or creates each mode by name, reusing the default mode for the first */
+function updateModes(
+  collection: VariableCollection,
+  modes: string[]
+): string[] {
+  return modes.map((name, i) => {
+    const found = collection.modes.find((mode) => mode.name === name);
+    if (found) return found.modeId;
+    const defaultMode = collection.modes.find(
+      (mode) => mode.modeId === collection.defaultModeId
+    );
+    if (i === 0 && defaultMode && !modes.includes(defaultMode.name)) {
+      collection.renameMode(defaultMode.modeId, name);
+      return defaultMode.modeId;
+    }
+    return collection.addMode(name);
+  });
+}
+
+function resolvedTypeOf(value: gen.VariableValue): VariableResolvedDataType {
+  return gen.VariableValue.match<VariableResolvedDataType>(value, {
+    Color: () => "COLOR",
+    Float: () => "FLOAT",
+    String: () => "STRING",
+  });
+}
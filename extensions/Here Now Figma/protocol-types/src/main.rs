@@ -7,6 +7,11 @@ use derive_codegen::{Codegen, Generation};
 #[codegen(tags = "hn-figma-types")]
 enum MessageToUI {
     LoadCollections { collections: Vec<CollectionInfo> },
+    /// Reply to [MessageToPlugin::ExportExistingTextStyles], to pass to `--figma-text-styles`
+    ExistingTextStyles {
+        #[codegen(ts_as = "Array<import(\"./figma-typography-export.gen.js\").ExistingTextStyle>")]
+        text_styles: (),
+    },
 }
 
 #[derive(Codegen)]
@@ -22,6 +27,7 @@ enum MessageToPlugin {
         #[codegen(ts_as = "import(\"./figma-typography-export.gen.js\").FigmaPluginCommand")]
         command: ()
     },
+    ExportExistingTextStyles,
 }
 
 #[derive(Codegen)]
@@ -15,9 +15,17 @@ export interface UpdateJSONMode {
   error$: Observable<string | null>;
   resetBtn: ButtonState;
   submitBtn: ButtonState;
+  /** What the command would change, to review before submitting */
+  plan$: Observable<gen.TypographySyncPlan | null>;
 }
 
-type Mode = UpdateJSONMode | RootMode;
+export interface ExportTextStylesMode {
+  name: "export-text-styles";
+  /** JSON for `--figma-text-styles`, empty until the plugin replies */
+  json$: Observable<string>;
+}
+
+type Mode = UpdateJSONMode | ExportTextStylesMode | RootMode;
 export type NavButtonState = {
   open(): void;
   isOpen$: Observable<boolean>;
@@ -32,6 +40,7 @@ export type ButtonState = {
 export type NavState = {
   home: NavButtonState;
   update: NavButtonState;
+  export: NavButtonState;
 };
 
 export type UIState = {
@@ -49,12 +58,18 @@ export function createUIState(rootSub: Subscription): UIState {
     },
   };
   const $mode$ = new BehaviorSubject<Mode>(createUpdateJSONMode(services));
+  const $existingTextStylesJSON$ = new BehaviorSubject("");
 
   window.onmessage = (event) => {
     protocol.MessageToUI.match(event.data.pluginMessage, {
       LoadCollections(inner) {
         console.log("Load collections", { inner });
       },
+      ExistingTextStyles(inner) {
+        $existingTextStylesJSON$.next(
+          JSON.stringify(inner.text_styles, null, 2)
+        );
+      },
     });
   };
 
@@ -75,6 +90,21 @@ export function createUIState(rootSub: Subscription): UIState {
           $mode$.next(createUpdateJSONMode(services));
         },
       },
+      export: {
+        isOpen$: $mode$.pipe(
+          map((mode) => mode.name === "export-text-styles")
+        ),
+        open() {
+          $existingTextStylesJSON$.next("");
+          services.sendToPlugin(
+            protocol.MessageToPlugin.ExportExistingTextStyles()
+          );
+          $mode$.next({
+            name: "export-text-styles",
+            json$: $existingTextStylesJSON$.asObservable(),
+          });
+        },
+      },
     },
   };
 }
@@ -108,6 +138,19 @@ function parseFigmaPluginJSON(
         UpdateTypography() {
           return { ok: true, message: "Update Typography", command };
         },
+        UpdateVariables() {
+          return { ok: true, message: "Update Variables", command };
+        },
+        ApplyTypographyPlan({ plan }) {
+          const changes =
+            plan.created.length +
+            plan.updated.length +
+            plan.renamed.length +
+            plan.orphaned.length;
+          return changes === 0
+            ? { ok: false, message: "Typography is already up to date" }
+            : { ok: true, message: `Apply ${changes} changes`, command };
+        },
       });
     } catch (err) {
       return {
@@ -134,6 +177,13 @@ function createUpdateJSONMode(services: Services): UpdateJSONMode {
       value$: $json$.asObservable(),
     },
     error$: checked$.pipe(map((c) => (c.ok ? null : c.message))),
+    plan$: checked$.pipe(
+      map((c) =>
+        c.ok && "ApplyTypographyPlan" in c.command.figma_plugin
+          ? c.command.figma_plugin.ApplyTypographyPlan.plan
+          : null
+      )
+    ),
     resetBtn: {
       click() {
         $json$.next("");
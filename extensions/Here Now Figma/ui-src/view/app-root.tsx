@@ -1,6 +1,8 @@
 import { fromEvent, map } from "rxjs";
+import type { gen } from "~gen";
 import type {
   ButtonState,
+  ExportTextStylesMode,
   NavButtonState,
   NavState,
   RootMode,
@@ -20,6 +22,9 @@ function UpdateJSONUI(updateJSON: UpdateJSONMode) {
   return (
     <div class="flex flex-col flex-1 gap">
       <TextArea input={updateJSON.jsonTextarea} />
+      {updateJSON.plan$.pipe(
+        map((plan) => (plan ? <PlanReview plan={plan} /> : null))
+      )}
       <div class="flex gap justify-end">
         <div class="error-message">{updateJSON.error$}</div>
         <Button class="btn-secondary" state={updateJSON.resetBtn} />
@@ -30,6 +35,75 @@ function UpdateJSONUI(updateJSON: UpdateJSONMode) {
   );
 }
 
+function PlanReview({ plan }: { plan: gen.TypographySyncPlan }) {
+  const renamedFrom = (change: gen.TextStyleChange) =>
+    change.renamed_from ? ` (from "${change.renamed_from}")` : "";
+  return (
+    <div class="flex flex-col gap" style={{ overflowY: "auto" }}>
+      {PlanSection(
+        "Created",
+        plan.created.map((style) => style.name)
+      )}
+      {PlanSection(
+        "Updated",
+        plan.updated.map(
+          (change) =>
+            `${change.text_style.name}${renamedFrom(change)}: ` +
+            (change.fields ?? [])
+              .map((field) => `${field.field} ${field.from} → ${field.to}`)
+              .join(", ")
+        )
+      )}
+      {PlanSection(
+        "Renamed",
+        plan.renamed.map(
+          (change) => `${change.renamed_from} → ${change.text_style.name}`
+        )
+      )}
+      {PlanSection(
+        "Marked ⚠️ Unlinked (no longer generated)",
+        plan.orphaned.map((style) => style.name)
+      )}
+      <div>{`${plan.unchanged_count} unchanged`}</div>
+    </div>
+  );
+}
+
+function PlanSection(title: string, items: string[]) {
+  if (items.length === 0) return null;
+  return (
+    <div>
+      <strong>{`${title} (${items.length})`}</strong>
+      <ul>
+        {items.map((item) => (
+          <li>{item}</li>
+        ))}
+      </ul>
+    </div>
+  );
+}
+
+function ExportTextStylesUI(exportTextStyles: ExportTextStylesMode) {
+  return (
+    <div class="flex flex-col flex-1 gap">
+      <div>
+        Save as a file for <code>--figma-text-styles</code> to plan the next
+        typography update.
+      </div>
+      <textarea
+        style={{ resize: "vertical" }}
+        ref={(textarea, sub) => {
+          sub.add(
+            exportTextStyles.json$.subscribe((value) => {
+              textarea.value = value;
+            })
+          );
+        }}
+      />
+    </div>
+  );
+}
+
 export function AppRoot({ state }: { state: UIState }) {
   return (
     <div class="flex flex-col flex-1 gap">
@@ -40,6 +114,8 @@ export function AppRoot({ state }: { state: UIState }) {
             RootUI(mode)
           ) : mode.name === "update-json" ? (
             UpdateJSONUI(mode)
+          ) : mode.name === "export-text-styles" ? (
+            ExportTextStylesUI(mode)
           ) : (
             <span>UI State not found</span>
           )
@@ -54,6 +130,7 @@ function navJsx(nav: NavState) {
     <div class="flex gap">
       <NavButton label="Home" state={nav.home} />
       <NavButton label="Update" state={nav.update} />
+      <NavButton label="Export" state={nav.export} />
     </div>
   );
 }
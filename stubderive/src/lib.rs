@@ -0,0 +1,3 @@
+use proc_macro::TokenStream;
+#[proc_macro_derive(Codegen, attributes(codegen))]
+pub fn derive(_: TokenStream) -> TokenStream { TokenStream::new() }
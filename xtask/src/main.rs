@@ -25,6 +25,8 @@ enum XtaskCommand {
     TestTypographyE2E(NoOptions),
     #[options(name = "test-typography-print-all-tokens")]
     TestTypographyAllTokens(NoOptions),
+    #[options(name = "bench-token-lookup")]
+    BenchTokenLookup(NoOptions),
 }
 
 // Define options for the program.
@@ -63,6 +65,7 @@ fn main() {
                 PickXtask(XtaskCommand::TestTypographyForFigmaPlugin(NoOptions {})),
                 PickXtask(XtaskCommand::TestTypographyE2E(NoOptions {})),
                 PickXtask(XtaskCommand::TestTypographyAllTokens(NoOptions {})),
+                PickXtask(XtaskCommand::BenchTokenLookup(NoOptions {})),
             ]
             .into_iter()
             .collect(),
@@ -83,6 +86,7 @@ fn main() {
             test_typography_for_figma_plugin(opts);
         }
         XtaskCommand::TestTypographyAllTokens(opts) => test_typography_print_all_tokens(opts),
+        XtaskCommand::BenchTokenLookup(opts) => bench_token_lookup(opts),
     };
 }
 
@@ -185,6 +189,18 @@ fn test_typography_print_all_tokens(_: NoOptions) {
     expect_success(&output);
 }
 
+fn bench_token_lookup(_: NoOptions) {
+    let root_dir = get_project_root_dir();
+    let output = Command::new("cargo")
+        .args("test --release -- --ignored --nocapture bench_token_lookup".split(' '))
+        .current_dir(root_dir.join("./design-tokens"))
+        .spawn()
+        .expect("benchmarking token lookup")
+        .wait_with_output()
+        .expect("exiting");
+    expect_success(&output);
+}
+
 fn test_typography_for_figma_plugin(_: NoOptions) {
     let root_dir = get_project_root_dir();
     let output = Command::new("cargo")